@@ -36,61 +36,195 @@ pub enum CondOp {
     Gte,
     Lte,
     Like,
+    NotLike,
     In,
+    NotIn,
+    /// Rhs is [`CondParam::Range`]
+    Between,
+    NotBetween,
+    /// Unary op, rhs is [`CondParam::Empty`]
+    IsNull,
+    IsNotNull,
+    /// Unary op on lhs cond expr, rhs is [`CondParam::Empty`]
+    Not,
+    /// Unary op on lhs subquery, rhs is [`CondParam::Empty`]
+    Exists,
     And,
     Or,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum CondParam {
+pub enum CondParam<'a> {
+    /// Value list bound as parameters, the rhs of `IN`
     VariantList(Vec<Variant>),
-    Variant(Variant),
-    CondExpr(Box<CondExpr>),
+    /// Value bound as parameter
+    Param(Variant),
+    CondExpr(Box<CondExpr<'a>>),
+    /// Column reference, optionally table qualified, e.g. `u.id`
+    Column(ColumnRef),
+    /// Aggregate function call in `HAVING` clause, e.g. `COUNT(*)`
+    Aggregate(AggregateFunc, ColumnRef),
+    /// Lower and upper bounds of `BETWEEN`, both inclusive
+    Range(Box<CondParam<'a>>, Box<CondParam<'a>>),
+    /// Rhs placeholder of unary ops
+    Empty,
+    /// Nested select, a scalar value or the rhs of `IN` and `EXISTS`
+    Subquery(Box<Selecter<'a>>),
 }
 
+/// Aggregate function, the column name `*` is only valid as `COUNT(*)` argument.
 #[derive(Debug, Clone, PartialEq)]
-pub struct CondExpr {
+pub enum AggregateFunc {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+/// Column reference, `table` is the table name or alias.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnRef {
+    pub table: Option<String>,
+    pub name: String,
+}
+
+impl From<&str> for ColumnRef {
+    /// Dotted name like `u.id` is treated as table qualified column.
+    fn from(name: &str) -> Self {
+        let (table, name) = split_qualified(name);
+
+        Self {
+            table: table.map(|table| table.to_owned()),
+            name: name.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CondExpr<'a> {
     pub op: CondOp,
-    pub lhs: CondParam,
-    pub rhs: CondParam,
+    pub lhs: CondParam<'a>,
+    pub rhs: CondParam<'a>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Deleter<'a> {
     pub table_name: &'a str,
-    pub cond: CondExpr,
+    pub cond: CondExpr<'a>,
+    /// `RETURNING` cols of deleted rows
+    pub returning: Option<Columns<'a>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Inserter<'a> {
     pub table_name: &'a str,
     pub cols: Columns<'a>,
+    /// `ON CONFLICT` clause, turns insert into upsert
+    pub on_conflict: Option<OnConflict<'a>>,
+    /// `RETURNING` cols of inserted or updated rows
+    pub returning: Option<Columns<'a>>,
+}
+
+/// Conflict clause of [`Inserter`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnConflict<'a> {
+    /// Unique/primary cols detecting conflicts,
+    /// MySQL ignores it and checks all unique keys.
+    pub target: Columns<'a>,
+    pub action: ConflictAction<'a>,
+}
+
+/// Action on conflicting rows
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConflictAction<'a> {
+    /// Keep existing row
+    DoNothing,
+    /// Overwrite existing row cols with the inserted values
+    DoUpdate(Columns<'a>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Selecter<'a> {
     pub cols: SelectColumns<'a>,
     pub from: SelectFrom<'a>,
-    pub cond: Option<CondExpr>,
+    pub cond: Option<CondExpr<'a>>,
+    pub group_by: Vec<GroupBy<'a>>,
+    pub having: Option<CondExpr<'a>>,
     pub limit: Option<Limit>,
-    pub order_by: Option<OrderBy<'a>>,
+    /// Order terms, the later ones break ties of the former
+    pub order_by: Vec<OrderBy<'a>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SelectFrom<'a> {
+    /// Table name, or the alias of derived table
     pub table_name: &'a str,
+    pub aliase: Option<&'a str>,
+    /// Derived table, e.g. `FROM (SELECT ...) AS t`
+    pub subquery: Option<Box<Selecter<'a>>>,
+    /// Joined tables, applied from left to right
+    pub joins: Vec<Join<'a>>,
 }
 
 impl<'a> From<&'a str> for SelectFrom<'a> {
     fn from(table_name: &'a str) -> Self {
-        Self { table_name }
+        Self {
+            table_name,
+            aliase: None,
+            subquery: None,
+            joins: vec![],
+        }
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    Cross,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Join<'a> {
+    pub kind: JoinKind,
+    pub table_name: &'a str,
+    pub aliase: Option<&'a str>,
+    /// `ON` clause, `None` for `CROSS JOIN`
+    pub on: Option<CondExpr<'a>>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct OrderBy<'a> {
+    /// Table name or alias qualifier
+    pub table: Option<&'a str>,
     pub col_name: &'a str,
     pub desc: bool,
+    /// `None` for the dialect default
+    pub nulls: Option<Nulls>,
+}
+
+/// Position of `NULL` values in ordering
+#[derive(Debug, Clone, PartialEq)]
+pub enum Nulls {
+    First,
+    Last,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupBy<'a> {
+    /// Table name or alias qualifier
+    pub table: Option<&'a str>,
+    pub col_name: &'a str,
+}
+
+impl<'a> From<&'a str> for GroupBy<'a> {
+    fn from(name: &'a str) -> Self {
+        let (table, col_name) = split_qualified(name);
+
+        GroupBy { table, col_name }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -109,21 +243,59 @@ pub enum SelectColumns<'a> {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SelectNamedColumn<'a> {
+    /// Table name or alias qualifier
+    pub table: Option<&'a str>,
     pub name: &'a str,
     pub aliase: Option<&'a str>,
+    /// Aggregate function applied to the column
+    pub func: Option<AggregateFunc>,
+}
+
+impl<'a> SelectNamedColumn<'a> {
+    /// Create aggregate column, use `*` as `name` for `COUNT(*)`.
+    pub fn aggregate(func: AggregateFunc, name: &'a str, aliase: Option<&'a str>) -> Self {
+        let (table, name) = split_qualified(name);
+
+        SelectNamedColumn {
+            table,
+            name,
+            aliase,
+            func: Some(func),
+        }
+    }
+}
+
+/// Split `u.id` into qualifier and column name.
+fn split_qualified(name: &str) -> (Option<&str>, &str) {
+    match name.split_once('.') {
+        Some((table, name)) => (Some(table), name),
+        None => (None, name),
+    }
 }
 
 impl<'a> From<&'a str> for SelectNamedColumn<'a> {
+    /// Dotted name like `u.id` is treated as table qualified column.
     fn from(name: &'a str) -> Self {
-        SelectNamedColumn { name, aliase: None }
+        let (table, name) = split_qualified(name);
+
+        SelectNamedColumn {
+            table,
+            name,
+            aliase: None,
+            func: None,
+        }
     }
 }
 
 impl<'a> From<(&'a str, &'a str)> for SelectNamedColumn<'a> {
     fn from(pair: (&'a str, &'a str)) -> Self {
+        let (table, name) = split_qualified(pair.0);
+
         SelectNamedColumn {
-            name: pair.0,
+            table,
+            name,
             aliase: Some(pair.1),
+            func: None,
         }
     }
 }
@@ -144,11 +316,14 @@ impl<'a> From<Vec<(&'a str, &'a str)>> for SelectColumns<'a> {
 pub struct Updater<'a> {
     pub table_name: &'a str,
     pub cols: Columns<'a>,
-    pub cond: Option<CondExpr>,
+    pub cond: Option<CondExpr<'a>>,
+    /// `RETURNING` cols of updated rows
+    pub returning: Option<Columns<'a>>,
 }
 
 /// DML expr definition
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum DML<'a> {
     Selecter(Selecter<'a>),
     Updater(Updater<'a>),
@@ -2,6 +2,49 @@ use crate::ddl;
 use crate::dml;
 use crate::Variant;
 
+mod rows;
+pub use rows::*;
+
+mod transaction;
+pub use transaction::*;
+
+mod stream;
+pub use stream::*;
+
+/// Result of executing insert, update or delete stmt.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExecResult {
+    /// Rows inserted, updated or deleted
+    pub affected_rows: usize,
+    /// Integer primary keys of inserted rows in insert order,
+    /// empty if the table has none or the driver can't tell, e.g. for upsert.
+    pub generated_keys: Vec<i64>,
+    /// Values of `RETURNING` cols, one row per affected row
+    pub returning: Vec<Vec<Variant>>,
+}
+
+impl ExecResult {
+    /// Result without generated keys or returning rows
+    pub fn affected(affected_rows: usize) -> Self {
+        Self {
+            affected_rows,
+            ..Default::default()
+        }
+    }
+
+    /// Key of the last inserted row
+    pub fn last_insert_id(&self) -> Option<i64> {
+        self.generated_keys.last().copied()
+    }
+
+    /// Merge result of the next stmt into `self`
+    pub fn append(&mut self, mut other: ExecResult) {
+        self.affected_rows += other.affected_rows;
+        self.generated_keys.append(&mut other.generated_keys);
+        self.returning.append(&mut other.returning);
+    }
+}
+
 #[async_trait::async_trait]
 pub trait QueryIterator {
     async fn next(&mut self) -> anyhow::Result<bool>;
@@ -11,6 +54,16 @@ pub trait QueryIterator {
 
     /// Get column value by column name
     async fn get_by_name(&mut self, name: &str) -> anyhow::Result<Variant>;
+
+    /// Get column value by column name, converted by [`ColumnLike`](crate::orm::ColumnLike)
+    async fn get_as<T>(&mut self, name: &'static str) -> anyhow::Result<T>
+    where
+        T: crate::orm::ColumnLike,
+    {
+        let value = self.get_by_name(name).await?;
+
+        T::from_column_value(crate::orm::ColumnValue::Simple(name, value))
+    }
 }
 
 /// Trait to support executing [`SELECT`](https://www.w3schools.com/sql/sql_select.asp) expr.
@@ -45,7 +98,7 @@ pub trait UpdateSupport<'a> {
         &mut self,
         updater: &dml::Updater<'a>,
         values: Vec<Variant>,
-    ) -> anyhow::Result<usize>;
+    ) -> anyhow::Result<ExecResult>;
 }
 
 /// Trait to support executing [`INSERT`](https://www.w3schools.com/sql/sql_insert.asp) expr.
@@ -56,16 +109,31 @@ pub trait InsertSupport<'a> {
         &mut self,
         inserter: &dml::Inserter<'a>,
         values: Vec<Variant>,
-    ) -> anyhow::Result<usize>;
+    ) -> anyhow::Result<ExecResult>;
+
+    /// Execute multi-row insert stmt, every row binds values in `inserter.cols` order.
+    ///
+    /// The default impl inserts rows one by one and merges their results.
+    async fn insert_many(
+        &mut self,
+        inserter: &dml::Inserter<'a>,
+        rows: Vec<Vec<Variant>>,
+    ) -> anyhow::Result<ExecResult> {
+        let mut result = ExecResult::default();
+
+        for values in rows {
+            result.append(self.insert(inserter, values).await?);
+        }
+
+        Ok(result)
+    }
 }
 
 /// Trait to support executing [`DELETE`](https://www.w3schools.com/sql/sql_delete.asp) expr.
 #[async_trait::async_trait]
 pub trait DeleteSupport<'a> {
     /// Execute delete stmt
-    ///
-    /// Returns deleted rows
-    async fn delete(&mut self, deleter: &dml::Deleter<'a>) -> anyhow::Result<usize>;
+    async fn delete(&mut self, deleter: &dml::Deleter<'a>) -> anyhow::Result<ExecResult>;
 }
 
 /// Trait to support executing [`DDL`](https://www.javatpoint.com/dbms-sql-command) exprs.
@@ -74,3 +142,44 @@ pub trait DDLSupport {
     /// Execute ddl stmts
     async fn exec_ddl<'a>(&mut self, ddls: &[ddl::DDL<'a>]) -> anyhow::Result<()>;
 }
+
+/// Trait to support [`transactions`](https://www.sqlite.org/lang_transaction.html) and savepoints.
+///
+/// Prefer the [`Transaction`] guard over calling these methods directly,
+/// it rolls back automatically on error or drop.
+#[async_trait::async_trait]
+pub trait TransactionSupport {
+    /// Begin a new transaction, nested transactions should use savepoints instead.
+    async fn begin(&mut self) -> anyhow::Result<()>;
+
+    /// Commit current transaction
+    async fn commit(&mut self) -> anyhow::Result<()>;
+
+    /// Rollback current transaction
+    async fn rollback(&mut self) -> anyhow::Result<()>;
+
+    /// Create savepoint `name` in current transaction
+    async fn savepoint(&mut self, name: &str) -> anyhow::Result<()>;
+
+    /// Release savepoint `name` and all savepoints created after it
+    async fn release_savepoint(&mut self, name: &str) -> anyhow::Result<()>;
+
+    /// Rollback to savepoint `name`, the savepoint itself remains active
+    async fn rollback_to_savepoint(&mut self, name: &str) -> anyhow::Result<()>;
+
+    /// Whether a transaction is active, [`Transaction`] nests a savepoint then.
+    fn in_transaction(&mut self) -> bool;
+
+    /// Synchronous rollback, called by a [`Transaction`] dropped before commit.
+    ///
+    /// Rolls back to and releases `savepoint` if set, otherwise the whole transaction.
+    /// Errors can't be returned from drop, implementations should log them instead.
+    fn rollback_on_drop(&mut self, savepoint: Option<&str>);
+}
+
+/// Pure rust in-memory reference engine
+pub mod memory;
+
+/// Reference SQLite driver
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
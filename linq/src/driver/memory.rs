@@ -0,0 +1,733 @@
+//! Pure rust in-memory engine executing LINQ IR directly, without rendering SQL.
+//!
+//! [`MemoryDriver`] implements every `driver` trait and is meant as the reference
+//! semantics for tests and for checking other drivers against. It enforces
+//! `NOT NULL`, primary key and `UNIQUE` constraints; foreign keys, indexes and
+//! checks are recorded but not enforced. Transactions snapshot all tables on
+//! `begin` and on every savepoint.
+//!
+//! # Examples
+//!
+//! ```
+//! use linq_rs::{driver::{memory::MemoryDriver, DDLSupport, InsertSupport, QueryIterator, SelectSupport}, *};
+//!
+//! # async_std::task::block_on(async {
+//! let mut d = MemoryDriver::default();
+//!
+//! d.exec_ddl(&ddl! {
+//!     CREATE TABLE user(id INT PRIMARY AUTOINC, name STRING);
+//! }).await?;
+//!
+//! let result = d.insert(&rql! { INSERT INTO user(name) }, vec!["hello".into()]).await?;
+//!
+//! assert_eq!(result.last_insert_id(), Some(1));
+//!
+//! let mut rows = d.select(&rql! { SELECT name FROM user WHERE id = 1; }).await?;
+//!
+//! assert!(rows.next().await?);
+//! assert_eq!(rows.get(0).await?, "hello".into());
+//! # anyhow::Ok(())
+//! # }).unwrap();
+//! ```
+
+mod eval;
+mod table;
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{
+    ddl::{self, AlterExpr},
+    dml::{self, AggregateFunc, CondExpr, Join, JoinKind, SelectColumns},
+    Variant,
+};
+
+use self::{
+    eval::{Row, Scope},
+    table::Table,
+};
+
+use super::{
+    DDLSupport, DeleteSupport, ExecResult, InsertSupport, Rows, SelectSupport, TransactionSupport,
+    UpdateSupport,
+};
+
+/// In-memory driver, tables are dropped with the driver.
+#[derive(Debug, Default)]
+pub struct MemoryDriver {
+    tables: HashMap<String, Table>,
+    /// Transaction snapshots, the first one is taken by `begin`, the others by savepoints
+    snapshots: Vec<(Option<String>, HashMap<String, Table>)>,
+}
+
+impl MemoryDriver {
+    fn savepoint_index(&self, name: &str) -> anyhow::Result<usize> {
+        self.snapshots
+            .iter()
+            .rposition(|(n, _)| n.as_deref() == Some(name))
+            .ok_or_else(|| anyhow::format_err!("Not found savepoint {}", name))
+    }
+
+    fn table(&self, name: &str) -> anyhow::Result<&Table> {
+        find_table(&self.tables, name)
+    }
+
+    fn table_mut(&mut self, name: &str) -> anyhow::Result<&mut Table> {
+        self.tables
+            .get_mut(name)
+            .ok_or_else(|| anyhow::format_err!("Not found table {}", name))
+    }
+
+    fn alter(&mut self, alter: &ddl::Alter<'_>) -> anyhow::Result<()> {
+        let table = self.table_mut(alter.table_name)?;
+
+        match &alter.expr {
+            AlterExpr::AddColumn(col) => table.add_col(col),
+            AlterExpr::DropColumn(name) => table.drop_col(name),
+            AlterExpr::AlterColumn(col) => table.alter_col(col),
+            AlterExpr::AddConstraint(constraint) => table.add_constraint(constraint),
+            AlterExpr::AlterConstraint(constraint) => table.alter_constraint(constraint),
+            AlterExpr::DropConstraint(name) => table.drop_constraint(name),
+            AlterExpr::RenameColumn(from, to) => table.rename_col(from, to),
+            AlterExpr::RenameConstraint(from, to) => table.rename_constraint(from, to),
+            AlterExpr::RenameTable(to) => {
+                if self.tables.contains_key(*to) {
+                    return Err(anyhow::format_err!("Table {} already exists", to));
+                }
+
+                let table = self.tables.remove(alter.table_name).expect("checked");
+
+                self.tables.insert(to.to_string(), table);
+
+                Ok(())
+            }
+        }
+    }
+}
+
+fn find_table<'t>(tables: &'t HashMap<String, Table>, name: &str) -> anyhow::Result<&'t Table> {
+    tables
+        .get(name)
+        .ok_or_else(|| anyhow::format_err!("Not found table {}", name))
+}
+
+/// Returns whether `row` matches `cond`, `UNKNOWN` is treated as not matched.
+fn matches(scope: &Scope<'_>, row: &[Variant], cond: Option<&CondExpr>) -> anyhow::Result<bool> {
+    match cond {
+        Some(cond) => Ok(eval::eval(scope, row.into(), cond)? == Some(true)),
+        None => Ok(true),
+    }
+}
+
+/// Nested loop join of `rows` with `table`, unmatched rows are padded with `NULL`.
+fn join(
+    scope: &Scope<'_>,
+    rows: Vec<Vec<Variant>>,
+    table: &Table,
+    join: &Join<'_>,
+) -> anyhow::Result<Vec<Vec<Variant>>> {
+    let left_nulls = vec![Variant::Null; scope.col_names().len() - table.cols.len()];
+    let right_nulls = vec![Variant::Null; table.cols.len()];
+
+    let mut right_matched = vec![false; table.rows.len()];
+    let mut result = vec![];
+
+    for lhs in rows {
+        let mut matched = false;
+
+        for (idx, rhs) in table.rows.iter().enumerate() {
+            let row = [lhs.as_slice(), rhs].concat();
+
+            if join.kind == JoinKind::Cross || matches(scope, &row, join.on.as_ref())? {
+                matched = true;
+                right_matched[idx] = true;
+                result.push(row);
+            }
+        }
+
+        if !matched && join.kind == JoinKind::Left {
+            result.push([lhs.as_slice(), &right_nulls].concat());
+        }
+    }
+
+    if join.kind == JoinKind::Right {
+        for (rhs, matched) in table.rows.iter().zip(right_matched) {
+            if !matched {
+                result.push([left_nulls.as_slice(), rhs].concat());
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[async_trait::async_trait]
+impl<'a> SelectSupport<'a> for MemoryDriver {
+    type SelectResult = Rows;
+
+    async fn select(&mut self, selecter: &dml::Selecter<'a>) -> anyhow::Result<Self::SelectResult> {
+        let (col_names, rows) = query(&self.tables, selecter, None)?;
+
+        Ok(Rows::new(col_names, rows))
+    }
+}
+
+/// Run `selecter` on `db` and returns col names and rows.
+///
+/// `outer` is the scope and row of the enclosing query, if `selecter` is a correlated subquery.
+fn query<'s>(
+    db: &'s HashMap<String, Table>,
+    selecter: &dml::Selecter<'_>,
+    outer: Option<(&'s Scope<'s>, &'s [Variant])>,
+) -> anyhow::Result<(Vec<String>, Vec<Vec<Variant>>)> {
+    let from = &selecter.from;
+
+    let mut scope = Scope::new(db, outer);
+
+    let mut rows = match &from.subquery {
+        Some(subquery) => {
+            let (col_names, rows) = query(db, subquery, None)?;
+
+            scope.push_cols(from.aliase.unwrap_or(from.table_name), col_names);
+
+            rows
+        }
+        None => {
+            let table = find_table(db, from.table_name)?;
+
+            scope.push(from.aliase.unwrap_or(from.table_name), table);
+
+            table.rows.clone()
+        }
+    };
+
+    for j in &from.joins {
+        if (j.kind == JoinKind::Cross) != j.on.is_none() {
+            return Err(anyhow::format_err!(
+                "Only CROSS JOIN {} omits ON clause",
+                j.table_name
+            ));
+        }
+
+        let table = find_table(db, j.table_name)?;
+
+        scope.push(j.aliase.unwrap_or(j.table_name), table);
+
+        rows = join(&scope, rows, table, j)?;
+    }
+
+    let mut filtered = vec![];
+
+    for row in rows {
+        if matches(&scope, &row, selecter.cond.as_ref())? {
+            filtered.push(row);
+        }
+    }
+
+    let (col_names, projections) = match &selecter.cols {
+        SelectColumns::All => (
+            scope.col_names(),
+            (0..scope.col_names().len())
+                .map(Projection::Col)
+                .collect::<Vec<_>>(),
+        ),
+        SelectColumns::NamedColumns(cols) => {
+            let mut col_names = vec![];
+            let mut projections = vec![];
+
+            for col in cols {
+                projections.push(match &col.func {
+                    Some(func) => Projection::Aggregate(func, col.table, col.name),
+                    None => Projection::Col(scope.resolve(col.table, col.name)?),
+                });
+
+                col_names.push(col.aliase.unwrap_or(col.name).to_owned());
+            }
+
+            (col_names, projections)
+        }
+    };
+
+    let aggregated = selecter.having.is_some()
+        || !selecter.group_by.is_empty()
+        || projections
+            .iter()
+            .any(|p| matches!(p, Projection::Aggregate(..)));
+
+    let group_indexes = selecter
+        .group_by
+        .iter()
+        .map(|col| scope.resolve(col.table, col.col_name))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if aggregated {
+        for (projection, col_name) in projections.iter().zip(&col_names) {
+            if let Projection::Col(index) = projection {
+                if !group_indexes.contains(index) {
+                    return Err(anyhow::format_err!(
+                        "Col {} must appear in GROUP BY or be used in aggregate function",
+                        col_name
+                    ));
+                }
+            }
+        }
+    }
+
+    // Every output row is computed from a group of rows.
+    let groups = if aggregated {
+        group(filtered, &group_indexes)
+    } else {
+        filtered.into_iter().map(|row| vec![row]).collect()
+    };
+
+    // `ORDER BY` may refer an output alias, e.g. the alias of `COUNT(*)`.
+    let mut order_keys = vec![];
+
+    for order_by in &selecter.order_by {
+        let aliase = match &selecter.cols {
+            SelectColumns::NamedColumns(cols) if order_by.table.is_none() => cols
+                .iter()
+                .position(|col| col.aliase == Some(order_by.col_name)),
+            _ => None,
+        };
+
+        order_keys.push(match aliase {
+            Some(index) => OrderKey::Output(index),
+            None => OrderKey::Source(scope.resolve(order_by.table, order_by.col_name)?),
+        });
+    }
+
+    let null_row = vec![Variant::Null; scope.col_names().len()];
+
+    let mut rows = vec![];
+
+    for group in &groups {
+        let values = group.first().unwrap_or(&null_row);
+
+        if let Some(having) = &selecter.having {
+            let row = Row {
+                values,
+                group: Some(group),
+            };
+
+            if eval::eval(&scope, row, having)? != Some(true) {
+                continue;
+            }
+        }
+
+        let mut output = vec![];
+
+        for projection in &projections {
+            output.push(match projection {
+                Projection::Col(index) => values[*index].clone(),
+                Projection::Aggregate(func, table, name) => {
+                    eval::aggregate(&scope, func, *table, name, group)?
+                }
+            });
+        }
+
+        let keys = order_keys
+            .iter()
+            .map(|key| match key {
+                OrderKey::Output(index) => output[*index].clone(),
+                OrderKey::Source(index) => values[*index].clone(),
+            })
+            .collect::<Vec<_>>();
+
+        rows.push((keys, output));
+    }
+
+    let mut err = None;
+
+    // Stable sort keeps rows with equal keys in scan order.
+    rows.sort_by(|(lhs, _), (rhs, _)| {
+        for ((lhs, rhs), order_by) in lhs.iter().zip(rhs).zip(&selecter.order_by) {
+            match eval::order(lhs, rhs, order_by) {
+                Ok(Ordering::Equal) => {}
+                Ok(ordering) => return ordering,
+                Err(e) => {
+                    err = Some(e);
+                    return Ordering::Equal;
+                }
+            }
+        }
+
+        Ordering::Equal
+    });
+
+    if let Some(err) = err {
+        return Err(err);
+    }
+
+    let mut rows = rows.into_iter().map(|(_, row)| row).collect::<Vec<_>>();
+
+    if let Some(limit) = &selecter.limit {
+        rows = rows
+            .into_iter()
+            .skip(limit.offset.unwrap_or(0))
+            .take(limit.count)
+            .collect();
+    }
+
+    Ok((col_names, rows))
+}
+
+/// How to compute a select column from a group of rows
+enum Projection<'a> {
+    /// Row offset, taken from the first row of the group
+    Col(usize),
+    Aggregate(&'a AggregateFunc, Option<&'a str>, &'a str),
+}
+
+enum OrderKey {
+    /// Offset of output row
+    Output(usize),
+    /// Offset of source row
+    Source(usize),
+}
+
+/// Group rows by cols at `indexes` in first seen order, `NULL` keys are grouped together.
+///
+/// Without `GROUP BY`, all rows form one group, even if there is no row.
+fn group(rows: Vec<Vec<Variant>>, indexes: &[usize]) -> Vec<Vec<Vec<Variant>>> {
+    if indexes.is_empty() {
+        return vec![rows];
+    }
+
+    let mut groups: Vec<(Vec<Variant>, Vec<Vec<Variant>>)> = vec![];
+
+    for row in rows {
+        let key = indexes
+            .iter()
+            .map(|idx| row[*idx].clone())
+            .collect::<Vec<_>>();
+
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.push(row),
+            None => groups.push((key, vec![row])),
+        }
+    }
+
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+#[async_trait::async_trait]
+impl<'a> InsertSupport<'a> for MemoryDriver {
+    async fn insert(
+        &mut self,
+        inserter: &dml::Inserter<'a>,
+        values: Vec<Variant>,
+    ) -> anyhow::Result<ExecResult> {
+        self.insert_many(inserter, vec![values]).await
+    }
+
+    /// Rows are inserted atomically, none of them is kept if any row fails.
+    ///
+    /// Rows updated by upsert have no generated key.
+    async fn insert_many(
+        &mut self,
+        inserter: &dml::Inserter<'a>,
+        values: Vec<Vec<Variant>>,
+    ) -> anyhow::Result<ExecResult> {
+        let table = self.table_mut(inserter.table_name)?;
+
+        let primary = table.cols.iter().position(|col| col.primary == Some(true));
+
+        let conflict = match &inserter.on_conflict {
+            Some(on_conflict) => {
+                let target = col_indexes(table, &on_conflict.target)?;
+
+                if !table.is_unique_key(&target) {
+                    return Err(anyhow::format_err!(
+                        "Conflict target {:?} of {} is not a primary or unique key",
+                        on_conflict.target.col_names(),
+                        inserter.table_name
+                    ));
+                }
+
+                let update = match &on_conflict.action {
+                    dml::ConflictAction::DoNothing => vec![],
+                    dml::ConflictAction::DoUpdate(cols) => col_indexes(table, cols)?,
+                };
+
+                Some((target, update))
+            }
+            None => None,
+        };
+
+        let returning = inserter
+            .returning
+            .as_ref()
+            .map(|cols| col_indexes(table, cols))
+            .transpose()?;
+
+        let mut rows = table.rows.clone();
+        let mut next_id = table.next_id;
+        let mut result = ExecResult::default();
+
+        for values in values {
+            let mut row = table.new_row(inserter.cols.col_names(), values)?;
+
+            if let Some((target, update)) = &conflict {
+                if let Some(existing) = table.find_conflict(&rows, &row, target)? {
+                    if !update.is_empty() {
+                        for idx in update {
+                            rows[existing][*idx] = row[*idx].clone();
+                        }
+
+                        result.affected_rows += 1;
+
+                        if let Some(indexes) = &returning {
+                            result.returning.push(project(&rows[existing], indexes));
+                        }
+                    }
+
+                    continue;
+                }
+            }
+
+            if let Some(index) = primary {
+                match &row[index] {
+                    Variant::Null => {
+                        row[index] = Variant::Int(next_id);
+                        result.generated_keys.push(next_id);
+                        next_id += 1;
+                    }
+                    Variant::Int(value) => {
+                        result.generated_keys.push(*value);
+                        next_id = next_id.max(value + 1);
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(indexes) = &returning {
+                result.returning.push(project(&row, indexes));
+            }
+
+            result.affected_rows += 1;
+
+            rows.push(row);
+        }
+
+        table.check(&rows)?;
+
+        table.rows = rows;
+        table.next_id = next_id;
+
+        Ok(result)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> UpdateSupport<'a> for MemoryDriver {
+    async fn update(
+        &mut self,
+        updater: &dml::Updater<'a>,
+        values: Vec<Variant>,
+    ) -> anyhow::Result<ExecResult> {
+        let table = self.table(updater.table_name)?;
+
+        let cols = updater.cols.col_names();
+
+        if cols.len() != values.len() {
+            return Err(anyhow::format_err!(
+                "Update {} cols with {} values",
+                cols.len(),
+                values.len()
+            ));
+        }
+
+        let indexes = col_indexes(table, &updater.cols)?;
+
+        let returning = updater
+            .returning
+            .as_ref()
+            .map(|cols| col_indexes(table, cols))
+            .transpose()?;
+
+        let mut scope = Scope::new(&self.tables, None);
+
+        scope.push(updater.table_name, table);
+
+        let mut rows = table.rows.clone();
+        let mut result = ExecResult::default();
+
+        for row in &mut rows {
+            if matches(&scope, row, updater.cond.as_ref())? {
+                for (index, value) in indexes.iter().zip(&values) {
+                    row[*index] = value.clone();
+                }
+
+                if let Some(returning) = &returning {
+                    result.returning.push(project(row, returning));
+                }
+
+                result.affected_rows += 1;
+            }
+        }
+
+        table.check(&rows)?;
+
+        self.table_mut(updater.table_name)?.rows = rows;
+
+        Ok(result)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> DeleteSupport<'a> for MemoryDriver {
+    async fn delete(&mut self, deleter: &dml::Deleter<'a>) -> anyhow::Result<ExecResult> {
+        let table = self.table(deleter.table_name)?;
+
+        let returning = deleter
+            .returning
+            .as_ref()
+            .map(|cols| col_indexes(table, cols))
+            .transpose()?;
+
+        let mut scope = Scope::new(&self.tables, None);
+
+        scope.push(deleter.table_name, table);
+
+        let mut rows = vec![];
+        let mut result = ExecResult::default();
+
+        for row in &table.rows {
+            if !matches(&scope, row, Some(&deleter.cond))? {
+                rows.push(row.clone());
+            } else if let Some(returning) = &returning {
+                result.returning.push(project(row, returning));
+            }
+        }
+
+        result.affected_rows = table.rows.len() - rows.len();
+
+        self.table_mut(deleter.table_name)?.rows = rows;
+
+        Ok(result)
+    }
+}
+
+fn col_indexes(table: &Table, cols: &dml::Columns<'_>) -> anyhow::Result<Vec<usize>> {
+    cols.col_names()
+        .iter()
+        .map(|col| table.col_index(col))
+        .collect()
+}
+
+/// Values of `indexes` cols, e.g. for `RETURNING` clause
+fn project(row: &[Variant], indexes: &[usize]) -> Vec<Variant> {
+    indexes.iter().map(|idx| row[*idx].clone()).collect()
+}
+
+#[async_trait::async_trait]
+impl DDLSupport for MemoryDriver {
+    /// Execute ddl stmts in order, stops at the first error.
+    async fn exec_ddl<'a>(&mut self, ddls: &[ddl::DDL<'a>]) -> anyhow::Result<()> {
+        for ddl in ddls {
+            match ddl {
+                ddl::DDL::Create(create) => {
+                    if self.tables.contains_key(create.table_name) {
+                        return Err(anyhow::format_err!(
+                            "Table {} already exists",
+                            create.table_name
+                        ));
+                    }
+
+                    let table = Table::new(&create.cols, &create.constraints)?;
+
+                    self.tables.insert(create.table_name.to_owned(), table);
+                }
+                ddl::DDL::Alter(alter) => self.alter(alter)?,
+                ddl::DDL::Drop(table_name) => {
+                    self.tables
+                        .remove(*table_name)
+                        .ok_or_else(|| anyhow::format_err!("Not found table {}", table_name))?;
+                }
+                ddl::DDL::Truncate(table_name) => self.table_mut(table_name)?.rows.clear(),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionSupport for MemoryDriver {
+    async fn begin(&mut self) -> anyhow::Result<()> {
+        if !self.snapshots.is_empty() {
+            return Err(anyhow::format_err!("Transaction already started"));
+        }
+
+        self.snapshots.push((None, self.tables.clone()));
+
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> anyhow::Result<()> {
+        if self.snapshots.is_empty() {
+            return Err(anyhow::format_err!("No active transaction"));
+        }
+
+        self.snapshots.clear();
+
+        Ok(())
+    }
+
+    async fn rollback(&mut self) -> anyhow::Result<()> {
+        if self.snapshots.is_empty() {
+            return Err(anyhow::format_err!("No active transaction"));
+        }
+
+        self.rollback_on_drop(None);
+
+        Ok(())
+    }
+
+    async fn savepoint(&mut self, name: &str) -> anyhow::Result<()> {
+        if self.snapshots.is_empty() {
+            return Err(anyhow::format_err!("No active transaction"));
+        }
+
+        self.snapshots
+            .push((Some(name.to_owned()), self.tables.clone()));
+
+        Ok(())
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> anyhow::Result<()> {
+        let index = self.savepoint_index(name)?;
+
+        self.snapshots.truncate(index);
+
+        Ok(())
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> anyhow::Result<()> {
+        let index = self.savepoint_index(name)?;
+
+        self.snapshots.truncate(index + 1);
+        self.tables = self.snapshots[index].1.clone();
+
+        Ok(())
+    }
+
+    fn in_transaction(&mut self) -> bool {
+        !self.snapshots.is_empty()
+    }
+
+    fn rollback_on_drop(&mut self, savepoint: Option<&str>) {
+        let index = match savepoint.map(|name| self.savepoint_index(name)) {
+            Some(Ok(index)) => index,
+            Some(Err(err)) => {
+                log::error!("memory rollback on drop: {}", err);
+                return;
+            }
+            None => 0,
+        };
+
+        if let Some((_, tables)) = self.snapshots.drain(index..).next() {
+            self.tables = tables;
+        }
+    }
+}
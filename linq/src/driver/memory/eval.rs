@@ -0,0 +1,511 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use num::{BigInt, BigRational, FromPrimitive};
+
+use crate::{
+    dml::{AggregateFunc, CondExpr, CondOp, CondParam, Nulls, OrderBy, Selecter},
+    Variant,
+};
+
+use super::table::Table;
+
+/// Column names of the tables visible to a cond expr.
+///
+/// Rows evaluated in a scope are the concatenation of each table's row.
+#[derive(Debug, Clone)]
+pub struct Scope<'s> {
+    /// Table name or alias, and its col names
+    tables: Vec<(String, Vec<String>)>,
+    /// All tables of the driver, to run subqueries
+    db: &'s HashMap<String, Table>,
+    /// Scope and row of the outer query, visible to correlated subqueries
+    outer: Option<(&'s Scope<'s>, &'s [Variant])>,
+}
+
+impl<'s> Scope<'s> {
+    pub fn new(
+        db: &'s HashMap<String, Table>,
+        outer: Option<(&'s Scope<'s>, &'s [Variant])>,
+    ) -> Self {
+        Scope {
+            tables: vec![],
+            db,
+            outer,
+        }
+    }
+
+    /// Append table cols, rows must append its values in the same order.
+    pub fn push(&mut self, qualifier: &str, table: &Table) {
+        self.push_cols(
+            qualifier,
+            table.cols.iter().map(|col| col.name.clone()).collect(),
+        );
+    }
+
+    /// Append cols of derived table.
+    pub fn push_cols(&mut self, qualifier: &str, cols: Vec<String>) {
+        self.tables.push((qualifier.to_owned(), cols));
+    }
+
+    /// All col names, in row order
+    pub fn col_names(&self) -> Vec<String> {
+        self.tables
+            .iter()
+            .flat_map(|(_, cols)| cols.iter().cloned())
+            .collect()
+    }
+
+    fn find(&self, table: Option<&str>, name: &str) -> anyhow::Result<Option<usize>> {
+        let mut offset = 0;
+        let mut found = None;
+
+        for (qualifier, cols) in &self.tables {
+            if table.is_none() || table == Some(qualifier.as_str()) {
+                if let Some(idx) = cols.iter().position(|col| col == name) {
+                    if found.is_some() {
+                        return Err(anyhow::format_err!("Ambiguous col {}", name));
+                    }
+
+                    found = Some(offset + idx);
+                }
+            }
+
+            offset += cols.len();
+        }
+
+        Ok(found)
+    }
+
+    /// Returns row offset of col, unqualified name must be unique across tables.
+    pub fn resolve(&self, table: Option<&str>, name: &str) -> anyhow::Result<usize> {
+        self.find(table, name)?
+            .ok_or_else(|| not_found(table, name))
+    }
+
+    /// Returns col value of `values`, cols not in this scope are looked up in the outer query.
+    pub fn lookup(
+        &self,
+        values: &[Variant],
+        table: Option<&str>,
+        name: &str,
+    ) -> anyhow::Result<Variant> {
+        match (self.find(table, name)?, self.outer) {
+            (Some(index), _) => Ok(values[index].clone()),
+            (None, Some((outer, values))) => outer.lookup(values, table, name),
+            (None, None) => Err(not_found(table, name)),
+        }
+    }
+}
+
+fn not_found(table: Option<&str>, name: &str) -> anyhow::Error {
+    match table {
+        Some(table) => anyhow::format_err!("Not found col {}.{}", table, name),
+        None => anyhow::format_err!("Not found col {}", name),
+    }
+}
+
+/// Row under evaluation.
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'r> {
+    pub values: &'r [Variant],
+    /// All rows of the group, only available when evaluating `HAVING` clause
+    pub group: Option<&'r [Vec<Variant>]>,
+}
+
+impl<'r> From<&'r [Variant]> for Row<'r> {
+    fn from(values: &'r [Variant]) -> Self {
+        Row {
+            values,
+            group: None,
+        }
+    }
+}
+
+/// Evaluate where clause on `row` with SQL three-valued logic,
+/// `None` stands for `UNKNOWN`.
+pub fn eval(scope: &Scope, row: Row<'_>, expr: &CondExpr) -> anyhow::Result<Option<bool>> {
+    match expr.op {
+        CondOp::And => {
+            let lhs = eval_logical(scope, row, &expr.lhs)?;
+            let rhs = eval_logical(scope, row, &expr.rhs)?;
+
+            Ok(match (lhs, rhs) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            })
+        }
+        CondOp::Or => {
+            let lhs = eval_logical(scope, row, &expr.lhs)?;
+            let rhs = eval_logical(scope, row, &expr.rhs)?;
+
+            Ok(match (lhs, rhs) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            })
+        }
+        CondOp::Not => Ok(eval_logical(scope, row, &expr.lhs)?.map(|value| !value)),
+        CondOp::Exists => match &expr.lhs {
+            CondParam::Subquery(selecter) => Ok(Some(!subquery(scope, row, selecter)?.is_empty())),
+            param => Err(anyhow::format_err!(
+                "EXISTS operand must be subquery, got {:?}",
+                param
+            )),
+        },
+        CondOp::IsNull => Ok(Some(value(scope, row, &expr.lhs)? == Variant::Null)),
+        CondOp::IsNotNull => Ok(Some(value(scope, row, &expr.lhs)? != Variant::Null)),
+        CondOp::In | CondOp::NotIn => {
+            let lhs = value(scope, row, &expr.lhs)?;
+
+            let matched = match &expr.rhs {
+                CondParam::VariantList(values) => in_list(&lhs, values)?,
+                CondParam::Subquery(selecter) => {
+                    let values = subquery(scope, row, selecter)?
+                        .into_iter()
+                        .map(single)
+                        .collect::<anyhow::Result<Vec<_>>>()?;
+
+                    in_list(&lhs, &values)?
+                }
+                param => {
+                    return Err(anyhow::format_err!("IN expect value list, got {:?}", param));
+                }
+            };
+
+            Ok(matched.map(|matched| matched != (expr.op == CondOp::NotIn)))
+        }
+        CondOp::Between | CondOp::NotBetween => {
+            let lhs = value(scope, row, &expr.lhs)?;
+
+            let (low, high) = match &expr.rhs {
+                CondParam::Range(low, high) => (value(scope, row, low)?, value(scope, row, high)?),
+                param => {
+                    return Err(anyhow::format_err!("BETWEEN expect range, got {:?}", param));
+                }
+            };
+
+            let ge = compare(&lhs, &low)?.map(|ordering| ordering != Ordering::Less);
+            let le = compare(&lhs, &high)?.map(|ordering| ordering != Ordering::Greater);
+
+            let matched = match (ge, le) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            };
+
+            Ok(matched.map(|matched| matched != (expr.op == CondOp::NotBetween)))
+        }
+        CondOp::Like | CondOp::NotLike => {
+            let lhs = value(scope, row, &expr.lhs)?;
+            let rhs = value(scope, row, &expr.rhs)?;
+
+            match (lhs, rhs) {
+                (Variant::Null, _) | (_, Variant::Null) => Ok(None),
+                (Variant::String(value), Variant::String(pattern)) => {
+                    let value = value.chars().collect::<Vec<_>>();
+                    let pattern = pattern.chars().collect::<Vec<_>>();
+
+                    Ok(Some(like(&value, &pattern) != (expr.op == CondOp::NotLike)))
+                }
+                (lhs, rhs) => Err(anyhow::format_err!(
+                    "LIKE expect string operands, got {:?} and {:?}",
+                    lhs,
+                    rhs
+                )),
+            }
+        }
+        _ => {
+            let lhs = value(scope, row, &expr.lhs)?;
+            let rhs = value(scope, row, &expr.rhs)?;
+
+            let ordering = match compare(&lhs, &rhs)? {
+                Some(ordering) => ordering,
+                None => return Ok(None),
+            };
+
+            Ok(Some(match expr.op {
+                CondOp::Eq => ordering == Ordering::Equal,
+                CondOp::NotEq => ordering != Ordering::Equal,
+                CondOp::Gt => ordering == Ordering::Greater,
+                CondOp::Lt => ordering == Ordering::Less,
+                CondOp::Gte => ordering != Ordering::Less,
+                CondOp::Lte => ordering != Ordering::Greater,
+                _ => unreachable!(),
+            }))
+        }
+    }
+}
+
+fn eval_logical(scope: &Scope, row: Row<'_>, param: &CondParam) -> anyhow::Result<Option<bool>> {
+    match param {
+        CondParam::CondExpr(expr) => eval(scope, row, expr),
+        _ => Err(anyhow::format_err!(
+            "AND/OR operands must be cond exprs, got {:?}",
+            param
+        )),
+    }
+}
+
+fn value(scope: &Scope, row: Row<'_>, param: &CondParam) -> anyhow::Result<Variant> {
+    match param {
+        CondParam::Param(value) => Ok(value.clone()),
+        CondParam::CondExpr(expr) => Ok(match eval(scope, row, expr)? {
+            Some(value) => Variant::Int(value as i64),
+            None => Variant::Null,
+        }),
+        CondParam::Column(col) => scope.lookup(row.values, col.table.as_deref(), &col.name),
+        CondParam::Subquery(selecter) => {
+            let mut rows = subquery(scope, row, selecter)?;
+
+            match rows.len() {
+                0 => Ok(Variant::Null),
+                1 => single(rows.remove(0)),
+                len => Err(anyhow::format_err!("Scalar subquery returns {} rows", len)),
+            }
+        }
+        CondParam::Aggregate(func, col) => match row.group {
+            Some(group) => aggregate(scope, func, col.table.as_deref(), &col.name, group),
+            None => Err(anyhow::format_err!(
+                "Aggregate function only allowed in HAVING clause"
+            )),
+        },
+        CondParam::VariantList(_) => Err(anyhow::format_err!(
+            "Value list only allowed on the rhs of IN"
+        )),
+        CondParam::Range(..) => Err(anyhow::format_err!(
+            "Range only allowed on the rhs of BETWEEN"
+        )),
+        CondParam::Empty => Err(anyhow::format_err!(
+            "Empty cond param is only valid for unary ops"
+        )),
+    }
+}
+
+/// Run `selecter` as subquery correlated to `row`.
+fn subquery(
+    scope: &Scope<'_>,
+    row: Row<'_>,
+    selecter: &Selecter<'_>,
+) -> anyhow::Result<Vec<Vec<Variant>>> {
+    let (_, rows) = super::query(scope.db, selecter, Some((scope, row.values)))?;
+
+    Ok(rows)
+}
+
+/// Unwrap value of subquery row, which must have exactly one col.
+fn single(mut row: Vec<Variant>) -> anyhow::Result<Variant> {
+    if row.len() != 1 {
+        return Err(anyhow::format_err!(
+            "Subquery must return one col, got {}",
+            row.len()
+        ));
+    }
+
+    Ok(row.remove(0))
+}
+
+fn in_list(lhs: &Variant, values: &[Variant]) -> anyhow::Result<Option<bool>> {
+    let mut result = Some(false);
+
+    for value in values {
+        match compare(lhs, value)? {
+            Some(Ordering::Equal) => return Ok(Some(true)),
+            Some(_) => {}
+            None => result = None,
+        }
+    }
+
+    Ok(result)
+}
+
+/// Match `LIKE` pattern, `%` matches any sequence and `_` matches one char.
+fn like(value: &[char], pattern: &[char]) -> bool {
+    match pattern.split_first() {
+        None => value.is_empty(),
+        Some(('%', rest)) => (0..=value.len()).any(|idx| like(&value[idx..], rest)),
+        Some(('_', rest)) => !value.is_empty() && like(&value[1..], rest),
+        Some((c, rest)) => value.first() == Some(c) && like(&value[1..], rest),
+    }
+}
+
+/// Compare two values, returns `None` if either side is `NULL`.
+pub fn compare(lhs: &Variant, rhs: &Variant) -> anyhow::Result<Option<Ordering>> {
+    let ordering = match (lhs, rhs) {
+        (Variant::Null, _) | (_, Variant::Null) => return Ok(None),
+        (Variant::Bool(lhs), Variant::Bool(rhs)) => lhs.cmp(rhs),
+        (Variant::Int(lhs), Variant::Int(rhs)) => lhs.cmp(rhs),
+        (Variant::Int(lhs), Variant::Float(rhs)) => return Ok((*lhs as f64).partial_cmp(rhs)),
+        (Variant::Float(lhs), Variant::Int(rhs)) => return Ok(lhs.partial_cmp(&(*rhs as f64))),
+        (Variant::Float(lhs), Variant::Float(rhs)) => return Ok(lhs.partial_cmp(rhs)),
+        (Variant::Int(lhs), Variant::BigInt(rhs)) => BigInt::from(*lhs).cmp(rhs),
+        (Variant::BigInt(lhs), Variant::Int(rhs)) => lhs.cmp(&BigInt::from(*rhs)),
+        (Variant::BigInt(lhs), Variant::BigInt(rhs)) => lhs.cmp(rhs),
+        (Variant::BigFloat(lhs), Variant::BigFloat(rhs)) => lhs.cmp(rhs),
+        (Variant::BigFloat(lhs), Variant::Int(rhs)) => {
+            lhs.cmp(&BigRational::from_integer((*rhs).into()))
+        }
+        (Variant::Int(lhs), Variant::BigFloat(rhs)) => {
+            BigRational::from_integer((*lhs).into()).cmp(rhs)
+        }
+        (Variant::BigFloat(lhs), Variant::BigInt(rhs)) => {
+            lhs.cmp(&BigRational::from_integer(rhs.clone()))
+        }
+        (Variant::BigInt(lhs), Variant::BigFloat(rhs)) => {
+            BigRational::from_integer(lhs.clone()).cmp(rhs)
+        }
+        (Variant::BigFloat(lhs), Variant::Float(rhs)) => match BigRational::from_f64(*rhs) {
+            Some(rhs) => lhs.cmp(&rhs),
+            None => return Ok(None),
+        },
+        (Variant::Float(lhs), Variant::BigFloat(rhs)) => match BigRational::from_f64(*lhs) {
+            Some(lhs) => lhs.cmp(rhs),
+            None => return Ok(None),
+        },
+        (Variant::String(lhs), Variant::String(rhs)) => lhs.cmp(rhs),
+        (Variant::Bytes(lhs), Variant::Bytes(rhs)) => lhs.cmp(rhs),
+        (Variant::Date(lhs), Variant::Date(rhs)) => lhs.cmp(rhs),
+        (Variant::Time(lhs), Variant::Time(rhs)) => lhs.cmp(rhs),
+        (Variant::Timestamp(lhs), Variant::Timestamp(rhs)) => lhs.cmp(rhs),
+        // Compare instants, regardless of utc offsets.
+        (Variant::DateTime(lhs), Variant::DateTime(rhs)) => lhs.cmp(rhs),
+        (Variant::Interval(lhs), Variant::Interval(rhs)) => lhs.cmp(rhs),
+        (Variant::Uuid(lhs), Variant::Uuid(rhs)) => lhs.cmp(rhs),
+        _ => {
+            return Err(anyhow::format_err!(
+                "Can't compare {:?} with {:?}",
+                lhs,
+                rhs
+            ))
+        }
+    };
+
+    Ok(Some(ordering))
+}
+
+/// Compute aggregate function over `group` rows, `NULL` values are skipped.
+pub fn aggregate(
+    scope: &Scope,
+    func: &AggregateFunc,
+    table: Option<&str>,
+    name: &str,
+    group: &[Vec<Variant>],
+) -> anyhow::Result<Variant> {
+    if name == "*" {
+        return match func {
+            AggregateFunc::Count => Ok(Variant::Int(group.len() as i64)),
+            _ => Err(anyhow::format_err!("Only COUNT accepts *")),
+        };
+    }
+
+    let index = scope.resolve(table, name)?;
+
+    let mut values = group
+        .iter()
+        .map(|row| &row[index])
+        .filter(|value| **value != Variant::Null);
+
+    match func {
+        AggregateFunc::Count => Ok(Variant::Int(values.count() as i64)),
+        AggregateFunc::Sum => values.try_fold(Variant::Null, |sum, value| add(&sum, value)),
+        AggregateFunc::Avg => {
+            let mut count = 0;
+            let mut sum = Variant::Null;
+
+            for value in values {
+                sum = add(&sum, value)?;
+                count += 1;
+            }
+
+            match sum {
+                Variant::Null => Ok(Variant::Null),
+                Variant::Int(sum) => Ok(Variant::Float(sum as f64 / count as f64)),
+                Variant::Float(sum) => Ok(Variant::Float(sum / count as f64)),
+                Variant::BigInt(sum) => Ok(Variant::BigFloat(BigRational::new(
+                    sum,
+                    BigInt::from(count),
+                ))),
+                Variant::BigFloat(sum) => Ok(Variant::BigFloat(sum / BigInt::from(count))),
+                _ => unreachable!(),
+            }
+        }
+        AggregateFunc::Min | AggregateFunc::Max => {
+            let mut result = Variant::Null;
+
+            for value in values {
+                let ordering = match compare(value, &result)? {
+                    Some(ordering) => ordering,
+                    None => {
+                        result = value.clone();
+                        continue;
+                    }
+                };
+
+                if (*func == AggregateFunc::Min) == (ordering == Ordering::Less) {
+                    result = value.clone();
+                }
+            }
+
+            Ok(result)
+        }
+    }
+}
+
+/// Numeric addition used by `SUM`, `NULL` is the empty sum.
+fn add(lhs: &Variant, rhs: &Variant) -> anyhow::Result<Variant> {
+    let value = match (lhs, rhs) {
+        (Variant::Null, value) => match value {
+            Variant::Int(_) | Variant::Float(_) | Variant::BigInt(_) | Variant::BigFloat(_) => {
+                value.clone()
+            }
+            _ => return Err(anyhow::format_err!("Can't sum {:?}", value)),
+        },
+        (Variant::Int(lhs), Variant::Int(rhs)) => match lhs.checked_add(*rhs) {
+            Some(sum) => Variant::Int(sum),
+            None => Variant::BigInt(BigInt::from(*lhs) + rhs),
+        },
+        (Variant::Int(lhs), Variant::Float(rhs)) | (Variant::Float(rhs), Variant::Int(lhs)) => {
+            Variant::Float(*lhs as f64 + rhs)
+        }
+        (Variant::Float(lhs), Variant::Float(rhs)) => Variant::Float(lhs + rhs),
+        (Variant::BigInt(lhs), Variant::Int(rhs)) | (Variant::Int(rhs), Variant::BigInt(lhs)) => {
+            Variant::BigInt(lhs + rhs)
+        }
+        (Variant::BigInt(lhs), Variant::BigInt(rhs)) => Variant::BigInt(lhs + rhs),
+        (Variant::BigFloat(lhs), Variant::BigFloat(rhs)) => Variant::BigFloat(lhs + rhs),
+        (Variant::BigFloat(lhs), Variant::Int(rhs))
+        | (Variant::Int(rhs), Variant::BigFloat(lhs)) => {
+            Variant::BigFloat(lhs + BigInt::from(*rhs))
+        }
+        (Variant::BigFloat(lhs), Variant::BigInt(rhs))
+        | (Variant::BigInt(rhs), Variant::BigFloat(lhs)) => Variant::BigFloat(lhs + rhs.clone()),
+        _ => return Err(anyhow::format_err!("Can't sum {:?} and {:?}", lhs, rhs)),
+    };
+
+    Ok(value)
+}
+
+/// Ordering of an `ORDER BY` term, by default `NULL` sorts first in ascending order
+/// as in SQLite and MySQL.
+pub fn order(lhs: &Variant, rhs: &Variant, order_by: &OrderBy<'_>) -> anyhow::Result<Ordering> {
+    let nulls_first = match order_by.nulls {
+        Some(Nulls::First) => true,
+        Some(Nulls::Last) => false,
+        None => !order_by.desc,
+    };
+
+    let ordering = match (lhs, rhs) {
+        (Variant::Null, Variant::Null) => return Ok(Ordering::Equal),
+        (Variant::Null, _) if nulls_first => return Ok(Ordering::Less),
+        (Variant::Null, _) => return Ok(Ordering::Greater),
+        (_, Variant::Null) if nulls_first => return Ok(Ordering::Greater),
+        (_, Variant::Null) => return Ok(Ordering::Less),
+        _ => compare(lhs, rhs)?.unwrap_or(Ordering::Equal),
+    };
+
+    if order_by.desc {
+        Ok(ordering.reverse())
+    } else {
+        Ok(ordering)
+    }
+}
@@ -0,0 +1,434 @@
+use std::cmp::Ordering;
+
+use crate::{
+    ddl::{Column, Constraint, NamedConstraint},
+    IrType, Variant,
+};
+
+use super::eval::compare;
+
+/// Owned column define.
+#[derive(Debug, Clone)]
+pub struct TableColumn {
+    pub name: String,
+    pub col_type: IrType,
+    pub not_null: bool,
+    pub default_value: Option<Variant>,
+    /// `Some(true)` for auto increment primary column
+    pub primary: Option<bool>,
+}
+
+impl<'a> From<&Column<'a>> for TableColumn {
+    fn from(col: &Column<'a>) -> Self {
+        Self {
+            name: col.name.to_owned(),
+            col_type: col.col_type.clone(),
+            not_null: col.not_null,
+            default_value: col.default_value.clone(),
+            primary: col.primary,
+        }
+    }
+}
+
+/// Owned table constraint, only `Unique` is enforced.
+#[derive(Debug, Clone)]
+pub enum TableConstraint {
+    Unique(Vec<String>),
+    Index(Vec<String>),
+    /// Local cols only, references are not checked
+    ForeignKey(Vec<String>),
+    Check,
+}
+
+impl TableConstraint {
+    fn cols_mut(&mut self) -> Option<&mut Vec<String>> {
+        match self {
+            TableConstraint::Unique(cols)
+            | TableConstraint::Index(cols)
+            | TableConstraint::ForeignKey(cols) => Some(cols),
+            TableConstraint::Check => None,
+        }
+    }
+}
+
+impl<'a> From<&Constraint<'a>> for TableConstraint {
+    fn from(constraint: &Constraint<'a>) -> Self {
+        let owned = |cols: &[&str]| cols.iter().map(|c| c.to_string()).collect();
+
+        match constraint {
+            Constraint::Unique(cols) => TableConstraint::Unique(owned(cols)),
+            Constraint::Index(cols) => TableConstraint::Index(owned(cols)),
+            Constraint::ForeignKey(cols, _, _) => TableConstraint::ForeignKey(owned(cols)),
+            Constraint::Check => TableConstraint::Check,
+        }
+    }
+}
+
+/// In-memory table storage.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub cols: Vec<TableColumn>,
+    pub constraints: Vec<(String, TableConstraint)>,
+    pub rows: Vec<Vec<Variant>>,
+    /// Next auto increment id, starting from 1
+    pub next_id: i64,
+}
+
+impl Table {
+    pub fn new(cols: &[Column<'_>], constraints: &[NamedConstraint<'_>]) -> anyhow::Result<Self> {
+        let mut table = Table {
+            next_id: 1,
+            ..Default::default()
+        };
+
+        for col in cols {
+            table.add_col(col)?;
+        }
+
+        for constraint in constraints {
+            table.add_constraint(constraint)?;
+        }
+
+        Ok(table)
+    }
+
+    pub fn col_index(&self, name: &str) -> anyhow::Result<usize> {
+        self.cols
+            .iter()
+            .position(|col| col.name == name)
+            .ok_or_else(|| anyhow::format_err!("Not found col {}", name))
+    }
+
+    fn constraint_index(&self, name: &str) -> anyhow::Result<usize> {
+        self.constraints
+            .iter()
+            .position(|(n, _)| n == name)
+            .ok_or_else(|| anyhow::format_err!("Not found constraint {}", name))
+    }
+
+    pub fn add_col(&mut self, col: &Column<'_>) -> anyhow::Result<()> {
+        if self.col_index(col.name).is_ok() {
+            return Err(anyhow::format_err!("Duplicate col {}", col.name));
+        }
+
+        if col.primary.is_some() && self.cols.iter().any(|c| c.primary.is_some()) {
+            return Err(anyhow::format_err!(
+                "Add second primary col {} not support",
+                col.name
+            ));
+        }
+
+        let value = col.default_value.clone().unwrap_or(Variant::Null);
+
+        if !self.rows.is_empty()
+            && value == Variant::Null
+            && (col.not_null || col.primary.is_some())
+        {
+            return Err(anyhow::format_err!(
+                "Add not null col {} without default value to non-empty table",
+                col.name
+            ));
+        }
+
+        for row in &mut self.rows {
+            row.push(value.clone());
+        }
+
+        self.cols.push(col.into());
+
+        Ok(())
+    }
+
+    pub fn drop_col(&mut self, name: &str) -> anyhow::Result<()> {
+        let index = self.col_index(name)?;
+
+        self.cols.remove(index);
+
+        for row in &mut self.rows {
+            row.remove(index);
+        }
+
+        // Drop constraints referring the col, as SQLite and PostgreSQL do.
+        self.constraints
+            .retain_mut(|(_, constraint)| match constraint.cols_mut() {
+                Some(cols) => !cols.iter().any(|c| c == name),
+                None => true,
+            });
+
+        Ok(())
+    }
+
+    pub fn alter_col(&mut self, col: &Column<'_>) -> anyhow::Result<()> {
+        let index = self.col_index(col.name)?;
+
+        let old = std::mem::replace(&mut self.cols[index], col.into());
+
+        if let Err(err) = self.check(&self.rows) {
+            self.cols[index] = old;
+
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn rename_col(&mut self, from: &str, to: &str) -> anyhow::Result<()> {
+        if self.col_index(to).is_ok() {
+            return Err(anyhow::format_err!("Duplicate col {}", to));
+        }
+
+        let index = self.col_index(from)?;
+
+        self.cols[index].name = to.to_owned();
+
+        for (_, constraint) in &mut self.constraints {
+            if let Some(cols) = constraint.cols_mut() {
+                for col in cols.iter_mut().filter(|c| *c == from) {
+                    *col = to.to_owned();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn add_constraint(&mut self, constraint: &NamedConstraint<'_>) -> anyhow::Result<()> {
+        if self.constraint_index(constraint.name).is_ok() {
+            return Err(anyhow::format_err!(
+                "Duplicate constraint {}",
+                constraint.name
+            ));
+        }
+
+        let constraint = (constraint.name.to_owned(), (&constraint.constraint).into());
+
+        if let (_, TableConstraint::Unique(cols)) = &constraint {
+            let indexes = self.col_indexes(cols)?;
+
+            self.check_unique(&self.rows, &indexes, &constraint.0)?;
+        }
+
+        self.constraints.push(constraint);
+
+        Ok(())
+    }
+
+    pub fn alter_constraint(&mut self, constraint: &NamedConstraint<'_>) -> anyhow::Result<()> {
+        let index = self.constraint_index(constraint.name)?;
+
+        let old = self.constraints.remove(index);
+
+        if let Err(err) = self.add_constraint(constraint) {
+            self.constraints.insert(index, old);
+
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn drop_constraint(&mut self, name: &str) -> anyhow::Result<()> {
+        let index = self.constraint_index(name)?;
+
+        self.constraints.remove(index);
+
+        Ok(())
+    }
+
+    pub fn rename_constraint(&mut self, from: &str, to: &str) -> anyhow::Result<()> {
+        if self.constraint_index(to).is_ok() {
+            return Err(anyhow::format_err!("Duplicate constraint {}", to));
+        }
+
+        let index = self.constraint_index(from)?;
+
+        self.constraints[index].0 = to.to_owned();
+
+        Ok(())
+    }
+
+    fn col_indexes(&self, cols: &[String]) -> anyhow::Result<Vec<usize>> {
+        cols.iter().map(|col| self.col_index(col)).collect()
+    }
+
+    /// Create new row from insert cols and values, filling omitted cols with default values.
+    pub fn new_row(&mut self, cols: &[&str], values: Vec<Variant>) -> anyhow::Result<Vec<Variant>> {
+        if cols.len() != values.len() {
+            return Err(anyhow::format_err!(
+                "Insert {} cols with {} values",
+                cols.len(),
+                values.len()
+            ));
+        }
+
+        let mut row = self
+            .cols
+            .iter()
+            .map(|col| col.default_value.clone().unwrap_or(Variant::Null))
+            .collect::<Vec<_>>();
+
+        for (col, value) in cols.iter().zip(values) {
+            row[self.col_index(col)?] = value;
+        }
+
+        Ok(row)
+    }
+
+    /// Whether cols at `indexes` are the primary col or the cols of a unique constraint,
+    /// in any order, as an upsert conflict target must be.
+    pub fn is_unique_key(&self, indexes: &[usize]) -> bool {
+        let mut indexes = indexes.to_vec();
+
+        indexes.sort();
+
+        if let Some(index) = self.cols.iter().position(|col| col.primary.is_some()) {
+            if indexes == [index] {
+                return true;
+            }
+        }
+
+        self.constraints
+            .iter()
+            .any(|(_, constraint)| match constraint {
+                TableConstraint::Unique(cols) => self.col_indexes(cols).is_ok_and(|mut unique| {
+                    unique.sort();
+                    unique == indexes
+                }),
+                _ => false,
+            })
+    }
+
+    /// Find index of the row in `rows` having the same `indexes` values as `row`.
+    ///
+    /// Rows containing `NULL` never conflict, as in SQL.
+    pub fn find_conflict(
+        &self,
+        rows: &[Vec<Variant>],
+        row: &[Variant],
+        indexes: &[usize],
+    ) -> anyhow::Result<Option<usize>> {
+        let key = indexes.iter().map(|idx| &row[*idx]).collect::<Vec<_>>();
+
+        if key.contains(&&Variant::Null) {
+            return Ok(None);
+        }
+
+        for (row_idx, other) in rows.iter().enumerate() {
+            let other_key = indexes.iter().map(|idx| &other[*idx]).collect::<Vec<_>>();
+
+            if !other_key.contains(&&Variant::Null) && cmp_key(&key, &other_key)? == Ordering::Equal
+            {
+                return Ok(Some(row_idx));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Check col types, not null and unique constraints of `rows`, returns error on violation.
+    pub fn check(&self, rows: &[Vec<Variant>]) -> anyhow::Result<()> {
+        for row in rows {
+            for (col, value) in self.cols.iter().zip(row) {
+                if (col.not_null || col.primary.is_some()) && *value == Variant::Null {
+                    return Err(anyhow::format_err!("Col {} can't be null", col.name));
+                }
+
+                if !accepts(&col.col_type, value) {
+                    return Err(anyhow::format_err!(
+                        "Col {} of type {:?} can't store {:?}",
+                        col.name,
+                        col.col_type,
+                        value
+                    ));
+                }
+            }
+        }
+
+        if let Some(index) = self.cols.iter().position(|col| col.primary.is_some()) {
+            self.check_unique(rows, &[index], "PRIMARY")?;
+        }
+
+        for (name, constraint) in &self.constraints {
+            if let TableConstraint::Unique(cols) = constraint {
+                self.check_unique(rows, &self.col_indexes(cols)?, name)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rows containing `NULL` never conflict, as in SQL.
+    fn check_unique(
+        &self,
+        rows: &[Vec<Variant>],
+        indexes: &[usize],
+        name: &str,
+    ) -> anyhow::Result<()> {
+        let mut keys = rows
+            .iter()
+            .map(|row| indexes.iter().map(|idx| &row[*idx]).collect::<Vec<_>>())
+            .filter(|key| !key.contains(&&Variant::Null))
+            .collect::<Vec<_>>();
+
+        let mut err = None;
+
+        keys.sort_by(|lhs, rhs| {
+            cmp_key(lhs, rhs).unwrap_or_else(|e| {
+                err = Some(e);
+                Ordering::Equal
+            })
+        });
+
+        if let Some(err) = err {
+            return Err(err);
+        }
+
+        for pair in keys.windows(2) {
+            if cmp_key(&pair[0], &pair[1])? == Ordering::Equal {
+                return Err(anyhow::format_err!(
+                    "Unique constraint {} violated by {:?}",
+                    name,
+                    pair[0]
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn cmp_key(lhs: &[&Variant], rhs: &[&Variant]) -> anyhow::Result<Ordering> {
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        match compare(lhs, rhs)? {
+            Some(Ordering::Equal) | None => {}
+            Some(ordering) => return Ok(ordering),
+        }
+    }
+
+    Ok(Ordering::Equal)
+}
+
+/// Whether col of `col_type` can store `value`, integers widen to any numeric type.
+fn accepts(col_type: &IrType, value: &Variant) -> bool {
+    matches!(
+        (col_type, value),
+        (_, Variant::Null)
+            | (IrType::Bool, Variant::Bool(_))
+            | (IrType::Int, Variant::Int(_))
+            | (IrType::BigInt, Variant::Int(_) | Variant::BigInt(_))
+            | (IrType::Float, Variant::Int(_) | Variant::Float(_))
+            | (
+                IrType::Decimal,
+                Variant::Int(_) | Variant::BigInt(_) | Variant::Float(_) | Variant::BigFloat(_)
+            )
+            | (IrType::String, Variant::String(_))
+            | (IrType::Bytes, Variant::Bytes(_))
+            | (IrType::Date, Variant::Date(_))
+            | (IrType::Time, Variant::Time(_))
+            | (IrType::Timestamp, Variant::Timestamp(_))
+            | (IrType::DateTime, Variant::DateTime(_))
+            | (IrType::Interval, Variant::Interval(_))
+            | (IrType::Json, Variant::Json(_))
+            | (IrType::Uuid, Variant::Uuid(_))
+    )
+}
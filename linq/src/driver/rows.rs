@@ -0,0 +1,58 @@
+use crate::Variant;
+
+use super::QueryIterator;
+
+/// [`QueryIterator`] over materialized rows.
+pub struct Rows {
+    col_names: Vec<String>,
+    rows: std::vec::IntoIter<Vec<Variant>>,
+    current: Option<Vec<Variant>>,
+}
+
+impl Rows {
+    /// Create result set, every row must hold one value per `col_names` entry.
+    pub fn new(col_names: Vec<String>, rows: Vec<Vec<Variant>>) -> Self {
+        Self {
+            col_names,
+            rows: rows.into_iter(),
+            current: None,
+        }
+    }
+
+    /// Result set column names
+    pub fn col_names(&self) -> &[String] {
+        &self.col_names
+    }
+
+    fn current(&self) -> anyhow::Result<&Vec<Variant>> {
+        self.current
+            .as_ref()
+            .ok_or_else(|| anyhow::format_err!("Call next first"))
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryIterator for Rows {
+    async fn next(&mut self) -> anyhow::Result<bool> {
+        self.current = self.rows.next();
+
+        Ok(self.current.is_some())
+    }
+
+    async fn get(&mut self, offset: usize) -> anyhow::Result<Variant> {
+        self.current()?
+            .get(offset)
+            .cloned()
+            .ok_or_else(|| anyhow::format_err!("Column offset {} out of range", offset))
+    }
+
+    async fn get_by_name(&mut self, name: &str) -> anyhow::Result<Variant> {
+        let offset = self
+            .col_names
+            .iter()
+            .position(|col_name| col_name == name)
+            .ok_or_else(|| anyhow::format_err!("Not found col {}", name))?;
+
+        self.get(offset).await
+    }
+}
@@ -0,0 +1,363 @@
+//! Reference [`SQLite`](https://www.sqlite.org) driver, enabled by the `sqlite` feature.
+//!
+//! The driver renders IR with [`render::Sqlite`] and executes it on an embedded
+//! database. Calls block the current task, which is fine for tests and tools,
+//! but production services should prefer a real async driver.
+//!
+//! # Examples
+//!
+//! ```
+//! use linq_rs::{driver::{sqlite::SqliteDriver, DDLSupport, InsertSupport}, *};
+//!
+//! # async_std::task::block_on(async {
+//! let mut d = SqliteDriver::open_in_memory()?;
+//!
+//! d.exec_ddl(&ddl! {
+//!     CREATE TABLE user(id INT PRIMARY AUTOINC, name STRING);
+//! }).await?;
+//!
+//! let result = d.insert(&rql! { INSERT INTO user(name) }, vec!["hello".into()]).await?;
+//!
+//! assert_eq!(result.last_insert_id(), Some(1));
+//! # anyhow::Ok(())
+//! # }).unwrap();
+//! ```
+
+use std::path::Path;
+
+use rusqlite::{
+    params_from_iter,
+    types::{Value, ValueRef},
+    Connection,
+};
+
+use crate::{
+    ddl, dml,
+    render::{self, Dialect, Sqlite, Statement, DATE_FORMAT, TIMESTAMP_FORMAT, TIME_FORMAT},
+    Date, DateTime, Interval, Time, Timestamp, Variant,
+};
+
+use super::{
+    DDLSupport, DeleteSupport, ExecResult, InsertSupport, Rows, SelectSupport, TransactionSupport,
+    UpdateSupport,
+};
+
+/// SQLite driver implementing every `driver` trait.
+pub struct SqliteDriver {
+    conn: Connection,
+}
+
+// SAFETY: `Connection` is `Send` but not `Sync`. It is private and only reached
+// through `&mut self`, so a shared `&SqliteDriver` can't touch it.
+unsafe impl Sync for SqliteDriver {}
+
+impl SqliteDriver {
+    /// Open database file, create it if not exists.
+    pub fn open<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        Ok(Connection::open(path)?.into())
+    }
+
+    /// Open a new in-memory database.
+    pub fn open_in_memory() -> anyhow::Result<Self> {
+        Ok(Connection::open_in_memory()?.into())
+    }
+
+    /// Get underlying connection.
+    pub fn conn(&mut self) -> &mut Connection {
+        &mut self.conn
+    }
+
+    fn execute(&mut self, stmt: Statement) -> anyhow::Result<usize> {
+        log::debug!("sqlite execute: {}", stmt.sql);
+
+        let params = stmt
+            .params
+            .into_iter()
+            .map(to_value)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let conn = self.conn();
+
+        // Cached, as `insert_many` runs the same stmt for every row.
+        let mut prepared = conn.prepare_cached(&stmt.sql)?;
+
+        Ok(prepared.execute(params_from_iter(params))?)
+    }
+
+    /// Run `stmt` and collect all rows with col names.
+    fn query(&mut self, stmt: Statement) -> anyhow::Result<(Vec<String>, Vec<Vec<Variant>>)> {
+        log::debug!("sqlite query: {}", stmt.sql);
+
+        let params = stmt
+            .params
+            .into_iter()
+            .map(to_value)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let conn = self.conn();
+
+        let mut prepared = conn.prepare(&stmt.sql)?;
+
+        let mut col_names = vec![];
+        let mut col_types = vec![];
+
+        for col in prepared.columns() {
+            col_names.push(col.name().to_owned());
+            col_types.push(col.decl_type().map(|t| t.to_uppercase()));
+        }
+
+        let mut rows = prepared.query(params_from_iter(params))?;
+
+        let mut result = vec![];
+
+        while let Some(row) = rows.next()? {
+            let mut values = vec![];
+
+            for (idx, col_type) in col_types.iter().enumerate() {
+                values.push(from_value_ref(row.get_ref(idx)?, col_type.as_deref())?);
+            }
+
+            result.push(values);
+        }
+
+        Ok((col_names, result))
+    }
+
+    /// Execute dml `stmt`, rows of `RETURNING` clause are collected if `returning` is set.
+    fn execute_dml(&mut self, stmt: Statement, returning: bool) -> anyhow::Result<ExecResult> {
+        if returning {
+            let (_, rows) = self.query(stmt)?;
+
+            Ok(ExecResult {
+                affected_rows: rows.len(),
+                returning: rows,
+                ..Default::default()
+            })
+        } else {
+            Ok(ExecResult::affected(self.execute(stmt)?))
+        }
+    }
+
+    fn execute_batch(&mut self, sql: &str) -> anyhow::Result<()> {
+        log::debug!("sqlite execute: {}", sql);
+
+        Ok(self.conn().execute_batch(sql)?)
+    }
+}
+
+impl From<Connection> for SqliteDriver {
+    fn from(conn: Connection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> SelectSupport<'a> for SqliteDriver {
+    type SelectResult = Rows;
+
+    async fn select(&mut self, selecter: &dml::Selecter<'a>) -> anyhow::Result<Self::SelectResult> {
+        let (col_names, rows) = self.query(render::select(&Sqlite, selecter)?)?;
+
+        Ok(Rows::new(col_names, rows))
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> InsertSupport<'a> for SqliteDriver {
+    /// Generated key is the last insert rowid, it's unknown for upsert,
+    /// which may skip or update the row.
+    async fn insert(
+        &mut self,
+        inserter: &dml::Inserter<'a>,
+        values: Vec<Variant>,
+    ) -> anyhow::Result<ExecResult> {
+        let stmt = render::insert(&Sqlite, inserter, values)?;
+
+        let mut result = self.execute_dml(stmt, inserter.returning.is_some())?;
+
+        if inserter.on_conflict.is_none() && result.affected_rows == 1 {
+            result.generated_keys = vec![self.conn().last_insert_rowid()];
+        }
+
+        Ok(result)
+    }
+
+    /// Rows are inserted one by one inside a savepoint, so every generated key
+    /// is the real rowid of its row, even if some rows carry explicit keys.
+    async fn insert_many(
+        &mut self,
+        inserter: &dml::Inserter<'a>,
+        rows: Vec<Vec<Variant>>,
+    ) -> anyhow::Result<ExecResult> {
+        self.execute_batch("SAVEPOINT insert_many")?;
+
+        let mut result = ExecResult::default();
+
+        for values in rows {
+            match self.insert(inserter, values).await {
+                Ok(r) => result.append(r),
+                Err(err) => {
+                    self.execute_batch("ROLLBACK TO insert_many; RELEASE insert_many")?;
+
+                    return Err(err);
+                }
+            }
+        }
+
+        self.execute_batch("RELEASE insert_many")?;
+
+        Ok(result)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> UpdateSupport<'a> for SqliteDriver {
+    async fn update(
+        &mut self,
+        updater: &dml::Updater<'a>,
+        values: Vec<Variant>,
+    ) -> anyhow::Result<ExecResult> {
+        let stmt = render::update(&Sqlite, updater, values)?;
+
+        self.execute_dml(stmt, updater.returning.is_some())
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> DeleteSupport<'a> for SqliteDriver {
+    async fn delete(&mut self, deleter: &dml::Deleter<'a>) -> anyhow::Result<ExecResult> {
+        let stmt = render::delete(&Sqlite, deleter)?;
+
+        self.execute_dml(stmt, deleter.returning.is_some())
+    }
+}
+
+#[async_trait::async_trait]
+impl DDLSupport for SqliteDriver {
+    async fn exec_ddl<'a>(&mut self, ddls: &[ddl::DDL<'a>]) -> anyhow::Result<()> {
+        for ddl in ddls {
+            for stmt in render::ddl(&Sqlite, ddl)? {
+                self.execute(stmt)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionSupport for SqliteDriver {
+    async fn begin(&mut self) -> anyhow::Result<()> {
+        self.execute_batch("BEGIN")
+    }
+
+    async fn commit(&mut self) -> anyhow::Result<()> {
+        self.execute_batch("COMMIT")
+    }
+
+    async fn rollback(&mut self) -> anyhow::Result<()> {
+        self.execute_batch("ROLLBACK")
+    }
+
+    async fn savepoint(&mut self, name: &str) -> anyhow::Result<()> {
+        self.execute_batch(&format!("SAVEPOINT {}", Sqlite.quote_ident(name)))
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> anyhow::Result<()> {
+        self.execute_batch(&format!("RELEASE SAVEPOINT {}", Sqlite.quote_ident(name)))
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> anyhow::Result<()> {
+        self.execute_batch(&format!(
+            "ROLLBACK TO SAVEPOINT {}",
+            Sqlite.quote_ident(name)
+        ))
+    }
+
+    fn in_transaction(&mut self) -> bool {
+        !self.conn.is_autocommit()
+    }
+
+    fn rollback_on_drop(&mut self, savepoint: Option<&str>) {
+        let sql = match savepoint {
+            Some(name) => {
+                let name = Sqlite.quote_ident(name);
+
+                format!("ROLLBACK TO SAVEPOINT {}; RELEASE SAVEPOINT {}", name, name)
+            }
+            None => "ROLLBACK".to_owned(),
+        };
+
+        if let Err(err) = self.execute_batch(&sql) {
+            log::error!("sqlite rollback on drop: {}", err);
+        }
+    }
+}
+
+fn to_value(value: Variant) -> anyhow::Result<Value> {
+    let value = match value {
+        Variant::Null => Value::Null,
+        Variant::Bool(v) => Value::Integer(v as i64),
+        Variant::Int(v) => Value::Integer(v),
+        Variant::BigInt(v) => Value::Integer(
+            v.try_into()
+                .map_err(|err| anyhow::format_err!("BigInt out of sqlite range: {}", err))?,
+        ),
+        Variant::Float(v) => Value::Real(v),
+        // `numer/denom` text isn't a numeric literal, so NUMERIC affinity can't round it to REAL.
+        Variant::BigFloat(v) => match i64::try_from(v.to_integer()) {
+            Ok(int) if v.is_integer() => Value::Integer(int),
+            _ => Value::Text(format!("{}/{}", v.numer(), v.denom())),
+        },
+        Variant::String(v) => Value::Text(v),
+        Variant::Bytes(v) => Value::Blob(v),
+        Variant::Date(v) => Value::Text(v.format(DATE_FORMAT).to_string()),
+        Variant::Time(v) => Value::Text(v.format(TIME_FORMAT).to_string()),
+        Variant::Timestamp(v) => Value::Text(v.format(TIMESTAMP_FORMAT).to_string()),
+        // Normalized to utc, so text comparison matches instant comparison.
+        Variant::DateTime(v) => Value::Text(v.to_utc().to_rfc3339()),
+        Variant::Interval(v) => Value::Integer(render::interval_micros(&v)?),
+        Variant::Json(v) => Value::Text(v.to_string()),
+        Variant::Uuid(v) => Value::Blob(v.to_vec()),
+    };
+
+    Ok(value)
+}
+
+/// Decode column value, `col_type` is the upper case declared column type.
+fn from_value_ref(value: ValueRef<'_>, col_type: Option<&str>) -> anyhow::Result<Variant> {
+    let value = match value {
+        ValueRef::Null => Variant::Null,
+        ValueRef::Integer(v) => match col_type {
+            Some("BOOLEAN") => Variant::Bool(v != 0),
+            Some("INTERVAL") => Variant::Interval(Interval::microseconds(v)),
+            _ => Variant::Int(v),
+        },
+        ValueRef::Real(v) => Variant::Float(v),
+        ValueRef::Blob(v) => match col_type {
+            Some("UUID") => Variant::Uuid(
+                v.try_into()
+                    .map_err(|_| anyhow::format_err!("Uuid expect 16 bytes, got {}", v.len()))?,
+            ),
+            _ => Variant::Bytes(v.to_vec()),
+        },
+        ValueRef::Text(v) => {
+            let text = std::str::from_utf8(v)?;
+
+            match col_type {
+                Some("DATE") => Variant::Date(Date::parse_from_str(text, DATE_FORMAT)?),
+                Some("TIME") => Variant::Time(Time::parse_from_str(text, TIME_FORMAT)?),
+                Some("TIMESTAMP") => {
+                    Variant::Timestamp(Timestamp::parse_from_str(text, TIMESTAMP_FORMAT)?)
+                }
+                Some("DATETIME") => Variant::DateTime(DateTime::parse_from_rfc3339(text)?),
+                Some("JSON") => Variant::Json(serde_json::from_str(text)?),
+                // Decimals other than i64 integers are stored as `numer/denom` text.
+                Some("NUMERIC") => Variant::BigFloat(text.parse()?),
+                _ => Variant::String(text.to_owned()),
+            }
+        }
+    };
+
+    Ok(value)
+}
@@ -0,0 +1,44 @@
+use futures::{future::BoxFuture, Stream};
+
+use super::QueryIterator;
+
+/// Adapt any [`QueryIterator`] into a [`Stream`], `decode` is called once per row.
+///
+/// # Examples
+///
+/// ```
+/// use futures::TryStreamExt;
+/// use linq_rs::driver::{into_stream, QueryIterator, Rows};
+///
+/// # async_std::task::block_on(async {
+/// let rows = Rows::new(vec!["id".into()], vec![vec![1.into()], vec![2.into()]]);
+///
+/// let ids: Vec<i64> = into_stream(rows, |rows: &mut Rows| {
+///     Box::pin(async move { i64::try_from(rows.get(0).await?) })
+/// })
+/// .try_collect()
+/// .await?;
+///
+/// assert_eq!(ids, vec![1, 2]);
+/// # anyhow::Ok(())
+/// # }).unwrap();
+/// ```
+pub fn into_stream<'a, I, T, F>(
+    rows: I,
+    decode: F,
+) -> impl Stream<Item = anyhow::Result<T>> + Send + 'a
+where
+    I: QueryIterator + Send + 'a,
+    T: Send + 'a,
+    F: for<'r> FnMut(&'r mut I) -> BoxFuture<'r, anyhow::Result<T>> + Send + 'a,
+{
+    futures::stream::try_unfold((rows, decode), |(mut rows, mut decode)| async move {
+        if !rows.next().await? {
+            return Ok(None);
+        }
+
+        let value = decode(&mut rows).await?;
+
+        Ok(Some((value, (rows, decode))))
+    })
+}
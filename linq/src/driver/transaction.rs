@@ -0,0 +1,133 @@
+use std::ops::{Deref, DerefMut};
+
+use super::TransactionSupport;
+
+/// Scoped transaction guard, rolls back on drop unless committed.
+///
+/// The guard derefs to the driver, so any `exec(&mut *tx)` call runs inside the transaction.
+/// Begun inside another transaction, the guard uses a savepoint instead.
+///
+/// # Examples
+///
+/// ```
+/// use linq_rs::{driver::{memory::MemoryDriver, DDLSupport, InsertSupport, Transaction}, *};
+///
+/// # async_std::task::block_on(async {
+/// let mut d = MemoryDriver::default();
+///
+/// d.exec_ddl(&ddl! {
+///     CREATE TABLE user(id INT PRIMARY AUTOINC, name STRING);
+/// }).await?;
+///
+/// let mut tx = Transaction::begin(&mut d).await?;
+///
+/// let result = tx.insert(&rql! { INSERT INTO user(name) }, vec!["hello".into()]).await;
+///
+/// tx.finish(result).await?;
+/// # anyhow::Ok(())
+/// # }).unwrap();
+/// ```
+pub struct Transaction<'d, D>
+where
+    D: TransactionSupport,
+{
+    d: &'d mut D,
+    done: bool,
+    /// Begun inside an outer transaction, as savepoint [`SAVEPOINT`].
+    nested: bool,
+}
+
+/// Savepoint name of nested transactions, each one shadows the outer ones.
+const SAVEPOINT: &str = "linq_tx";
+
+impl<'d, D> Transaction<'d, D>
+where
+    D: TransactionSupport,
+{
+    /// Begin transaction on driver `d`, or a savepoint if `d` is already in one.
+    pub async fn begin(d: &'d mut D) -> anyhow::Result<Transaction<'d, D>> {
+        let nested = d.in_transaction();
+
+        if nested {
+            d.savepoint(SAVEPOINT).await?;
+        } else {
+            d.begin().await?;
+        }
+
+        Ok(Self {
+            d,
+            done: false,
+            nested,
+        })
+    }
+
+    pub async fn commit(mut self) -> anyhow::Result<()> {
+        self.done = true;
+
+        if self.nested {
+            self.d.release_savepoint(SAVEPOINT).await
+        } else {
+            self.d.commit().await
+        }
+    }
+
+    pub async fn rollback(mut self) -> anyhow::Result<()> {
+        self.done = true;
+
+        if self.nested {
+            self.d.rollback_to_savepoint(SAVEPOINT).await?;
+            self.d.release_savepoint(SAVEPOINT).await
+        } else {
+            self.d.rollback().await
+        }
+    }
+
+    /// Commit if `result` is ok, otherwise rollback and return the original error.
+    pub async fn finish<T>(self, result: anyhow::Result<T>) -> anyhow::Result<T> {
+        match result {
+            Ok(value) => {
+                self.commit().await?;
+
+                Ok(value)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = self.rollback().await {
+                    log::error!("rollback failed: {}", rollback_err);
+                }
+
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<'d, D> Deref for Transaction<'d, D>
+where
+    D: TransactionSupport,
+{
+    type Target = D;
+
+    fn deref(&self) -> &Self::Target {
+        self.d
+    }
+}
+
+impl<'d, D> DerefMut for Transaction<'d, D>
+where
+    D: TransactionSupport,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.d
+    }
+}
+
+impl<'d, D> Drop for Transaction<'d, D>
+where
+    D: TransactionSupport,
+{
+    fn drop(&mut self) {
+        if !self.done {
+            self.d.rollback_on_drop(self.nested.then_some(SAVEPOINT));
+        }
+    }
+}
@@ -10,11 +10,14 @@ pub mod dml;
 /// This module define LINQ IR structures for SQL [`DDL`](https://www.javatpoint.com/dbms-sql-command) clause
 pub mod ddl;
 
+/// This module render LINQ IR into parameterized SQL text for several dialects.
+pub mod render;
+
 pub mod orm;
 
 pub use anyhow;
 
-pub use orm::{DeleteObject, DeleteWhereCond, Insert, Select, Update};
+pub use orm::{DeleteObject, DeleteWhereCond, Insert, Select, Update, Upsert};
 
 /// Proc macros to build LINQ language ir data.
 pub use linq_proc_macro::ddl;
@@ -27,6 +30,23 @@ pub use linq_proc_macro::rql_where;
 
 /// Macro to generate implementation of the `Table` trait for data structures.
 ///
+/// Fields tagged with `#[column(json)]` are stored as [`Variant::Json`] via serde.
+/// Fields tagged with `#[column(with = "path::to::module")]` are converted by
+/// `fn to_variant(value: T) -> Variant` and `fn from_variant(value: Variant) -> anyhow::Result<T>`
+/// of that module, e.g. foreign types without [`ColumnLike`](orm::ColumnLike) impl.
+///
+/// Cascade fields tagged with `#[cascade(from=.. to=.. lazy)]` are only selected when
+/// requested by `with_relation`. Skipped cascades become an empty `Vec` or `None`,
+/// so a lazy one-to-one field must be an `Option`.
+///
+/// `Option<Vec<T>>` fields tagged with `#[many_to_many(through = Link, from = col_x, to = col_y)]`
+/// are linked by rows of the `Link` table. Insert and update only write link rows,
+/// so target rows must already exist. `None` means links were not loaded, which
+/// are left untouched; update deletes the loaded links to other targets and adds
+/// the missing ones. Upsert and update skip existing links, so the `Link` table
+/// needs a `UNIQUE(col_x, col_y)` constraint, and may omit a primary col.
+/// Deleting an object deletes its link rows, deleting by condition doesn't.
+///
 /// # Examples
 ///
 /// ```
@@ -41,6 +61,8 @@ pub use linq_proc_macro::rql_where;
 ///     last_name: String,
 ///     #[cascade(from=col_id to=col_user_id)]
 ///     cards: Vec<Card>,
+///     #[column("user_tags", json)]
+///     tags: Option<Vec<String>>,
 /// }
 ///
 /// #[derive(linq_proc_macro::ORM, Serialize, Deserialize, Default)]
@@ -53,6 +75,85 @@ pub use linq_proc_macro::rql_where;
 /// ```
 pub use linq_proc_macro::ORM;
 
+/// Macro to implement [`ColumnLike`](orm::ColumnLike) for single-field newtype structs,
+/// by delegating to the inner type.
+///
+/// # Examples
+///
+/// ```
+/// use linq_rs::{orm::{ColumnLike, ColumnValue}, *};
+///
+/// #[derive(ColumnLike, Debug, PartialEq)]
+/// struct Email(String);
+///
+/// let value = Email("a@b.c".into()).into_column_value("email").unwrap();
+///
+/// assert_eq!(Email::from_column_value(value).unwrap(), Email("a@b.c".into()));
+/// ```
+pub use linq_proc_macro::ColumnLike;
+
+/// Macro to implement [`FromRow`](orm::FromRow) for structs decoded from ad-hoc queries.
+///
+/// Fields are read by name, `#[column("alias")]`, `#[column(json)]` and
+/// `#[column(with = "path")]` work the same as on [`ORM`] structs.
+///
+/// # Examples
+///
+/// ```
+/// use linq_rs::{driver::Rows, orm::collect_rows, *};
+///
+/// #[derive(FromRow, Debug, PartialEq)]
+/// struct NameCount {
+///     name: String,
+///     #[column("total")]
+///     count: i64,
+/// }
+///
+/// # async_std::task::block_on(async {
+/// let rows = Rows::new(
+///     vec!["name".into(), "total".into()],
+///     vec![vec!["a".into(), 2.into()]],
+/// );
+///
+/// let values = collect_rows::<NameCount, _>(rows).await?;
+///
+/// assert_eq!(values, vec![NameCount { name: "a".into(), count: 2 }]);
+/// # anyhow::Ok(())
+/// # }).unwrap();
+/// ```
+pub use linq_proc_macro::FromRow;
+
+/// Macro to map unit-variant enums to string (default) or integer columns.
+///
+/// Variants are stored by name unless renamed with `#[linq(rename = "...")]`.
+/// With `#[linq(int)]` they are stored by discriminant, overridden by `#[linq(value = ...)]`.
+///
+/// # Examples
+///
+/// ```
+/// use linq_rs::*;
+///
+/// #[derive(LinqEnum, Debug, PartialEq)]
+/// enum Status {
+///     Active,
+///     #[linq(rename = "banned")]
+///     Disabled,
+/// }
+///
+/// #[derive(LinqEnum, Debug, PartialEq)]
+/// #[linq(int)]
+/// enum Level {
+///     Low = 1,
+///     #[linq(value = 10)]
+///     High,
+/// }
+///
+/// assert_eq!(Variant::from(Status::Disabled), Variant::String("banned".into()));
+/// assert_eq!(Level::try_from(Variant::Int(10)).unwrap(), Level::High);
+/// assert!(Level::try_from(Variant::Int(2)).is_err());
+/// ```
+pub use linq_proc_macro::LinqEnum;
+
 pub use async_trait::async_trait;
 
 pub use log;
@@ -1,15 +1,17 @@
 use crate::{
     orm::{ColumnValue, Table},
-    DateTime, Timestamp, Variant,
+    BigInt, Date, DateTime, Decimal, Interval, Json, Time, Timestamp, Variant,
 };
 
+use serde::{de::DeserializeOwned, Serialize};
+
 /// Generic orm column trait.
 ///
 /// Add serailze/deserialize ability via `LINQ` engine to
 /// rust primitive types or generated table structures.
 pub trait ColumnLike: Sized {
     /// Convert `Self` into [`ColumnValue`]
-    fn into_column_value(self, col_name: &'static str) -> ColumnValue;
+    fn into_column_value(self, col_name: &'static str) -> anyhow::Result<ColumnValue>;
 
     /// Generate new type instance from [`ColumnValue`]
     fn from_column_value(value: ColumnValue) -> anyhow::Result<Self>;
@@ -42,11 +44,62 @@ where
     C::from_column_value(value)
 }
 
+/// Helper fn used by proc_macro to convert `#[column(with = "path")]` field,
+/// `from_variant` is the `path::from_variant` fn, which also receives `NULL` values.
+pub fn from_column_value_with<C>(
+    value: ColumnValue,
+    from_variant: fn(Variant) -> anyhow::Result<C>,
+) -> anyhow::Result<C> {
+    match value {
+        ColumnValue::Simple(_, value) => from_variant(value),
+        _ => Err(anyhow::format_err!("Column type mismatch")),
+    }
+}
+
+/// Helper fn used by proc_macro to serialize `#[column(json)]` field.
+///
+/// `None` like values are stored as `NULL` instead of json `null`.
+pub fn into_json_column_value<C>(value: C, col_name: &'static str) -> anyhow::Result<ColumnValue>
+where
+    C: Serialize,
+{
+    match serde_json::to_value(value) {
+        Ok(Json::Null) => Ok(ColumnValue::Simple(col_name, Variant::Null)),
+        Ok(json) => Ok(ColumnValue::Simple(col_name, Variant::Json(json))),
+        Err(err) => Err(anyhow::format_err!(
+            "Column({}) serialize to json: {}",
+            col_name,
+            err
+        )),
+    }
+}
+
+/// Helper fn used by proc_macro to deserialize `#[column(json)]` field.
+pub fn from_json_column_value<C>(value: ColumnValue) -> anyhow::Result<C>
+where
+    C: DeserializeOwned,
+{
+    match value {
+        ColumnValue::Simple(col_name, value) => {
+            let json = match value {
+                Variant::Null => Json::Null,
+                Variant::Json(json) => json,
+                _ => return Err(anyhow::format_err!("Column({}) expect json", col_name)),
+            };
+
+            serde_json::from_value(json).map_err(|err| {
+                anyhow::format_err!("Column({}) deserialize from json: {}", col_name, err)
+            })
+        }
+        _ => Err(anyhow::format_err!("Column type mismatch")),
+    }
+}
+
 macro_rules! def_column_like {
     ($ty:ty) => {
         impl ColumnLike for $ty {
-            fn into_column_value(self, col_name: &'static str) -> ColumnValue {
-                ColumnValue::Simple(col_name, self.into())
+            fn into_column_value(self, col_name: &'static str) -> anyhow::Result<ColumnValue> {
+                Ok(ColumnValue::Simple(col_name, self.into()))
             }
 
             fn from_column_value(value: ColumnValue) -> anyhow::Result<Self> {
@@ -65,28 +118,44 @@ macro_rules! def_column_like {
     };
 }
 
+def_column_like!(bool);
 def_column_like!(i8);
 def_column_like!(i16);
 def_column_like!(i32);
 def_column_like!(i64);
+def_column_like!(i128);
+def_column_like!(isize);
 def_column_like!(u8);
 def_column_like!(u16);
 def_column_like!(u32);
 def_column_like!(u64);
+def_column_like!(u128);
 def_column_like!(usize);
+def_column_like!(f32);
+def_column_like!(f64);
+def_column_like!(BigInt);
+def_column_like!(Decimal);
 def_column_like!(String);
 def_column_like!(Vec<u8>);
-def_column_like!(DateTime);
+def_column_like!(Date);
+def_column_like!(Time);
 def_column_like!(Timestamp);
+def_column_like!(DateTime);
+def_column_like!(chrono::DateTime<chrono::Utc>);
+def_column_like!(chrono::DateTime<chrono::Local>);
+def_column_like!(Interval);
+def_column_like!(Json);
+#[cfg(feature = "uuid")]
+def_column_like!(uuid::Uuid);
 
 impl<T> ColumnLike for Option<T>
 where
     T: ColumnLike,
 {
-    fn into_column_value(self, col_name: &'static str) -> ColumnValue {
+    fn into_column_value(self, col_name: &'static str) -> anyhow::Result<ColumnValue> {
         match self {
             Some(v) => v.into_column_value(col_name),
-            None => ColumnValue::Simple(col_name, Variant::Null),
+            None => Ok(ColumnValue::Simple(col_name, Variant::Null)),
         }
     }
 
@@ -99,7 +168,10 @@ where
                     Ok(Some(T::from_column_value(value)?))
                 }
             }
-            _ => Err(anyhow::format_err!("Column type mismatch")),
+            // Loaded cascade row or many-to-many rows.
+            ColumnValue::OneToOne(..) | ColumnValue::OneToMany(..) => {
+                Ok(Some(T::from_column_value(value)?))
+            }
         }
     }
 }
@@ -108,13 +180,17 @@ impl<T> ColumnLike for T
 where
     T: Table,
 {
-    fn into_column_value(self, col_name: &'static str) -> ColumnValue {
-        ColumnValue::OneToOne(col_name, self.into_values())
+    fn into_column_value(self, col_name: &'static str) -> anyhow::Result<ColumnValue> {
+        Ok(ColumnValue::OneToOne(col_name, self.into_values()?))
     }
 
     fn from_column_value(value: ColumnValue) -> anyhow::Result<Self> {
         match value {
             ColumnValue::OneToOne(_, values) => Ok(T::from_values(values)?),
+            ColumnValue::Simple(col_name, Variant::Null) => Err(anyhow::format_err!(
+                "Cascade({}) row not found, expect Option field",
+                col_name
+            )),
             _ => Err(anyhow::format_err!("Column type mismatch")),
         }
     }
@@ -124,14 +200,14 @@ impl<T> ColumnLike for Vec<T>
 where
     T: Table,
 {
-    fn into_column_value(self, col_name: &'static str) -> ColumnValue {
+    fn into_column_value(self, col_name: &'static str) -> anyhow::Result<ColumnValue> {
         let mut rows = vec![];
 
         for row in self {
-            rows.push(row.into_values());
+            rows.push(row.into_values()?);
         }
 
-        ColumnValue::OneToMany(col_name, rows)
+        Ok(ColumnValue::OneToMany(col_name, rows))
     }
 
     fn from_column_value(value: ColumnValue) -> anyhow::Result<Self> {
@@ -6,9 +6,9 @@ use crate::dml::CondExpr;
 /// # Examples
 ///
 ///
-pub trait Where {
+pub trait Where<'a> {
     type Context;
-    fn cond(self, cond: CondExpr) -> Self::Context;
+    fn cond(self, cond: CondExpr<'a>) -> Self::Context;
 }
 
 pub trait Offset {
@@ -23,7 +23,10 @@ pub trait Limit {
 
 pub trait Order<'a> {
     type Context;
+    /// Order by `col_name`, replacing previous order terms.
     fn order_by(self, col_name: &'a str, desc: bool) -> Self::Context;
+    /// Append tie-breaker order term.
+    fn then_by(self, col_name: &'a str, desc: bool) -> Self::Context;
 }
 
 pub trait Select {
@@ -38,6 +41,13 @@ pub trait Insert {
     fn insert(self) -> Self::Context;
 }
 
+/// Extend [`Table`](super::Table) structures to support insert-or-update,
+/// rows conflicting on the primary col are overwritten, cascade rows included.
+pub trait Upsert {
+    type Context;
+    fn upsert(self) -> Self::Context;
+}
+
 pub trait Update {
     type Context;
     fn update(self) -> Self::Context;
@@ -6,14 +6,17 @@ use crate::{
     Variant,
 };
 
-use crate::orm::{ColumnValue, Table, Where};
+use crate::orm::{find_col_value, table_primary_col, Column, ColumnValue, Table, Where};
 
 use super::{DeleteObject, DeleteWhereCond};
 
 /// Update context struct
 pub struct DeleteObjectContext<'a, T> {
     table_name: &'a str,
-    condexpr: Option<CondExpr>,
+    /// Serialize error of the object is returned by `exec`.
+    condexpr: anyhow::Result<Option<CondExpr<'a>>>,
+    /// Link rows of many-to-many cols, deleted before the object.
+    links: Vec<Deleter<'a>>,
     _marked: PhantomData<T>,
 }
 
@@ -21,18 +24,26 @@ impl<'a, T> DeleteObjectContext<'a, T>
 where
     T: Table + Default,
 {
-    pub async fn exec<D>(mut self, d: &mut D) -> anyhow::Result<usize>
+    pub async fn exec<D>(self, d: &mut D) -> anyhow::Result<usize>
     where
         D: DeleteSupport<'a> + Sync + Send,
     {
-        if let Some(condexpr) = self.condexpr.take() {
-            d.delete(&Deleter {
-                table_name: self.table_name,
-                cond: condexpr,
-            })
-            .await
+        if let Some(condexpr) = self.condexpr? {
+            for deleter in &self.links {
+                d.delete(deleter).await?;
+            }
+
+            let result = d
+                .delete(&Deleter {
+                    table_name: self.table_name,
+                    cond: condexpr,
+                    returning: None,
+                })
+                .await?;
+
+            Ok(result.affected_rows)
         } else {
-            return Err(anyhow::format_err!("Expect primary column value"));
+            Err(anyhow::format_err!("Expect primary column value"))
         }
     }
 }
@@ -43,37 +54,68 @@ where
 {
     type Context<'a> = DeleteObjectContext<'a, T>;
     fn delete<'a>(self) -> Self::Context<'a> {
-        let mut condexpr = None;
+        let target = self.into_values().and_then(|values| {
+            let links = link_deleters(T::cols(), &values);
 
-        for value in self.into_values() {
-            match value {
-                ColumnValue::Simple(col_name, variant) => {
-                    if let Variant::Null = variant {
-                        continue;
-                    }
+            let Some((col_name, _)) = table_primary_col(T::cols()) else {
+                return Ok((None, links));
+            };
 
-                    condexpr = Some(CondExpr {
+            match values.into_iter().find(|v| v.col_name() == col_name) {
+                Some(ColumnValue::Simple(_, Variant::Null)) => Err(anyhow::format_err!(
+                    "Primary column {} value is null",
+                    col_name
+                )),
+                Some(ColumnValue::Simple(_, variant)) => Ok((
+                    Some(CondExpr {
                         op: CondOp::Eq,
-                        lhs: CondParam::Variant(Variant::String(col_name.to_owned())),
-                        rhs: CondParam::Variant(variant),
-                    });
-                }
-                _ => {
-                    continue;
-                }
+                        lhs: CondParam::Column(col_name.into()),
+                        rhs: CondParam::Param(variant),
+                    }),
+                    links,
+                )),
+                _ => Ok((None, links)),
             }
-        }
+        });
+
+        let (condexpr, links) = match target {
+            Ok((condexpr, links)) => (Ok(condexpr), links),
+            Err(err) => (Err(err), vec![]),
+        };
 
-        let context = DeleteObjectContext {
+        DeleteObjectContext {
             table_name: T::table_name(),
             condexpr,
+            links,
             _marked: Default::default(),
-        };
-
-        context
+        }
     }
 }
 
+/// Deleters of link rows owned by `values`, one per many-to-many col of `cols`.
+fn link_deleters<'a>(cols: &'static [Column], values: &[ColumnValue]) -> Vec<Deleter<'a>> {
+    cols.iter()
+        .filter_map(|col| match col {
+            Column::ManyToMany(many_to_many) => Some(many_to_many),
+            _ => None,
+        })
+        .filter_map(
+            |many_to_many| match find_col_value(values, many_to_many.ref_col) {
+                Some(ColumnValue::Simple(_, value)) if *value != Variant::Null => Some(Deleter {
+                    table_name: (many_to_many.through_table_name)(),
+                    cond: CondExpr {
+                        op: CondOp::Eq,
+                        lhs: CondParam::Column(many_to_many.from_col.into()),
+                        rhs: CondParam::Param(value.clone()),
+                    },
+                    returning: None,
+                }),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
 impl<T> DeleteWhereCond for T
 where
     T: Table + Default,
@@ -90,13 +132,14 @@ pub struct DeleteWhereCondContext<'a> {
     table_name: &'a str,
 }
 
-impl<'a> Where for DeleteWhereCondContext<'a> {
+impl<'a> Where<'a> for DeleteWhereCondContext<'a> {
     type Context = DeleteCondContext<'a>;
-    fn cond(self, cond: CondExpr) -> Self::Context {
+    fn cond(self, cond: CondExpr<'a>) -> Self::Context {
         DeleteCondContext {
             deleter: Deleter {
                 table_name: self.table_name,
                 cond,
+                returning: None,
             },
         }
     }
@@ -111,6 +154,6 @@ impl<'a> DeleteCondContext<'a> {
     where
         D: DeleteSupport<'a> + Sync + Send,
     {
-        d.delete(&self.deleter).await
+        Ok(d.delete(&self.deleter).await?.affected_rows)
     }
 }
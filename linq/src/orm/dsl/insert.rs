@@ -1,10 +1,16 @@
 use std::marker::PhantomData;
 
-use crate::{dml::Inserter, driver::InsertSupport, Variant};
+use crate::{
+    dml::{ConflictAction, Inserter, OnConflict},
+    driver::{InsertSupport, Transaction, TransactionSupport},
+    Variant,
+};
 
-use crate::orm::{find_col, table_primary_col, Column, ColumnValue, Table};
+use crate::orm::{
+    find_col, find_col_value, table_primary_col, Column, ColumnValue, KeyGen, ManyToMany, Table,
+};
 
-use super::Insert;
+use super::{Insert, Upsert};
 
 struct InsertCascade {
     table_name: &'static str,
@@ -13,29 +19,103 @@ struct InsertCascade {
     foreign_key_col_value: Option<Variant>,
     cols: &'static [Column],
     values: Vec<ColumnValue>,
+    /// Update existing row with the same primary key instead of failing.
+    upsert: bool,
+    /// Location of `values` in the inserted objects, `None` for link rows.
+    path: Option<ValuePath>,
+}
+
+/// Root object index and `(cascade col name, row index)` steps to a cascade row.
+#[derive(Debug, Clone)]
+struct ValuePath {
+    root: usize,
+    steps: Vec<(&'static str, usize)>,
+}
+
+impl ValuePath {
+    fn child(&self, col_name: &'static str, idx: usize) -> Self {
+        let mut steps = self.steps.clone();
+
+        steps.push((col_name, idx));
+
+        Self {
+            root: self.root,
+            steps,
+        }
+    }
+}
+
+/// Col value generated on insert, written back to the inserted object.
+struct Patch {
+    path: ValuePath,
+    col_name: &'static str,
+    value: Variant,
+}
+
+/// Row ready to insert, with cascades waiting for its generated key.
+struct PreparedInsert {
+    table_name: &'static str,
+    /// `None` for link rows without primary col.
+    primary_col_name: Option<&'static str>,
+    /// Primary key is generated by database.
+    auto_inc: bool,
+    cols: Vec<&'static str>,
+    values: Vec<Variant>,
+    upsert: bool,
+    path: Option<ValuePath>,
+    patches: Vec<Patch>,
+    completed: Vec<InsertCascade>,
+    incompleted: Vec<InsertCascade>,
 }
 
 impl InsertCascade {
-    pub async fn exec<'a, D>(self, d: &mut D) -> anyhow::Result<Vec<InsertCascade>>
-    where
-        D: InsertSupport<'a> + Sync + Send,
-    {
-        let (primary_col_name, auto_inc) =
-            table_primary_col(self.cols).ok_or(anyhow::format_err!("Primary col not found"))?;
+    fn prepare(self) -> anyhow::Result<PreparedInsert> {
+        let (primary_col_name, key_gen) = match table_primary_col(self.cols) {
+            Some((col_name, key_gen)) => (Some(col_name), key_gen),
+            // Link tables may be keyed by `(from_col, to_col)` alone.
+            None if self.path.is_none() => (None, KeyGen::Manual),
+            None => return Err(anyhow::format_err!("Primary col not found")),
+        };
 
         let mut cols = vec![];
         let mut values = vec![];
 
         let mut insert_cascades = vec![];
+        let mut patches = vec![];
 
         for value in self.values {
             match value {
                 ColumnValue::Simple(col_name, value) => {
+                    let value = if Some(col_name) == primary_col_name {
+                        match key_gen {
+                            // Upsert keeps bound auto increment key to detect conflicts.
+                            KeyGen::AutoInc if self.upsert => value,
+                            KeyGen::AutoInc => continue,
+                            KeyGen::Uuid => {
+                                let key = gen_uuid(value.clone())?;
+
+                                if let Some(path) = self.path.as_ref().filter(|_| key != value) {
+                                    patches.push(Patch {
+                                        path: path.clone(),
+                                        col_name,
+                                        value: key.clone(),
+                                    });
+                                }
+
+                                key
+                            }
+                            KeyGen::Manual => value,
+                        }
+                    } else {
+                        value
+                    };
+
                     if let Variant::Null = value {
                         continue;
                     }
 
-                    if col_name == primary_col_name && auto_inc {
+                    // Cascade foreign key value is bound by parent row.
+                    if Some(col_name) == self.foreign_key_col {
                         continue;
                     }
 
@@ -54,6 +134,8 @@ impl InsertCascade {
                             foreign_key_col_value: None,
                             cols: (cascade.table_cols)(),
                             values,
+                            upsert: self.upsert,
+                            path: self.path.as_ref().map(|path| path.child(col_name, 0)),
                         }),
                         _ => {
                             return Err(anyhow::format_err!(
@@ -69,7 +151,7 @@ impl InsertCascade {
 
                     match col {
                         Column::OneToMany(cascade) => {
-                            for values in rows {
+                            for (idx, values) in rows.into_iter().enumerate() {
                                 insert_cascades.push(InsertCascade {
                                     table_name: (cascade.table_name)(),
                                     ref_col: Some(cascade.ref_col),
@@ -77,6 +159,26 @@ impl InsertCascade {
                                     foreign_key_col_value: None,
                                     cols: (cascade.table_cols)(),
                                     values,
+                                    upsert: self.upsert,
+                                    path: self.path.as_ref().map(|path| path.child(col_name, idx)),
+                                })
+                            }
+                        }
+                        // Target rows exist, only link rows are inserted.
+                        Column::ManyToMany(many_to_many) => {
+                            for values in rows {
+                                insert_cascades.push(InsertCascade {
+                                    table_name: (many_to_many.through_table_name)(),
+                                    ref_col: Some(many_to_many.ref_col),
+                                    foreign_key_col: Some(many_to_many.from_col),
+                                    foreign_key_col_value: None,
+                                    cols: (many_to_many.through_table_cols)(),
+                                    values: vec![ColumnValue::Simple(
+                                        many_to_many.to_col,
+                                        target_key(many_to_many, &values)?,
+                                    )],
+                                    upsert: self.upsert,
+                                    path: None,
                                 })
                             }
                         }
@@ -92,12 +194,22 @@ impl InsertCascade {
         }
 
         if let Some(col_name) = self.foreign_key_col {
+            let value = self.foreign_key_col_value.unwrap();
+
+            if let Some(path) = &self.path {
+                patches.push(Patch {
+                    path: path.clone(),
+                    col_name,
+                    value: value.clone(),
+                });
+            }
+
             cols.push(col_name);
-            values.push(self.foreign_key_col_value.unwrap());
+            values.push(value);
         }
 
-        let mut insert_cascades_incompleted = vec![];
-        let mut insert_cascades_completed = vec![];
+        let mut incompleted = vec![];
+        let mut completed = vec![];
 
         // First, try find cascade column ref_col bound value
         for mut cascade in insert_cascades {
@@ -109,45 +221,274 @@ impl InsertCascade {
                 .find(|(_, col_name)| **col_name == ref_col)
             {
                 cascade.foreign_key_col_value = Some(values[idx].clone());
-                insert_cascades_completed.push(cascade);
+                completed.push(cascade);
             } else {
-                insert_cascades_incompleted.push(cascade);
+                incompleted.push(cascade);
             }
         }
 
-        let last_insert_id = d
-            .insert(
-                &Inserter {
-                    table_name: self.table_name,
-                    cols: cols.into(),
-                },
-                values,
-            )
+        let auto_inc = key_gen == KeyGen::AutoInc
+            && primary_col_name.is_some_and(|col_name| !cols.contains(&col_name));
+
+        Ok(PreparedInsert {
+            table_name: self.table_name,
+            primary_col_name,
+            auto_inc,
+            cols,
+            values,
+            upsert: self.upsert,
+            path: self.path,
+            patches,
+            completed,
+            incompleted,
+        })
+    }
+
+    pub async fn exec<'a, D>(
+        self,
+        d: &mut D,
+        patches: &mut Vec<Patch>,
+    ) -> anyhow::Result<Vec<InsertCascade>>
+    where
+        D: InsertSupport<'a> + Sync + Send,
+    {
+        let mut prepared = self.prepare()?;
+
+        let result = d
+            .insert(&prepared.inserter(), std::mem::take(&mut prepared.values))
             .await?;
 
-        // Then, try match ref_col to auto_inc primary col bound value.
-        for mut cascade in insert_cascades_incompleted {
+        prepared.complete(result.last_insert_id(), patches)
+    }
+}
+
+impl PreparedInsert {
+    fn inserter(&self) -> Inserter<'static> {
+        let on_conflict = if !self.upsert {
+            None
+        } else if self.path.is_none() {
+            // Link row has no bound primary key, it conflicts on `(from_col, to_col)`.
+            Some(OnConflict {
+                target: self.cols.clone().into(),
+                action: ConflictAction::DoNothing,
+            })
+        } else if let Some(primary_col_name) = self
+            .primary_col_name
+            .filter(|col_name| self.cols.contains(col_name))
+        {
+            let update = self
+                .cols
+                .iter()
+                .copied()
+                .filter(|col_name| *col_name != primary_col_name)
+                .collect::<Vec<_>>();
+
+            Some(OnConflict {
+                target: vec![primary_col_name].into(),
+                action: if update.is_empty() {
+                    ConflictAction::DoNothing
+                } else {
+                    ConflictAction::DoUpdate(update.into())
+                },
+            })
+        } else {
+            // No conflict is possible without bound primary key.
+            None
+        };
+
+        Inserter {
+            table_name: self.table_name,
+            cols: self.cols.clone().into(),
+            on_conflict,
+            returning: None,
+        }
+    }
+
+    /// Then, try match ref_col to auto_inc primary col generated value.
+    ///
+    /// Generated values are appended to `patches`.
+    fn complete(
+        self,
+        generated_key: Option<i64>,
+        patches: &mut Vec<Patch>,
+    ) -> anyhow::Result<Vec<InsertCascade>> {
+        // Paired with the primary col it was generated for.
+        let generated_key = generated_key
+            .filter(|_| self.auto_inc)
+            .zip(self.primary_col_name);
+
+        patches.extend(self.patches);
+
+        if let (Some((key, col_name)), Some(path)) = (generated_key, self.path) {
+            patches.push(Patch {
+                path,
+                col_name,
+                value: key.into(),
+            });
+        }
+
+        let mut completed = self.completed;
+
+        for mut cascade in self.incompleted {
             let ref_col = cascade.ref_col.unwrap();
 
-            if ref_col != primary_col_name {
-                return Err(anyhow::format_err!(
-                    "Cascade column {} ref col is null",
-                    ref_col
-                ));
+            match generated_key {
+                Some((key, col_name)) if ref_col == col_name => {
+                    cascade.foreign_key_col_value = Some(key.into());
+                }
+                _ => {
+                    return Err(anyhow::format_err!(
+                        "Cascade column {} ref col is null",
+                        ref_col
+                    ));
+                }
             }
 
-            cascade.foreign_key_col_value = Some(last_insert_id.into());
+            completed.push(cascade);
+        }
+
+        Ok(completed)
+    }
+}
+
+/// Insert `cascades` by multi-row inserts of at most `chunk_size` rows,
+/// rows are grouped by table and bound cols.
+///
+/// Returns cascades of the next level.
+async fn exec_batch<'a, D>(
+    cascades: Vec<InsertCascade>,
+    chunk_size: usize,
+    d: &mut D,
+    patches: &mut Vec<Patch>,
+) -> anyhow::Result<Vec<InsertCascade>>
+where
+    D: InsertSupport<'a> + Sync + Send,
+{
+    let mut groups: Vec<Vec<PreparedInsert>> = vec![];
+
+    for cascade in cascades {
+        let prepared = cascade.prepare()?;
 
-            insert_cascades_completed.push(cascade);
+        match groups.iter_mut().find(|group| {
+            group[0].table_name == prepared.table_name && group[0].cols == prepared.cols
+        }) {
+            Some(group) => group.push(prepared),
+            None => groups.push(vec![prepared]),
         }
+    }
+
+    let mut next = vec![];
+
+    for mut group in groups {
+        while !group.is_empty() {
+            let mut chunk = group
+                .drain(..chunk_size.min(group.len()))
+                .collect::<Vec<_>>();
+
+            let inserter = chunk[0].inserter();
+
+            let rows = chunk
+                .iter_mut()
+                .map(|prepared| std::mem::take(&mut prepared.values))
+                .collect();
+
+            let result = d.insert_many(&inserter, rows).await?;
+
+            // Keys can't be matched to rows unless every row got one.
+            let keys = if result.generated_keys.len() == chunk.len() {
+                result.generated_keys.into_iter().map(Some).collect()
+            } else {
+                vec![None; chunk.len()]
+            };
+
+            for (prepared, key) in chunk.into_iter().zip(keys) {
+                next.append(&mut prepared.complete(key, patches)?);
+            }
+        }
+    }
+
+    Ok(next)
+}
+
+/// Primary col value of `many_to_many` target row `values`.
+pub(crate) fn target_key(
+    many_to_many: &ManyToMany,
+    values: &[ColumnValue],
+) -> anyhow::Result<Variant> {
+    let (primary_col_name, _) = table_primary_col((many_to_many.table_cols)())
+        .ok_or(anyhow::format_err!("Primary col not found"))?;
+
+    let value = find_col_value(values, primary_col_name)
+        .ok_or(anyhow::format_err!(
+            "ManyToMany({}) target col {} not found",
+            many_to_many.name,
+            primary_col_name
+        ))?
+        .as_simple_value()?;
+
+    if let Variant::Null = value {
+        return Err(anyhow::format_err!(
+            "ManyToMany({}) target {} is null, insert target rows first",
+            many_to_many.name,
+            primary_col_name
+        ));
+    }
+
+    Ok(value.clone())
+}
 
-        Ok(insert_cascades_completed)
+/// Generate v4 uuid key if `value` is `NULL` or nil uuid.
+fn gen_uuid(value: Variant) -> anyhow::Result<Variant> {
+    match value {
+        Variant::Null => new_uuid(),
+        Variant::Uuid(v) if v == [0; 16] => new_uuid(),
+        value => Ok(value),
     }
 }
 
+#[cfg(feature = "uuid")]
+fn new_uuid() -> anyhow::Result<Variant> {
+    Ok(uuid::Uuid::new_v4().into())
+}
+
+#[cfg(not(feature = "uuid"))]
+fn new_uuid() -> anyhow::Result<Variant> {
+    Err(anyhow::format_err!(
+        "Generate uuid primary key requires `uuid` feature"
+    ))
+}
+
+/// Write generated col values back to inserted objects `roots`.
+fn apply_patches(roots: &mut [Vec<ColumnValue>], patches: Vec<Patch>) -> anyhow::Result<()> {
+    for patch in patches {
+        let mut values = &mut roots[patch.path.root];
+
+        for (col_name, idx) in &patch.path.steps {
+            let row = values.iter_mut().find_map(|value| match value {
+                ColumnValue::OneToOne(name, values) if name == col_name => Some(values),
+                ColumnValue::OneToMany(name, rows) if name == col_name => rows.get_mut(*idx),
+                _ => None,
+            });
+
+            values = row.ok_or(anyhow::format_err!("Cascade col {} not found", col_name))?;
+        }
+
+        for value in values.iter_mut() {
+            if let ColumnValue::Simple(col_name, col_value) = value {
+                if *col_name == patch.col_name {
+                    *col_value = patch.value.clone();
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Cascade insert context structure .
 pub struct InsertContext<T> {
-    cascade: InsertCascade,
+    /// Serialize error of the object is returned by `exec`.
+    cascade: anyhow::Result<InsertCascade>,
     _marked: PhantomData<T>,
 }
 
@@ -155,22 +496,40 @@ impl<T> InsertContext<T>
 where
     T: Table + Default,
 {
-    pub async fn exec<'a, D>(self, d: &mut D) -> anyhow::Result<()>
+    /// Returns the inserted object, with generated primary and foreign keys filled in.
+    pub async fn exec<'a, D>(self, d: &mut D) -> anyhow::Result<T>
     where
         D: InsertSupport<'a> + Sync + Send,
     {
-        let mut insert_stack = vec![self.cascade];
+        let cascade = self.cascade?;
 
-        // Cascade insert row
-        while !insert_stack.is_empty() {
-            let cascade = insert_stack.pop().unwrap();
+        let mut roots = vec![cascade.values.clone()];
 
-            let mut next = cascade.exec(d).await?;
+        let mut insert_stack = vec![cascade];
+        let mut patches = vec![];
+
+        // Cascade insert row
+        while let Some(cascade) = insert_stack.pop() {
+            let mut next = cascade.exec(d, &mut patches).await?;
 
             insert_stack.append(&mut next);
         }
 
-        Ok(())
+        apply_patches(&mut roots, patches)?;
+
+        T::from_values(roots.pop().unwrap())
+    }
+
+    /// Same as [`exec`](Self::exec), but runs the whole cascade in one transaction.
+    pub async fn exec_tx<'a, D>(self, d: &mut D) -> anyhow::Result<T>
+    where
+        D: InsertSupport<'a> + TransactionSupport + Sync + Send,
+    {
+        let mut tx = Transaction::begin(d).await?;
+
+        let result = self.exec(&mut *tx).await;
+
+        tx.finish(result).await
     }
 }
 
@@ -181,15 +540,145 @@ where
     type Context = InsertContext<T>;
     fn insert(self) -> Self::Context {
         InsertContext {
-            cascade: InsertCascade {
+            cascade: self.into_values().map(|values| InsertCascade {
                 table_name: T::table_name(),
                 ref_col: None,
                 foreign_key_col_value: None,
                 foreign_key_col: None,
                 cols: T::cols(),
-                values: self.into_values(),
-            },
+                values,
+                upsert: false,
+                path: Some(ValuePath {
+                    root: 0,
+                    steps: vec![],
+                }),
+            }),
+            _marked: Default::default(),
+        }
+    }
+}
+
+/// Default rows per multi-row insert of [`InsertManyContext`].
+pub const INSERT_CHUNK_SIZE: usize = 100;
+
+/// Batch cascade insert context structure.
+pub struct InsertManyContext<T> {
+    /// Serialize error of any object is returned by `exec`.
+    cascades: anyhow::Result<Vec<InsertCascade>>,
+    chunk_size: usize,
+    _marked: PhantomData<T>,
+}
+
+impl<T> InsertManyContext<T>
+where
+    T: Table + Default,
+{
+    /// Set max rows per multi-row insert, defaults to [`INSERT_CHUNK_SIZE`].
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Insert rows level by level, cascade rows of the same table are batched too.
+    ///
+    /// Returns the inserted objects, with generated keys filled in.
+    pub async fn exec<'a, D>(self, d: &mut D) -> anyhow::Result<Vec<T>>
+    where
+        D: InsertSupport<'a> + Sync + Send,
+    {
+        let mut cascades = self.cascades?;
+
+        let mut roots = cascades
+            .iter()
+            .map(|cascade| cascade.values.clone())
+            .collect::<Vec<_>>();
+
+        let mut patches = vec![];
+
+        while !cascades.is_empty() {
+            cascades = exec_batch(cascades, self.chunk_size, d, &mut patches).await?;
+        }
+
+        apply_patches(&mut roots, patches)?;
+
+        roots.into_iter().map(T::from_values).collect()
+    }
+
+    /// Same as [`exec`](Self::exec), but runs all inserts in one transaction.
+    pub async fn exec_tx<'a, D>(self, d: &mut D) -> anyhow::Result<Vec<T>>
+    where
+        D: InsertSupport<'a> + TransactionSupport + Sync + Send,
+    {
+        let mut tx = Transaction::begin(d).await?;
+
+        let result = self.exec(&mut *tx).await;
+
+        tx.finish(result).await
+    }
+}
+
+impl<T> Insert for Vec<T>
+where
+    T: Table + Default,
+{
+    type Context = InsertManyContext<T>;
+    fn insert(self) -> Self::Context {
+        InsertManyContext {
+            cascades: self
+                .into_iter()
+                .enumerate()
+                .map(|(root, value)| {
+                    Ok(InsertCascade {
+                        table_name: T::table_name(),
+                        ref_col: None,
+                        foreign_key_col_value: None,
+                        foreign_key_col: None,
+                        cols: T::cols(),
+                        values: value.into_values()?,
+                        upsert: false,
+                        path: Some(ValuePath {
+                            root,
+                            steps: vec![],
+                        }),
+                    })
+                })
+                .collect(),
+            chunk_size: INSERT_CHUNK_SIZE,
             _marked: Default::default(),
         }
     }
 }
+
+impl<T> Upsert for T
+where
+    T: Table + Default,
+{
+    type Context = InsertContext<T>;
+    fn upsert(self) -> Self::Context {
+        let mut context = self.insert();
+
+        if let Ok(cascade) = &mut context.cascade {
+            cascade.upsert = true;
+        }
+
+        context
+    }
+}
+
+impl<T> Upsert for Vec<T>
+where
+    T: Table + Default,
+{
+    type Context = InsertManyContext<T>;
+    fn upsert(self) -> Self::Context {
+        let mut context = self.insert();
+
+        if let Ok(cascades) = &mut context.cascades {
+            for cascade in cascades {
+                cascade.upsert = true;
+            }
+        }
+
+        context
+    }
+}
@@ -1,10 +1,20 @@
-use std::marker::PhantomData;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 
 use crate::{
-    dml::{CondExpr, CondOp, CondParam, Limit, OrderBy, Selecter},
-    driver::{QueryIterator, SelectSupport},
-    orm::{find_col_value, Column, ColumnValue, Table},
-    Select,
+    dml::{
+        AggregateFunc, CondExpr, CondOp, CondParam, Limit, OrderBy, SelectColumns,
+        SelectNamedColumn, Selecter,
+    },
+    driver::{into_stream, QueryIterator, SelectSupport},
+    orm::{find_col, find_col_value, table_primary_col, Column, ColumnValue, ManyToMany, Table},
+    Select, Variant,
 };
 
 use super::Where;
@@ -13,18 +23,138 @@ use super::Where;
 struct CascadeSelecter<'a> {
     table_name: &'static str,
     cols: &'static [Column],
-    cond: Option<CondExpr>,
+    cond: Option<CondExpr<'a>>,
     limits: Option<Limit>,
-    order_by: Option<OrderBy<'a>>,
+    order_by: Vec<OrderBy<'a>>,
+    relations: Relations<'a>,
+}
+
+/// Cascade cols loaded by select.
+#[derive(Clone)]
+struct Relations<'a> {
+    /// Cascade col paths loaded even if lazy, e.g. `cards` or `cards.owner`.
+    include: Vec<&'a str>,
+    /// Load cascade cols not tagged by `#[cascade(lazy)]`.
+    eager: bool,
+}
+
+impl<'a> Default for Relations<'a> {
+    fn default() -> Self {
+        Self {
+            include: vec![],
+            eager: true,
+        }
+    }
 }
 
+impl<'a> Relations<'a> {
+    fn loads(&self, name: &str, lazy: bool) -> bool {
+        (self.eager && !lazy) || self.include.iter().any(|path| path_head(path) == name)
+    }
+
+    /// Relations of the rows of cascade col `name`.
+    fn scoped(&self, name: &str) -> Relations<'a> {
+        Relations {
+            include: self
+                .include
+                .iter()
+                .filter_map(|path| path.strip_prefix(name)?.strip_prefix('.'))
+                .collect(),
+            eager: self.eager,
+        }
+    }
+
+    /// Check included paths start with cascade cols of the table,
+    /// nested segments are checked by the child tables.
+    fn check(&self, cols: &[Column]) -> anyhow::Result<()> {
+        for path in &self.include {
+            let name = path_head(path);
+
+            match find_col(cols, name) {
+                Some(Column::OneToOne(_) | Column::OneToMany(_) | Column::ManyToMany(_)) => {}
+                _ => return Err(anyhow::format_err!("Cascade col {} not found", name)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Hashable view of a key [`Variant`] used to stitch cascade rows.
+///
+/// Drivers may decode a foreign key and its referenced key as different variants,
+/// e.g. a `TEXT` foreign key of an `INT` primary key, so integers, big integers
+/// and integer strings share the canonical [`VariantKey::Int`] form. Other key
+/// types only hash their payload when common, which is still consistent with `PartialEq`.
+#[derive(PartialEq)]
+enum VariantKey<'v> {
+    Int(i64),
+    Other(&'v Variant),
+}
+
+impl<'v> VariantKey<'v> {
+    fn new(value: &'v Variant) -> Self {
+        let int = match value {
+            Variant::Int(v) => Some(*v),
+            Variant::BigInt(v) => i64::try_from(v).ok(),
+            // Only the canonical spelling, `"01"` is not the key `1`.
+            Variant::String(v) => v.parse::<i64>().ok().filter(|int| int.to_string() == *v),
+            _ => None,
+        };
+
+        int.map_or(VariantKey::Other(value), VariantKey::Int)
+    }
+}
+
+impl<'v> Eq for VariantKey<'v> {}
+
+impl<'v> Hash for VariantKey<'v> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            VariantKey::Int(v) => v.hash(state),
+            VariantKey::Other(value) => {
+                std::mem::discriminant(*value).hash(state);
+
+                match value {
+                    Variant::Bool(v) => v.hash(state),
+                    Variant::BigInt(v) => v.hash(state),
+                    Variant::String(v) => v.hash(state),
+                    Variant::Bytes(v) => v.hash(state),
+                    Variant::Uuid(v) => v.hash(state),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Group `children` by key, keeping table order within each group.
+fn group_children(
+    children: &[(Variant, Vec<ColumnValue>)],
+) -> HashMap<VariantKey<'_>, Vec<&Vec<ColumnValue>>> {
+    let mut groups = HashMap::<_, Vec<_>>::new();
+
+    for (key, values) in children {
+        groups.entry(VariantKey::new(key)).or_default().push(values);
+    }
+
+    groups
+}
+
+/// First segment of relation path `cards.owner`.
+fn path_head(path: &str) -> &str {
+    path.split_once('.').map_or(path, |(head, _)| head)
+}
+
+/// Chunk size of parent rows loaded by [`SelectMany::stream`] before cascade select.
+pub const STREAM_CHUNK_SIZE: usize = 100;
+
+/// Max ref values bound to one cascade select `IN (..)` list.
+pub const CASCADE_CHUNK_SIZE: usize = 500;
+
 impl<'a> CascadeSelecter<'a> {
-    #[async_recursion::async_recursion]
-    pub async fn exec<D>(self, d: &mut D) -> anyhow::Result<Vec<Vec<ColumnValue>>>
-    where
-        D: SelectSupport<'a> + Sync + Send,
-        'a: 'async_recursion,
-    {
+    /// Simple/Primary cols selected from the table itself.
+    fn col_names(&self) -> Vec<&'static str> {
         let mut cols = vec![];
 
         for col in self.cols {
@@ -39,99 +169,342 @@ impl<'a> CascadeSelecter<'a> {
             }
         }
 
-        let selecter = Selecter {
+        cols
+    }
+
+    fn into_selecter(self, cols: &[&'static str]) -> Selecter<'a> {
+        Selecter {
             from: self.table_name.into(),
-            cols: cols.clone().into(),
+            cols: cols.to_vec().into(),
             cond: self.cond,
+            group_by: vec![],
+            having: None,
             order_by: self.order_by,
             limit: self.limits,
-        };
+        }
+    }
 
-        let mut rows = d.select(&selecter).await?;
+    pub async fn exec<D>(self, d: &mut D) -> anyhow::Result<Vec<Vec<ColumnValue>>>
+    where
+        D: SelectSupport<'a> + Sync + Send,
+    {
+        let table_cols = self.cols;
+        let cols = self.col_names();
+        let relations = self.relations.clone();
+
+        relations.check(table_cols)?;
+
+        let mut rows = d.select(&self.into_selecter(&cols)).await?;
 
         let mut result = vec![];
 
         while rows.next().await? {
-            let mut row_values = vec![];
+            result.push(read_row(&mut rows, &cols).await?);
+        }
+
+        load_cascades(table_cols, &mut result, &relations, d).await?;
 
-            for col_name in &cols {
-                let value = rows.get_by_name(col_name).await?;
+        Ok(result)
+    }
+}
 
-                row_values.push(ColumnValue::Simple(*col_name, value));
+/// Read current row simple col values.
+async fn read_row<I>(rows: &mut I, cols: &[&'static str]) -> anyhow::Result<Vec<ColumnValue>>
+where
+    I: QueryIterator,
+{
+    let mut row_values = vec![];
+
+    for col_name in cols {
+        let value = rows.get_by_name(col_name).await?;
+
+        row_values.push(ColumnValue::Simple(col_name, value));
+    }
+
+    Ok(row_values)
+}
+
+/// Cascade select OneToOne/OntToMany cols of parent `rows`, filtered by `relations`.
+///
+/// Children of all `rows` are selected by one `foreign_key_col IN (..)` query
+/// per cascade col and per [`CASCADE_CHUNK_SIZE`] ref values.
+#[async_recursion::async_recursion]
+async fn load_cascades<'a, D>(
+    cols: &'static [Column],
+    rows: &mut [Vec<ColumnValue>],
+    relations: &Relations<'_>,
+    d: &mut D,
+) -> anyhow::Result<()>
+where
+    D: SelectSupport<'a> + Sync + Send,
+{
+    for col in cols {
+        let cascade = match col {
+            Column::OneToOne(cascade) | Column::OneToMany(cascade)
+                if cascade.required || relations.loads(cascade.name, cascade.lazy) =>
+            {
+                cascade
             }
+            Column::ManyToMany(many_to_many)
+                if relations.loads(many_to_many.name, many_to_many.lazy) =>
+            {
+                let nested = relations.scoped(many_to_many.name);
 
-            for col in self.cols {
-                match col {
-                    Column::OneToOne(cascade) => {
-                        let ref_col_value = find_col_value(&row_values, cascade.ref_col)
-                            .ok_or(anyhow::format_err!(
-                                "Cascade({}) ref_col {} is null",
-                                cascade.name,
-                                cascade.ref_col
-                            ))?
-                            .as_simple_value()?;
-
-                        let cascade_selecter = CascadeSelecter {
-                            table_name: (cascade.table_name)(),
-                            cols: (cascade.table_cols)(),
-                            cond: Some(CondExpr {
-                                op: CondOp::Eq,
-                                lhs: CondParam::Variant(cascade.foreign_key_col.into()),
-                                rhs: CondParam::Variant(ref_col_value.clone()),
-                            }),
-                            limits: Some(Limit {
-                                count: 1,
-                                offset: None,
-                            }),
-                            order_by: None,
-                        };
-
-                        let mut result = cascade_selecter.exec(d).await?;
-
-                        if !result.is_empty() {
-                            row_values.push(ColumnValue::OneToOne(cascade.name, result.remove(0)));
-                        }
-                    }
-                    Column::OneToMany(cascade) => {
-                        let ref_col_value = find_col_value(&row_values, cascade.ref_col)
-                            .ok_or(anyhow::format_err!(
-                                "Cascade({}) ref_col {} is null",
-                                cascade.name,
-                                cascade.ref_col
-                            ))?
-                            .as_simple_value()?;
-
-                        let cascade_selecter = CascadeSelecter {
-                            table_name: (cascade.table_name)(),
-                            cols: (cascade.table_cols)(),
-                            cond: Some(CondExpr {
-                                op: CondOp::Eq,
-                                lhs: CondParam::Variant(cascade.foreign_key_col.into()),
-                                rhs: CondParam::Variant(ref_col_value.clone()),
-                            }),
-                            limits: None,
-                            order_by: None,
-                        };
-
-                        let result = cascade_selecter.exec(d).await?;
-
-                        row_values.push(ColumnValue::OneToMany(cascade.name, result));
+                load_many_to_many(many_to_many, rows, &nested, d).await?;
+                continue;
+            }
+            _ => continue,
+        };
+
+        let (ref_values, keys) = ref_values(rows, cascade.name, cascade.ref_col)?;
+
+        let children = select_children(
+            (cascade.table_name)(),
+            (cascade.table_cols)(),
+            cascade.foreign_key_col,
+            keys,
+            &relations.scoped(cascade.name),
+            d,
+        )
+        .await?;
+
+        let groups = group_children(&children);
+
+        for (row_values, ref_value) in rows.iter_mut().zip(&ref_values) {
+            let mut matched = groups
+                .get(&VariantKey::new(ref_value))
+                .into_iter()
+                .flatten()
+                .map(|values| (*values).clone());
+
+            match col {
+                Column::OneToOne(_) => {
+                    if let Some(values) = matched.next() {
+                        row_values.push(ColumnValue::OneToOne(cascade.name, values));
                     }
-                    _ => {}
+                }
+                _ => {
+                    row_values.push(ColumnValue::OneToMany(cascade.name, matched.collect()));
                 }
             }
+        }
+    }
+
+    // `Table::from_values` expects values in `Table::cols` order.
+    for row_values in rows {
+        row_values.sort_by_key(|value| {
+            cols.iter()
+                .position(|col| col.col_name() == value.col_name())
+        });
+    }
+
+    Ok(())
+}
+
+/// `ref_col` value of each row, and the distinct non-null ones.
+fn ref_values(
+    rows: &[Vec<ColumnValue>],
+    name: &str,
+    ref_col: &str,
+) -> anyhow::Result<(Vec<Variant>, Vec<Variant>)> {
+    let mut ref_values = vec![];
+
+    for row_values in rows {
+        let value = find_col_value(row_values, ref_col)
+            .ok_or(anyhow::format_err!(
+                "Cascade({}) ref_col {} is null",
+                name,
+                ref_col
+            ))?
+            .as_simple_value()?;
+
+        ref_values.push(value.clone());
+    }
+
+    let mut keys = vec![];
+    let mut seen = HashSet::new();
 
-            result.push(row_values);
+    for value in &ref_values {
+        if *value != Variant::Null && seen.insert(VariantKey::new(value)) {
+            keys.push(value.clone());
         }
+    }
 
-        // Cascade select OneToOne/OntToMany cols
+    Ok((ref_values, keys))
+}
 
-        Ok(result)
+/// Select target rows of `many_to_many` col through link rows,
+/// targets are pushed to each row in link table order.
+async fn load_many_to_many<'a, D>(
+    many_to_many: &'static ManyToMany,
+    rows: &mut [Vec<ColumnValue>],
+    relations: &Relations<'_>,
+    d: &mut D,
+) -> anyhow::Result<()>
+where
+    D: SelectSupport<'a> + Sync + Send,
+{
+    let (ref_values, keys) = ref_values(rows, many_to_many.name, many_to_many.ref_col)?;
+
+    let links = select_children(
+        (many_to_many.through_table_name)(),
+        (many_to_many.through_table_cols)(),
+        many_to_many.from_col,
+        keys,
+        // Link rows are dropped after stitching, skip their cascades.
+        &Relations {
+            include: vec![],
+            eager: false,
+        },
+        d,
+    )
+    .await?;
+
+    let mut links_to = vec![];
+    let mut target_keys = vec![];
+    let mut seen = HashSet::new();
+
+    for (from, values) in links {
+        let to = find_col_value(&values, many_to_many.to_col)
+            .ok_or(anyhow::format_err!(
+                "ManyToMany({}) link col {} not found",
+                many_to_many.name,
+                many_to_many.to_col
+            ))?
+            .as_simple_value()?
+            .clone();
+
+        links_to.push((from, to));
     }
+
+    for (_, to) in &links_to {
+        if *to != Variant::Null && seen.insert(VariantKey::new(to)) {
+            target_keys.push(to.clone());
+        }
+    }
+
+    let table_cols = (many_to_many.table_cols)();
+
+    let (primary_col_name, _) =
+        table_primary_col(table_cols).ok_or(anyhow::format_err!("Primary col not found"))?;
+
+    let targets = select_children(
+        (many_to_many.table_name)(),
+        table_cols,
+        primary_col_name,
+        target_keys,
+        relations,
+        d,
+    )
+    .await?;
+
+    let mut links = HashMap::<_, Vec<_>>::new();
+
+    for (from, to) in &links_to {
+        links.entry(VariantKey::new(from)).or_default().push(to);
+    }
+
+    let mut targets_by_key = HashMap::new();
+
+    for (key, values) in &targets {
+        targets_by_key.entry(VariantKey::new(key)).or_insert(values);
+    }
+
+    for (row_values, ref_value) in rows.iter_mut().zip(&ref_values) {
+        let mut target_rows = vec![];
+
+        for to in links.get(&VariantKey::new(ref_value)).into_iter().flatten() {
+            if let Some(values) = targets_by_key.get(&VariantKey::new(to)) {
+                target_rows.push((*values).clone());
+            }
+        }
+
+        row_values.push(ColumnValue::OneToMany(many_to_many.name, target_rows));
+    }
+
+    Ok(())
+}
+
+/// Select `table_name` rows with `key_col` in `keys` and their cascades in `relations`,
+/// returns them in table order, paired with their `key_col` value.
+async fn select_children<'a, D>(
+    table_name: &'static str,
+    table_cols: &'static [Column],
+    key_col: &'static str,
+    keys: Vec<Variant>,
+    relations: &Relations<'_>,
+    d: &mut D,
+) -> anyhow::Result<Vec<(Variant, Vec<ColumnValue>)>>
+where
+    D: SelectSupport<'a> + Sync + Send,
+{
+    let mut cols = CascadeSelecter {
+        table_name,
+        cols: table_cols,
+        cond: None,
+        limits: None,
+        order_by: vec![],
+        relations: Relations::default(),
+    }
+    .col_names();
+
+    // Key col is selected for stitching, even if the child table doesn't map it.
+    let extra_col = !cols.contains(&key_col);
+
+    if extra_col {
+        cols.push(key_col);
+    }
+
+    let mut children = vec![];
+
+    for chunk in keys.chunks(CASCADE_CHUNK_SIZE) {
+        let selecter = Selecter {
+            from: table_name.into(),
+            cols: cols.clone().into(),
+            cond: Some(CondExpr {
+                op: CondOp::In,
+                lhs: CondParam::Column(key_col.into()),
+                rhs: CondParam::VariantList(chunk.to_vec()),
+            }),
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+        };
+
+        let mut rows = d.select(&selecter).await?;
+
+        while rows.next().await? {
+            children.push(read_row(&mut rows, &cols).await?);
+        }
+    }
+
+    relations.check(table_cols)?;
+
+    load_cascades(table_cols, &mut children, relations, d).await?;
+
+    let mut result = vec![];
+
+    for mut values in children {
+        let idx = values
+            .iter()
+            .position(|value| value.col_name() == key_col)
+            .unwrap();
+
+        let key = if extra_col {
+            values.remove(idx).as_simple_value()?.clone()
+        } else {
+            values[idx].as_simple_value()?.clone()
+        };
+
+        result.push((key, values));
+    }
+
+    Ok(result)
 }
 
 //////////////////////////////////////////////////////////////////////////////////////
-/// Select one
+// Select one
 
 /// Select one row context.
 pub struct SelectOne<'a, T> {
@@ -156,6 +529,22 @@ where
             Ok(None)
         }
     }
+
+    /// Load cascade col `name`, even if tagged by `#[cascade(lazy)]`.
+    ///
+    /// Cols of cascade rows are named by dotted paths, e.g. `cards.owner`,
+    /// which loads `cards` too.
+    pub fn with_relation(mut self, name: &'a str) -> Self {
+        self.selecter.relations.include.push(name);
+        self
+    }
+
+    /// Skip cascade cols not requested by [`with_relation`](Self::with_relation),
+    /// at every level. Non-`Option` one-to-one cols are still loaded.
+    pub fn without_relations(mut self) -> Self {
+        self.selecter.relations.eager = false;
+        self
+    }
 }
 
 impl<T> Select for T
@@ -170,17 +559,18 @@ where
                 cols: T::cols(),
                 cond: None,
                 limits: None,
-                order_by: None,
+                order_by: vec![],
+                relations: Relations::default(),
             },
             _marked: Default::default(),
         }
     }
 }
 
-impl<'a, T> Where for SelectOne<'a, T> {
+impl<'a, T> Where<'a> for SelectOne<'a, T> {
     type Context = SelectOne<'a, T>;
 
-    fn cond(mut self, cond: CondExpr) -> Self::Context {
+    fn cond(mut self, cond: CondExpr<'a>) -> Self::Context {
         self.selecter.cond = Some(cond);
         self
     }
@@ -203,15 +593,25 @@ impl<'a, T> super::Order<'a> for SelectOne<'a, T> {
     type Context = SelectOne<'a, T>;
 
     fn order_by(mut self, col_name: &'a str, desc: bool) -> Self::Context {
-        self.selecter.order_by = Some(OrderBy { col_name, desc });
+        self.selecter.order_by.clear();
+
+        self.then_by(col_name, desc)
+    }
+
+    fn then_by(mut self, col_name: &'a str, desc: bool) -> Self::Context {
+        self.selecter.order_by.push(OrderBy {
+            table: None,
+            col_name,
+            desc,
+            nulls: None,
+        });
 
         self
     }
 }
 
 //////////////////////////////////////////////////////////////////////////////////////
-/// Select many
-///
+// Select many
 
 pub struct SelectMany<'a, T> {
     selecter: CascadeSelecter<'a>,
@@ -237,6 +637,123 @@ where
 
         Ok(result)
     }
+
+    /// Load cascade col `name`, even if tagged by `#[cascade(lazy)]`.
+    ///
+    /// Cols of cascade rows are named by dotted paths, e.g. `cards.owner`,
+    /// which loads `cards` too.
+    pub fn with_relation(mut self, name: &'a str) -> Self {
+        self.selecter.relations.include.push(name);
+        self
+    }
+
+    /// Skip cascade cols not requested by [`with_relation`](Self::with_relation),
+    /// at every level. Non-`Option` one-to-one cols are still loaded.
+    pub fn without_relations(mut self) -> Self {
+        self.selecter.relations.eager = false;
+        self
+    }
+
+    /// Stream rows instead of collecting them into memory.
+    ///
+    /// Parent rows are read in chunks of [`STREAM_CHUNK_SIZE`],
+    /// cascade cols are selected per chunk, so memory stays bounded.
+    pub fn stream<'d, D>(self, d: &'d mut D) -> BoxStream<'d, anyhow::Result<T>>
+    where
+        D: SelectSupport<'a> + Sync + Send,
+        T: Send + 'd,
+        'a: 'd,
+    {
+        let table_cols = self.selecter.cols;
+        let cols: Arc<[&'static str]> = self.selecter.col_names().into();
+        let relations = self.selecter.relations.clone();
+        let selecter = self.selecter.into_selecter(&cols);
+
+        let chunks = async move {
+            relations.check(table_cols)?;
+
+            let rows = d.select(&selecter).await?;
+
+            let rows = into_stream(rows, move |rows| {
+                let cols = cols.clone();
+                Box::pin(async move { read_row(rows, &cols).await })
+            })
+            .try_chunks(STREAM_CHUNK_SIZE)
+            .map_err(|err| err.1);
+
+            Ok::<_, anyhow::Error>((d, Box::pin(rows), relations))
+        };
+
+        futures::stream::once(chunks)
+            .map_ok(move |state| {
+                futures::stream::try_unfold(state, move |(d, mut rows, relations)| async move {
+                    let mut chunk = match rows.try_next().await? {
+                        Some(chunk) => chunk,
+                        None => return Ok(None),
+                    };
+
+                    load_cascades(table_cols, &mut chunk, &relations, d).await?;
+
+                    let values = futures::stream::iter(chunk.into_iter().map(T::from_values));
+
+                    anyhow::Ok(Some((values, (d, rows, relations))))
+                })
+                .try_flatten()
+            })
+            .try_flatten()
+            .boxed()
+    }
+
+    /// Count rows matching the cond, limit and order are ignored.
+    pub async fn count<D>(self, d: &mut D) -> anyhow::Result<usize>
+    where
+        D: SelectSupport<'a> + Sync + Send,
+    {
+        let selecter = Selecter {
+            from: self.selecter.table_name.into(),
+            cols: SelectColumns::NamedColumns(vec![SelectNamedColumn::aggregate(
+                AggregateFunc::Count,
+                "*",
+                Some("count"),
+            )]),
+            cond: self.selecter.cond,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+        };
+
+        let mut rows = d.select(&selecter).await?;
+
+        if !rows.next().await? {
+            return Err(anyhow::format_err!("COUNT(*) returns no row"));
+        }
+
+        rows.get(0).await?.try_into()
+    }
+
+    /// Check if any row matches the cond, without cascade select.
+    pub async fn exists<D>(self, d: &mut D) -> anyhow::Result<bool>
+    where
+        D: SelectSupport<'a> + Sync + Send,
+    {
+        let selecter = Selecter {
+            from: self.selecter.table_name.into(),
+            cols: SelectColumns::All,
+            cond: self.selecter.cond,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: Some(Limit {
+                count: 1,
+                offset: None,
+            }),
+        };
+
+        let mut rows = d.select(&selecter).await?;
+
+        rows.next().await
+    }
 }
 
 impl<T> Select for Vec<T>
@@ -251,17 +768,18 @@ where
                 cols: T::cols(),
                 cond: None,
                 limits: None,
-                order_by: None,
+                order_by: vec![],
+                relations: Relations::default(),
             },
             _marked: Default::default(),
         }
     }
 }
 
-impl<'a, T> Where for SelectMany<'a, T> {
+impl<'a, T> Where<'a> for SelectMany<'a, T> {
     type Context = SelectMany<'a, T>;
 
-    fn cond(mut self, cond: CondExpr) -> Self::Context {
+    fn cond(mut self, cond: CondExpr<'a>) -> Self::Context {
         self.selecter.cond = Some(cond);
         self
     }
@@ -272,7 +790,7 @@ impl<'a, T> super::Limit for SelectMany<'a, T> {
 
     fn limit(mut self, count: usize, offset: usize) -> Self::Context {
         self.selecter.limits = Some(Limit {
-            count: count,
+            count,
             offset: Some(offset),
         });
 
@@ -284,7 +802,18 @@ impl<'a, T> super::Order<'a> for SelectMany<'a, T> {
     type Context = SelectMany<'a, T>;
 
     fn order_by(mut self, col_name: &'a str, desc: bool) -> Self::Context {
-        self.selecter.order_by = Some(OrderBy { col_name, desc });
+        self.selecter.order_by.clear();
+
+        self.then_by(col_name, desc)
+    }
+
+    fn then_by(mut self, col_name: &'a str, desc: bool) -> Self::Context {
+        self.selecter.order_by.push(OrderBy {
+            table: None,
+            col_name,
+            desc,
+            nulls: None,
+        });
 
         self
     }
@@ -1,15 +1,15 @@
 use std::marker::PhantomData;
 
 use crate::{
-    dml::{CondExpr, CondOp, CondParam, Updater},
-    driver::UpdateSupport,
-    orm::{find_col, table_primary_col, Column},
+    dml::{CondExpr, CondOp, CondParam, ConflictAction, Deleter, Inserter, OnConflict, Updater},
+    driver::{DeleteSupport, InsertSupport, Transaction, TransactionSupport, UpdateSupport},
+    orm::{find_col, table_primary_col, Column, KeyGen, ManyToMany},
     Variant,
 };
 
 use crate::orm::{ColumnValue, Table, Where};
 
-use super::Update;
+use super::{insert::target_key, Update};
 
 struct UpdateCascade {
     table_name: &'static str,
@@ -18,15 +18,31 @@ struct UpdateCascade {
     foreign_key_col_value: Option<Variant>,
     cols: &'static [Column],
     values: Vec<ColumnValue>,
-    cond: Option<CondExpr>,
+    cond: Option<CondExpr<'static>>,
+}
+
+/// Link rows of a loaded many-to-many col, synced after rows are updated.
+struct LinkUpdate {
+    many_to_many: &'static ManyToMany,
+    /// Primary key of the owner row
+    from: Variant,
+    /// Primary keys of the target rows
+    keys: Vec<Variant>,
 }
 
 impl UpdateCascade {
-    pub async fn exec<'a, D>(self, d: &mut D) -> anyhow::Result<Vec<UpdateCascade>>
+    /// Update the row, link rows of its loaded many-to-many cols are pushed to `links`.
+    ///
+    /// Returns cascades of the next level.
+    pub async fn exec<'a, D>(
+        self,
+        d: &mut D,
+        links: &mut Vec<LinkUpdate>,
+    ) -> anyhow::Result<Vec<UpdateCascade>>
     where
         D: UpdateSupport<'a> + Sync + Send,
     {
-        let (primary_col_name, auto_inc) =
+        let (primary_col_name, key_gen) =
             table_primary_col(self.cols).ok_or(anyhow::format_err!("Primary col not found"))?;
 
         let mut primary_col_value = None;
@@ -35,6 +51,7 @@ impl UpdateCascade {
         let mut values = vec![];
 
         let mut update_cascades = vec![];
+        let mut link_keys = vec![];
 
         for value in self.values {
             match value {
@@ -46,7 +63,7 @@ impl UpdateCascade {
                     if col_name == primary_col_name {
                         primary_col_value = Some(value.clone());
 
-                        if auto_inc {
+                        if key_gen == KeyGen::AutoInc {
                             continue;
                         }
                     }
@@ -94,6 +111,14 @@ impl UpdateCascade {
                                 })
                             }
                         }
+                        Column::ManyToMany(many_to_many) => {
+                            let keys = rows
+                                .iter()
+                                .map(|values| target_key(many_to_many, values))
+                                .collect::<anyhow::Result<Vec<_>>>()?;
+
+                            link_keys.push((many_to_many, keys));
+                        }
                         _ => {
                             return Err(anyhow::format_err!(
                                 "Target col {} is not OneToMany column",
@@ -105,7 +130,7 @@ impl UpdateCascade {
             }
         }
 
-        for mut cascade in &mut update_cascades {
+        for cascade in &mut update_cascades {
             let ref_col = cascade.ref_col.unwrap();
 
             if let Some((idx, _)) = cols
@@ -129,14 +154,14 @@ impl UpdateCascade {
             if let Some(col_name) = self.foreign_key_col {
                 Some(CondExpr {
                     op: CondOp::Eq,
-                    lhs: CondParam::Variant(col_name.into()),
-                    rhs: CondParam::Variant(self.foreign_key_col_value.unwrap()),
+                    lhs: CondParam::Column(col_name.into()),
+                    rhs: CondParam::Param(self.foreign_key_col_value.unwrap()),
                 })
-            } else if let Some(value) = primary_col_value {
+            } else if let Some(value) = &primary_col_value {
                 Some(CondExpr {
                     op: CondOp::Eq,
-                    lhs: CondParam::Variant(primary_col_name.into()),
-                    rhs: CondParam::Variant(value),
+                    lhs: CondParam::Column(primary_col_name.into()),
+                    rhs: CondParam::Param(value.clone()),
                 })
             } else {
                 return Err(anyhow::format_err!(
@@ -146,25 +171,127 @@ impl UpdateCascade {
             }
         };
 
+        for (many_to_many, keys) in link_keys {
+            let from = match &primary_col_value {
+                Some(value) if many_to_many.ref_col == primary_col_name => value.clone(),
+                _ => {
+                    return Err(anyhow::format_err!(
+                        "ManyToMany update({}) ref_col({}) is null",
+                        many_to_many.name,
+                        many_to_many.ref_col
+                    ))
+                }
+            };
+
+            links.push(LinkUpdate {
+                many_to_many,
+                from,
+                keys,
+            });
+        }
+
         d.update(
             &Updater {
                 table_name: self.table_name,
                 cols: cols.into(),
                 cond,
+                returning: None,
             },
             values,
         )
         .await?;
 
-        // Then, try match ref_col to auto_inc primary col bound value.
-
         Ok(update_cascades)
     }
 }
 
+/// Update `cascades` and their cascade rows, collecting link rows to `links`.
+async fn exec_cascades<'a, D>(
+    mut update_stack: Vec<UpdateCascade>,
+    d: &mut D,
+    links: &mut Vec<LinkUpdate>,
+) -> anyhow::Result<()>
+where
+    D: UpdateSupport<'a> + Sync + Send,
+{
+    while let Some(cascade) = update_stack.pop() {
+        let mut next = cascade.exec(d, links).await?;
+
+        update_stack.append(&mut next);
+    }
+
+    Ok(())
+}
+
+/// Sync link rows of each owner row in `links`, links to other targets are deleted
+/// and missing ones inserted, existing ones skipped by the `(from_col, to_col)` unique key.
+async fn sync_links<'a, D>(links: Vec<LinkUpdate>, d: &mut D) -> anyhow::Result<()>
+where
+    D: InsertSupport<'a> + DeleteSupport<'a> + Sync + Send,
+{
+    for link in links {
+        let many_to_many = link.many_to_many;
+        let table_name = (many_to_many.through_table_name)();
+
+        let from_cond = CondExpr {
+            op: CondOp::Eq,
+            lhs: CondParam::Column(many_to_many.from_col.into()),
+            rhs: CondParam::Param(link.from.clone()),
+        };
+
+        let cond = if link.keys.is_empty() {
+            from_cond
+        } else {
+            CondExpr {
+                op: CondOp::And,
+                lhs: CondParam::CondExpr(Box::new(from_cond)),
+                rhs: CondParam::CondExpr(Box::new(CondExpr {
+                    op: CondOp::NotIn,
+                    lhs: CondParam::Column(many_to_many.to_col.into()),
+                    rhs: CondParam::VariantList(link.keys.clone()),
+                })),
+            }
+        };
+
+        d.delete(&Deleter {
+            table_name,
+            cond,
+            returning: None,
+        })
+        .await?;
+
+        if !link.keys.is_empty() {
+            let cols = vec![many_to_many.from_col, many_to_many.to_col];
+
+            let rows = link
+                .keys
+                .into_iter()
+                .map(|key| vec![link.from.clone(), key])
+                .collect();
+
+            d.insert_many(
+                &Inserter {
+                    table_name,
+                    cols: cols.clone().into(),
+                    on_conflict: Some(OnConflict {
+                        target: cols.into(),
+                        action: ConflictAction::DoNothing,
+                    }),
+                    returning: None,
+                },
+                rows,
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Update context struct
 pub struct UpdateContext<T> {
-    cascade: UpdateCascade,
+    /// Serialize error of the object is returned by `exec`.
+    cascade: anyhow::Result<UpdateCascade>,
     _marked: PhantomData<T>,
 }
 
@@ -172,22 +299,33 @@ impl<T> UpdateContext<T>
 where
     T: Table + Default,
 {
+    /// Update the object and its cascade rows, loaded many-to-many cols sync their link rows.
     pub async fn exec<'a, D>(self, d: &mut D) -> anyhow::Result<()>
     where
-        D: UpdateSupport<'a> + Sync + Send,
+        D: UpdateSupport<'a> + InsertSupport<'a> + DeleteSupport<'a> + Sync + Send,
     {
-        let mut update_stack = vec![self.cascade];
+        let mut links = vec![];
 
-        // Cascade insert row
-        while !update_stack.is_empty() {
-            let cascade = update_stack.pop().unwrap();
+        exec_cascades(vec![self.cascade?], d, &mut links).await?;
 
-            let mut next = cascade.exec(d).await?;
+        sync_links(links, d).await
+    }
 
-            update_stack.append(&mut next);
-        }
+    /// Same as [`exec`](Self::exec), but runs the whole cascade in one transaction.
+    pub async fn exec_tx<'a, D>(self, d: &mut D) -> anyhow::Result<()>
+    where
+        D: UpdateSupport<'a>
+            + InsertSupport<'a>
+            + DeleteSupport<'a>
+            + TransactionSupport
+            + Sync
+            + Send,
+    {
+        let mut tx = Transaction::begin(d).await?;
+
+        let result = self.exec(&mut *tx).await;
 
-        Ok(())
+        tx.finish(result).await
     }
 }
 
@@ -198,30 +336,34 @@ where
     type Context = UpdateContext<T>;
     fn update(self) -> Self::Context {
         UpdateContext {
-            cascade: UpdateCascade {
+            cascade: self.into_values().map(|values| UpdateCascade {
                 table_name: T::table_name(),
                 ref_col: None,
                 foreign_key_col_value: None,
                 foreign_key_col: None,
                 cols: T::cols(),
-                values: self.into_values(),
+                values,
                 cond: None,
-            },
+            }),
             _marked: Default::default(),
         }
     }
 }
 
-impl<T> Where for UpdateContext<T> {
+impl<T> Where<'static> for UpdateContext<T> {
     type Context = UpdateContext<T>;
-    fn cond(mut self, cond: crate::dml::CondExpr) -> Self {
-        self.cascade.cond = Some(cond);
+    fn cond(mut self, cond: CondExpr<'static>) -> Self {
+        if let Ok(cascade) = &mut self.cascade {
+            cascade.cond = Some(cond);
+        }
+
         self
     }
 }
 
 pub struct UpdateAll<T> {
-    cascades: Vec<UpdateCascade>,
+    /// Serialize error of any object is returned by `exec`.
+    cascades: anyhow::Result<Vec<UpdateCascade>>,
     _marked: PhantomData<T>,
 }
 
@@ -229,22 +371,33 @@ impl<T> UpdateAll<T>
 where
     T: Table + Default,
 {
+    /// Update the objects and their cascade rows, loaded many-to-many cols sync their link rows.
     pub async fn exec<'a, D>(self, d: &mut D) -> anyhow::Result<()>
     where
-        D: UpdateSupport<'a> + Sync + Send,
+        D: UpdateSupport<'a> + InsertSupport<'a> + DeleteSupport<'a> + Sync + Send,
     {
-        let mut update_stack = self.cascades;
+        let mut links = vec![];
+
+        exec_cascades(self.cascades?, d, &mut links).await?;
 
-        // Cascade insert row
-        while !update_stack.is_empty() {
-            let cascade = update_stack.pop().unwrap();
+        sync_links(links, d).await
+    }
 
-            let mut next = cascade.exec(d).await?;
+    /// Same as [`exec`](Self::exec), but runs the whole cascade in one transaction.
+    pub async fn exec_tx<'a, D>(self, d: &mut D) -> anyhow::Result<()>
+    where
+        D: UpdateSupport<'a>
+            + InsertSupport<'a>
+            + DeleteSupport<'a>
+            + TransactionSupport
+            + Sync
+            + Send,
+    {
+        let mut tx = Transaction::begin(d).await?;
 
-            update_stack.append(&mut next);
-        }
+        let result = self.exec(&mut *tx).await;
 
-        Ok(())
+        tx.finish(result).await
     }
 }
 
@@ -254,19 +407,20 @@ where
 {
     type Context = UpdateAll<T>;
     fn update(self) -> Self::Context {
-        let mut cascades = vec![];
-
-        for t in self {
-            cascades.push(UpdateCascade {
-                table_name: T::table_name(),
-                ref_col: None,
-                foreign_key_col_value: None,
-                foreign_key_col: None,
-                cols: T::cols(),
-                values: t.into_values(),
-                cond: None,
-            });
-        }
+        let cascades = self
+            .into_iter()
+            .map(|t| {
+                Ok(UpdateCascade {
+                    table_name: T::table_name(),
+                    ref_col: None,
+                    foreign_key_col_value: None,
+                    foreign_key_col: None,
+                    cols: T::cols(),
+                    values: t.into_values()?,
+                    cond: None,
+                })
+            })
+            .collect();
 
         UpdateAll {
             cascades,
@@ -0,0 +1,46 @@
+use crate::driver::QueryIterator;
+
+use super::ColumnValue;
+
+/// Structures decoded from ad-hoc query rows, e.g. projections or report queries.
+///
+/// Unlike [`Table`](super::Table), no primary key or cascade cols are required,
+/// use `#[derive(FromRow)]` to implement it.
+pub trait FromRow: Sized {
+    /// Result set col names, in [`FromRow::from_values`] order.
+    fn col_names() -> &'static [&'static str];
+
+    fn from_values(values: Vec<ColumnValue>) -> anyhow::Result<Self>;
+}
+
+/// Decode current row of `rows`, call [`QueryIterator::next`] first.
+pub async fn decode_row<T, I>(rows: &mut I) -> anyhow::Result<T>
+where
+    T: FromRow,
+    I: QueryIterator + ?Sized,
+{
+    let mut values = vec![];
+
+    for col_name in T::col_names() {
+        let value = rows.get_by_name(col_name).await?;
+
+        values.push(ColumnValue::Simple(col_name, value));
+    }
+
+    T::from_values(values)
+}
+
+/// Decode all remaining rows of `rows`.
+pub async fn collect_rows<T, I>(mut rows: I) -> anyhow::Result<Vec<T>>
+where
+    T: FromRow,
+    I: QueryIterator,
+{
+    let mut result = vec![];
+
+    while rows.next().await? {
+        result.push(decode_row(&mut rows).await?);
+    }
+
+    Ok(result)
+}
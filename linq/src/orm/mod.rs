@@ -16,3 +16,6 @@ pub use dsl::*;
 
 mod col_like;
 pub use col_like::*;
+
+mod from_row;
+pub use from_row::*;
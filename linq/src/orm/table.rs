@@ -8,18 +8,17 @@ pub trait Table: Sized {
 
     fn from_values(values: Vec<ColumnValue>) -> anyhow::Result<Self>;
 
-    fn into_values(self) -> Vec<ColumnValue>;
+    fn into_values(self) -> anyhow::Result<Vec<ColumnValue>>;
 }
 
-pub fn table_primary_col(cols: &'static [Column]) -> Option<(&'static str, bool)> {
+pub fn table_primary_col(cols: &'static [Column]) -> Option<(&'static str, KeyGen)> {
     for col in cols {
-        match col {
-            Column::Primary(name, auto_inc) => return Some((name, *auto_inc)),
-            _ => {}
+        if let Column::Primary(name, key_gen) = col {
+            return Some((name, *key_gen));
         }
     }
 
-    return None;
+    None
 }
 /// Find col by col_name from array
 pub fn find_col<'a>(cols: &'a [Column], col_name: &'a str) -> Option<&'a Column> {
@@ -31,32 +30,45 @@ pub fn find_col_value<'a>(cols: &'a [ColumnValue], col_name: &'a str) -> Option<
 }
 
 pub trait TableEx {
-    fn table_primary_col() -> Option<(&'static str, bool)>;
+    fn table_primary_col() -> Option<(&'static str, KeyGen)>;
 }
 
 impl<T> TableEx for T
 where
     T: Table,
 {
-    fn table_primary_col() -> Option<(&'static str, bool)> {
+    fn table_primary_col() -> Option<(&'static str, KeyGen)> {
         table_primary_col(Self::cols())
     }
 }
 
+/// How primary key value is generated on insert
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyGen {
+    /// Set by caller
+    Manual,
+    /// Generated by database, tagged by `#[primary(autoinc)]`
+    AutoInc,
+    /// Random v4 uuid generated client side, tagged by `#[primary(uuid)]`
+    Uuid,
+}
+
 pub enum Column {
-    Primary(&'static str, bool),
+    Primary(&'static str, KeyGen),
     Simple(&'static str),
     OneToOne(Cascade),
     OneToMany(Cascade),
+    ManyToMany(ManyToMany),
 }
 
 impl Column {
     pub fn col_name(&self) -> &'static str {
         match self {
-            Column::Primary(name, _) => *name,
-            Column::Simple(name) => *name,
+            Column::Primary(name, _) => name,
+            Column::Simple(name) => name,
             Self::OneToOne(cascade) => cascade.name,
             Self::OneToMany(cascade) => cascade.name,
+            Self::ManyToMany(many_to_many) => many_to_many.name,
         }
     }
 }
@@ -73,6 +85,30 @@ pub struct Cascade {
     pub table_name: fn() -> &'static str,
     pub foreign_key_col: &'static str,
     pub table_cols: fn() -> &'static [Column],
+    /// Skipped by select unless requested, tagged by `#[cascade(lazy)]`
+    pub lazy: bool,
+    /// OneToOne field isn't `Option`, so select always loads it
+    pub required: bool,
+}
+
+/// Relation to rows of `table_name` through link table rows,
+/// tagged by `#[many_to_many(through = Link, from = col_x, to = col_y)]`.
+///
+/// Values are [`ColumnValue::OneToMany`] rows of the target table.
+pub struct ManyToMany {
+    pub name: &'static str,
+    /// Primary col of the owner table, referenced by `from_col`
+    pub ref_col: &'static str,
+    pub through_table_name: fn() -> &'static str,
+    pub through_table_cols: fn() -> &'static [Column],
+    /// Link table col referencing the owner row
+    pub from_col: &'static str,
+    /// Link table col referencing the target row primary col
+    pub to_col: &'static str,
+    pub table_name: fn() -> &'static str,
+    pub table_cols: fn() -> &'static [Column],
+    /// Skipped by select unless requested, tagged by `lazy` arg
+    pub lazy: bool,
 }
 
 #[derive(Debug, Clone)]
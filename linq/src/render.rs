@@ -0,0 +1,390 @@
+//! Render LINQ IR into parameterized SQL text.
+//!
+//! The [`Dialect`] trait describes how a target database quotes identifiers,
+//! names bind placeholders and spells column types. The free functions
+//! [`select`], [`insert`], [`update`], [`delete`] and [`ddl`] walk the IR and
+//! return [`Statement`]s whose `params` must be bound in order.
+//!
+//! # Examples
+//!
+//! ```
+//! use linq_rs::*;
+//!
+//! let qir = rql! {
+//!     SELECT name FROM user WHERE id = 1;
+//! };
+//!
+//! let stmt = render::select(&render::Postgres, &qir).unwrap();
+//!
+//! assert_eq!(stmt.sql, r#"SELECT "name" FROM "user" WHERE "id" = $1"#);
+//! assert_eq!(stmt.params, vec![Variant::Int(1)]);
+//! ```
+
+mod ddl;
+mod dml;
+
+mod sqlite;
+pub use sqlite::*;
+
+mod postgres;
+pub use postgres::*;
+
+mod mysql;
+pub use mysql::*;
+
+use crate::{
+    dml::{ConflictAction, Nulls, OnConflict, OrderBy},
+    IrType, Variant,
+};
+
+/// Rendered SQL statement with bind parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// SQL text with dialect placeholders
+    pub sql: String,
+    /// Bind parameters, in placeholder order
+    pub params: Vec<Variant>,
+}
+
+/// SQL dialect used by the renderer.
+///
+/// Only [`col_type`](Dialect::col_type) and
+/// [`auto_inc_col_type`](Dialect::auto_inc_col_type) are required,
+/// the other methods default to ANSI SQL.
+pub trait Dialect: Sync + Send {
+    /// Quote identifier, e.g. table or column name.
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("\"{}\"", ident.replace('"', "\"\""))
+    }
+
+    /// Bind placeholder for the `index`-th parameter, starting from 1.
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_owned()
+    }
+
+    /// Column type name.
+    fn col_type(&self, col_type: &IrType) -> anyhow::Result<String>;
+
+    /// Column type and key clause of an auto increment primary column.
+    fn auto_inc_col_type(&self, col_type: &IrType) -> anyhow::Result<String>;
+
+    /// Inline literal, used where bind parameters are not allowed (e.g. `DEFAULT` value).
+    fn literal(&self, value: &Variant) -> anyhow::Result<String> {
+        default_literal(value)
+    }
+
+    /// Insert a row with all default values.
+    fn insert_default_values(&self, w: &mut Writer<'_>, table_name: &str) -> anyhow::Result<()> {
+        w.push("INSERT INTO ");
+        w.push_ident(table_name);
+        w.push(" DEFAULT VALUES");
+
+        Ok(())
+    }
+
+    /// Render `ON CONFLICT` clause of insert stmt.
+    fn on_conflict(&self, w: &mut Writer<'_>, on_conflict: &OnConflict<'_>) -> anyhow::Result<()> {
+        let target = on_conflict.target.col_names();
+
+        w.push(" ON CONFLICT");
+
+        if !target.is_empty() {
+            w.push(" (");
+            w.push_idents(target);
+            w.push(")");
+        }
+
+        match &on_conflict.action {
+            ConflictAction::DoNothing => w.push(" DO NOTHING"),
+            ConflictAction::DoUpdate(cols) => {
+                if target.is_empty() {
+                    return Err(anyhow::format_err!("Upsert without conflict target"));
+                }
+
+                if cols.col_names().is_empty() {
+                    return Err(anyhow::format_err!("Upsert without cols"));
+                }
+
+                w.push(" DO UPDATE SET ");
+
+                for (idx, col) in cols.col_names().iter().enumerate() {
+                    if idx > 0 {
+                        w.push(", ");
+                    }
+
+                    w.push_ident(col);
+                    w.push(" = excluded.");
+                    w.push_ident(col);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render `RETURNING` clause of insert, update or delete stmt.
+    fn returning(&self, w: &mut Writer<'_>, cols: &[&str]) -> anyhow::Result<()> {
+        w.push(" RETURNING ");
+        w.push_idents(cols);
+
+        Ok(())
+    }
+
+    /// Render `TRUNCATE TABLE` stmt.
+    fn truncate(&self, w: &mut Writer<'_>, table_name: &str) -> anyhow::Result<()> {
+        w.push("TRUNCATE TABLE ");
+        w.push_ident(table_name);
+
+        Ok(())
+    }
+
+    /// Render `ALTER COLUMN` stmt, which has no portable syntax.
+    fn alter_column(
+        &self,
+        _w: &mut Writer<'_>,
+        table_name: &str,
+        col: &crate::ddl::Column<'_>,
+    ) -> anyhow::Result<()> {
+        Err(anyhow::format_err!(
+            "Dialect not support alter column {}.{}",
+            table_name,
+            col.name
+        ))
+    }
+
+    /// Render `RENAME CONSTRAINT` stmt.
+    fn rename_constraint(
+        &self,
+        w: &mut Writer<'_>,
+        table_name: &str,
+        from: &str,
+        to: &str,
+    ) -> anyhow::Result<()> {
+        w.push("ALTER TABLE ");
+        w.push_ident(table_name);
+        w.push(" RENAME CONSTRAINT ");
+        w.push_ident(from);
+        w.push(" TO ");
+        w.push_ident(to);
+
+        Ok(())
+    }
+
+    /// Render `DROP INDEX` stmt.
+    fn drop_index(
+        &self,
+        w: &mut Writer<'_>,
+        _table_name: &str,
+        index_name: &str,
+    ) -> anyhow::Result<()> {
+        w.push("DROP INDEX ");
+        w.push_ident(index_name);
+
+        Ok(())
+    }
+
+    /// Whether the dialect supports `ALTER TABLE ... ADD/DROP CONSTRAINT`
+    fn alter_constraint_support(&self) -> bool {
+        true
+    }
+
+    /// Render one term of `ORDER BY` clause.
+    fn order_by(&self, w: &mut Writer<'_>, order_by: &OrderBy<'_>) {
+        w.push_col(order_by.table, order_by.col_name);
+
+        w.push(if order_by.desc { " DESC" } else { " ASC" });
+
+        match order_by.nulls {
+            Some(Nulls::First) => w.push(" NULLS FIRST"),
+            Some(Nulls::Last) => w.push(" NULLS LAST"),
+            None => {}
+        }
+    }
+}
+
+/// SQL text builder, tracks bind parameters and placeholder index.
+pub struct Writer<'a> {
+    dialect: &'a dyn Dialect,
+    sql: String,
+    params: Vec<Variant>,
+}
+
+impl<'a> Writer<'a> {
+    pub fn new(dialect: &'a dyn Dialect) -> Self {
+        Self {
+            dialect,
+            sql: String::new(),
+            params: vec![],
+        }
+    }
+
+    pub fn dialect(&self) -> &'a dyn Dialect {
+        self.dialect
+    }
+
+    /// Append raw sql text
+    pub fn push(&mut self, sql: &str) {
+        self.sql.push_str(sql);
+    }
+
+    /// Append quoted identifier
+    pub fn push_ident(&mut self, ident: &str) {
+        let quoted = self.dialect.quote_ident(ident);
+        self.sql.push_str(&quoted);
+    }
+
+    /// Append optionally table qualified column, e.g. `"u"."id"`
+    pub fn push_col(&mut self, table: Option<&str>, name: &str) {
+        if let Some(table) = table {
+            self.push_ident(table);
+            self.push(".");
+        }
+
+        self.push_ident(name);
+    }
+
+    /// Append comma separated quoted identifiers
+    pub fn push_idents(&mut self, idents: &[&str]) {
+        for (idx, ident) in idents.iter().enumerate() {
+            if idx > 0 {
+                self.push(", ");
+            }
+
+            self.push_ident(ident);
+        }
+    }
+
+    /// Append bind placeholder and record `value`
+    pub fn push_param(&mut self, value: Variant) {
+        self.params.push(value);
+        let placeholder = self.dialect.placeholder(self.params.len());
+        self.sql.push_str(&placeholder);
+    }
+
+    /// Append inline literal
+    pub fn push_literal(&mut self, value: &Variant) -> anyhow::Result<()> {
+        let literal = self.dialect.literal(value)?;
+        self.sql.push_str(&literal);
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> Statement {
+        Statement {
+            sql: self.sql,
+            params: self.params,
+        }
+    }
+}
+
+/// Render `SELECT` stmt
+pub fn select(
+    dialect: &dyn Dialect,
+    selecter: &crate::dml::Selecter<'_>,
+) -> anyhow::Result<Statement> {
+    let mut w = Writer::new(dialect);
+
+    dml::select(&mut w, selecter)?;
+
+    Ok(w.finish())
+}
+
+/// Render `INSERT` stmt, `values` are bound in `inserter.cols` order.
+pub fn insert(
+    dialect: &dyn Dialect,
+    inserter: &crate::dml::Inserter<'_>,
+    values: Vec<Variant>,
+) -> anyhow::Result<Statement> {
+    insert_many(dialect, inserter, vec![values])
+}
+
+/// Render multi-row `INSERT` stmt, every row binds values in `inserter.cols` order.
+pub fn insert_many(
+    dialect: &dyn Dialect,
+    inserter: &crate::dml::Inserter<'_>,
+    rows: Vec<Vec<Variant>>,
+) -> anyhow::Result<Statement> {
+    let mut w = Writer::new(dialect);
+
+    dml::insert(&mut w, inserter, rows)?;
+
+    Ok(w.finish())
+}
+
+/// Render `UPDATE` stmt, `values` are bound in `updater.cols` order.
+pub fn update(
+    dialect: &dyn Dialect,
+    updater: &crate::dml::Updater<'_>,
+    values: Vec<Variant>,
+) -> anyhow::Result<Statement> {
+    let mut w = Writer::new(dialect);
+
+    dml::update(&mut w, updater, values)?;
+
+    Ok(w.finish())
+}
+
+/// Render `DELETE` stmt
+pub fn delete(
+    dialect: &dyn Dialect,
+    deleter: &crate::dml::Deleter<'_>,
+) -> anyhow::Result<Statement> {
+    let mut w = Writer::new(dialect);
+
+    dml::delete(&mut w, deleter)?;
+
+    Ok(w.finish())
+}
+
+/// Render one DDL expr, which may expand into several stmts
+/// (e.g. `CREATE TABLE` followed by `CREATE INDEX`).
+pub fn ddl(dialect: &dyn Dialect, ddl: &crate::ddl::DDL<'_>) -> anyhow::Result<Vec<Statement>> {
+    ddl::ddl(dialect, ddl)
+}
+
+/// ANSI SQL literal of `value`
+fn default_literal(value: &Variant) -> anyhow::Result<String> {
+    match value {
+        Variant::Null => Ok("NULL".to_owned()),
+        Variant::Bool(v) => Ok(if *v { "TRUE" } else { "FALSE" }.to_owned()),
+        Variant::Int(v) => Ok(v.to_string()),
+        Variant::BigInt(v) => Ok(v.to_string()),
+        Variant::Float(v) => Ok(v.to_string()),
+        Variant::String(v) => Ok(quote_str(v)),
+        Variant::Bytes(v) => Ok(format!("X'{}'", hex(v))),
+        Variant::Uuid(v) => Ok(format!("X'{}'", hex(v))),
+        Variant::Date(v) => Ok(quote_str(&v.format(DATE_FORMAT).to_string())),
+        Variant::Time(v) => Ok(quote_str(&v.format(TIME_FORMAT).to_string())),
+        Variant::Timestamp(v) => Ok(quote_str(&v.format(TIMESTAMP_FORMAT).to_string())),
+        Variant::DateTime(v) => Ok(quote_str(&v.to_rfc3339())),
+        Variant::Interval(v) => Ok(interval_micros(v)?.to_string()),
+        Variant::Json(v) => Ok(quote_str(&v.to_string())),
+        Variant::BigFloat(_) => Err(anyhow::format_err!(
+            "Can't render BigFloat literal {}",
+            value
+        )),
+    }
+}
+
+/// ISO 8601 date format
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// ISO 8601 time format, with optional fraction seconds
+pub const TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+/// ISO 8601 date and time format without utc offset
+pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// Interval as integer microseconds, used by dialects without native interval type.
+pub fn interval_micros(v: &crate::Interval) -> anyhow::Result<i64> {
+    v.num_microseconds()
+        .ok_or_else(|| anyhow::format_err!("Interval {} out of microseconds range", v))
+}
+
+fn quote_str(v: &str) -> String {
+    format!("'{}'", v.replace('\'', "''"))
+}
+
+fn hex(v: &[u8]) -> String {
+    v.iter().map(|b| format!("{:02X}", b)).collect()
+}
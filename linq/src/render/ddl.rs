@@ -0,0 +1,258 @@
+use crate::ddl::{AlterExpr, Column, Constraint, Create, NamedConstraint, DDL};
+
+use super::{Dialect, Statement, Writer};
+
+pub(super) fn ddl(dialect: &dyn Dialect, ddl: &DDL<'_>) -> anyhow::Result<Vec<Statement>> {
+    let mut stmts = vec![];
+
+    match ddl {
+        DDL::Create(create) => create_table(dialect, create, &mut stmts)?,
+        DDL::Alter(alter) => alter_table(dialect, alter.table_name, &alter.expr, &mut stmts)?,
+        DDL::Drop(table_name) => {
+            let mut w = Writer::new(dialect);
+            w.push("DROP TABLE ");
+            w.push_ident(table_name);
+            stmts.push(w.finish());
+        }
+        DDL::Truncate(table_name) => {
+            let mut w = Writer::new(dialect);
+            dialect.truncate(&mut w, table_name)?;
+            stmts.push(w.finish());
+        }
+    }
+
+    Ok(stmts)
+}
+
+fn create_table(
+    dialect: &dyn Dialect,
+    create: &Create<'_>,
+    stmts: &mut Vec<Statement>,
+) -> anyhow::Result<()> {
+    let mut w = Writer::new(dialect);
+
+    w.push("CREATE TABLE ");
+    w.push_ident(create.table_name);
+    w.push(" (");
+
+    for (idx, col) in create.cols.iter().enumerate() {
+        if idx > 0 {
+            w.push(", ");
+        }
+
+        column(&mut w, col)?;
+    }
+
+    let mut indexes = vec![];
+
+    for constraint in &create.constraints {
+        if let Constraint::Index(_) = constraint.constraint {
+            indexes.push(constraint);
+            continue;
+        }
+
+        w.push(", ");
+        table_constraint(&mut w, constraint)?;
+    }
+
+    w.push(")");
+
+    stmts.push(w.finish());
+
+    for index in indexes {
+        stmts.push(create_index(dialect, create.table_name, index)?);
+    }
+
+    Ok(())
+}
+
+fn alter_table(
+    dialect: &dyn Dialect,
+    table_name: &str,
+    expr: &AlterExpr<'_>,
+    stmts: &mut Vec<Statement>,
+) -> anyhow::Result<()> {
+    let mut w = Writer::new(dialect);
+
+    match expr {
+        AlterExpr::AddColumn(col) => {
+            alter_prefix(&mut w, table_name);
+            w.push("ADD COLUMN ");
+            column(&mut w, col)?;
+        }
+        AlterExpr::DropColumn(col_name) => {
+            alter_prefix(&mut w, table_name);
+            w.push("DROP COLUMN ");
+            w.push_ident(col_name);
+        }
+        AlterExpr::AlterColumn(col) => {
+            dialect.alter_column(&mut w, table_name, col)?;
+        }
+        AlterExpr::AddConstraint(constraint) => {
+            if let Constraint::Index(_) = constraint.constraint {
+                stmts.push(create_index(dialect, table_name, constraint)?);
+                return Ok(());
+            }
+
+            check_alter_constraint_support(dialect, table_name)?;
+
+            alter_prefix(&mut w, table_name);
+            w.push("ADD ");
+            table_constraint(&mut w, constraint)?;
+        }
+        AlterExpr::AlterConstraint(constraint) => {
+            // Indexes aren't table constraints, recreate them with `DROP/CREATE INDEX`.
+            if let Constraint::Index(_) = constraint.constraint {
+                dialect.drop_index(&mut w, table_name, constraint.name)?;
+                stmts.push(w.finish());
+                stmts.push(create_index(dialect, table_name, constraint)?);
+                return Ok(());
+            }
+
+            alter_table(
+                dialect,
+                table_name,
+                &AlterExpr::DropConstraint(constraint.name),
+                stmts,
+            )?;
+
+            return alter_table(
+                dialect,
+                table_name,
+                &AlterExpr::AddConstraint(constraint.clone()),
+                stmts,
+            );
+        }
+        AlterExpr::DropConstraint(name) => {
+            check_alter_constraint_support(dialect, table_name)?;
+
+            alter_prefix(&mut w, table_name);
+            w.push("DROP CONSTRAINT ");
+            w.push_ident(name);
+        }
+        AlterExpr::RenameTable(to) => {
+            alter_prefix(&mut w, table_name);
+            w.push("RENAME TO ");
+            w.push_ident(to);
+        }
+        AlterExpr::RenameColumn(from, to) => {
+            alter_prefix(&mut w, table_name);
+            w.push("RENAME COLUMN ");
+            w.push_ident(from);
+            w.push(" TO ");
+            w.push_ident(to);
+        }
+        AlterExpr::RenameConstraint(from, to) => {
+            dialect.rename_constraint(&mut w, table_name, from, to)?;
+        }
+    }
+
+    stmts.push(w.finish());
+
+    Ok(())
+}
+
+fn alter_prefix(w: &mut Writer<'_>, table_name: &str) {
+    w.push("ALTER TABLE ");
+    w.push_ident(table_name);
+    w.push(" ");
+}
+
+fn check_alter_constraint_support(dialect: &dyn Dialect, table_name: &str) -> anyhow::Result<()> {
+    if !dialect.alter_constraint_support() {
+        return Err(anyhow::format_err!(
+            "Dialect not support alter table {} constraints",
+            table_name
+        ));
+    }
+
+    Ok(())
+}
+
+/// Render column define, e.g. `"id" INTEGER NOT NULL PRIMARY KEY`
+pub(super) fn column(w: &mut Writer<'_>, col: &Column<'_>) -> anyhow::Result<()> {
+    w.push_ident(col.name);
+    w.push(" ");
+
+    let dialect = w.dialect();
+
+    match col.primary {
+        Some(true) => {
+            w.push(&dialect.auto_inc_col_type(&col.col_type)?);
+        }
+        Some(false) => {
+            w.push(&dialect.col_type(&col.col_type)?);
+            w.push(" PRIMARY KEY");
+        }
+        None => {
+            w.push(&dialect.col_type(&col.col_type)?);
+        }
+    }
+
+    if col.not_null {
+        w.push(" NOT NULL");
+    }
+
+    if let Some(default_value) = &col.default_value {
+        w.push(" DEFAULT ");
+        w.push_literal(default_value)?;
+    }
+
+    Ok(())
+}
+
+fn table_constraint(w: &mut Writer<'_>, constraint: &NamedConstraint<'_>) -> anyhow::Result<()> {
+    w.push("CONSTRAINT ");
+    w.push_ident(constraint.name);
+
+    match &constraint.constraint {
+        Constraint::Unique(cols) => {
+            w.push(" UNIQUE (");
+            w.push_idents(cols);
+            w.push(")");
+        }
+        Constraint::ForeignKey(cols, ref_table, ref_cols) => {
+            w.push(" FOREIGN KEY (");
+            w.push_idents(cols);
+            w.push(") REFERENCES ");
+            w.push_ident(ref_table);
+            w.push(" (");
+            w.push_idents(ref_cols);
+            w.push(")");
+        }
+        Constraint::Index(_) => {
+            return Err(anyhow::format_err!(
+                "Index {} is not a table constraint",
+                constraint.name
+            ));
+        }
+        Constraint::Check => {
+            return Err(anyhow::format_err!(
+                "Check constraint {} can't be rendered",
+                constraint.name
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn create_index(
+    dialect: &dyn Dialect,
+    table_name: &str,
+    constraint: &NamedConstraint<'_>,
+) -> anyhow::Result<Statement> {
+    let mut w = Writer::new(dialect);
+
+    if let Constraint::Index(cols) = &constraint.constraint {
+        w.push("CREATE INDEX ");
+        w.push_ident(constraint.name);
+        w.push(" ON ");
+        w.push_ident(table_name);
+        w.push(" (");
+        w.push_idents(cols);
+        w.push(")");
+    }
+
+    Ok(w.finish())
+}
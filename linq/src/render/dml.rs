@@ -0,0 +1,438 @@
+use crate::{
+    dml::{
+        AggregateFunc, Columns, CondExpr, CondOp, CondParam, Deleter, GroupBy, Inserter, JoinKind,
+        Limit, OrderBy, SelectColumns, SelectFrom, Selecter, Updater,
+    },
+    Variant,
+};
+
+use super::Writer;
+
+pub(super) fn select(w: &mut Writer<'_>, selecter: &Selecter<'_>) -> anyhow::Result<()> {
+    w.push("SELECT ");
+
+    cols(w, &selecter.cols);
+
+    w.push(" FROM ");
+    from(w, &selecter.from)?;
+
+    if let Some(cond) = &selecter.cond {
+        w.push(" WHERE ");
+        cond_expr(w, cond)?;
+    }
+
+    group_by(w, &selecter.group_by);
+
+    if let Some(having) = &selecter.having {
+        w.push(" HAVING ");
+        cond_expr(w, having)?;
+    }
+
+    order(w, &selecter.order_by);
+
+    if let Some(limit) = &selecter.limit {
+        limits(w, limit);
+    }
+
+    Ok(())
+}
+
+pub(super) fn insert(
+    w: &mut Writer<'_>,
+    inserter: &Inserter<'_>,
+    rows: Vec<Vec<Variant>>,
+) -> anyhow::Result<()> {
+    let col_names = inserter.cols.col_names();
+
+    for values in &rows {
+        check_values(inserter.table_name, col_names, values)?;
+    }
+
+    if rows.is_empty() {
+        return Err(anyhow::format_err!(
+            "Insert into {} without rows",
+            inserter.table_name
+        ));
+    }
+
+    if col_names.is_empty() {
+        if rows.len() > 1 || inserter.on_conflict.is_some() {
+            return Err(anyhow::format_err!(
+                "Multi-row insert or upsert into {} without cols",
+                inserter.table_name
+            ));
+        }
+
+        return w.dialect().insert_default_values(w, inserter.table_name);
+    }
+
+    w.push("INSERT INTO ");
+    w.push_ident(inserter.table_name);
+    w.push(" (");
+    w.push_idents(col_names);
+    w.push(") VALUES ");
+
+    for (row_idx, values) in rows.into_iter().enumerate() {
+        if row_idx > 0 {
+            w.push(", ");
+        }
+
+        w.push("(");
+
+        for (idx, value) in values.into_iter().enumerate() {
+            if idx > 0 {
+                w.push(", ");
+            }
+
+            w.push_param(value);
+        }
+
+        w.push(")");
+    }
+
+    if let Some(on_conflict) = &inserter.on_conflict {
+        w.dialect().on_conflict(w, on_conflict)?;
+    }
+
+    returning(w, inserter.returning.as_ref())
+}
+
+pub(super) fn update(
+    w: &mut Writer<'_>,
+    updater: &Updater<'_>,
+    values: Vec<Variant>,
+) -> anyhow::Result<()> {
+    let col_names = updater.cols.col_names();
+
+    check_values(updater.table_name, col_names, &values)?;
+
+    if col_names.is_empty() {
+        return Err(anyhow::format_err!(
+            "Update table {} without any column",
+            updater.table_name
+        ));
+    }
+
+    w.push("UPDATE ");
+    w.push_ident(updater.table_name);
+    w.push(" SET ");
+
+    for (idx, (col_name, value)) in col_names.iter().zip(values).enumerate() {
+        if idx > 0 {
+            w.push(", ");
+        }
+
+        w.push_ident(col_name);
+        w.push(" = ");
+        w.push_param(value);
+    }
+
+    if let Some(cond) = &updater.cond {
+        w.push(" WHERE ");
+        cond_expr(w, cond)?;
+    }
+
+    returning(w, updater.returning.as_ref())
+}
+
+pub(super) fn delete(w: &mut Writer<'_>, deleter: &Deleter<'_>) -> anyhow::Result<()> {
+    w.push("DELETE FROM ");
+    w.push_ident(deleter.table_name);
+    w.push(" WHERE ");
+
+    cond_expr(w, &deleter.cond)?;
+
+    returning(w, deleter.returning.as_ref())
+}
+
+fn returning(w: &mut Writer<'_>, cols: Option<&Columns<'_>>) -> anyhow::Result<()> {
+    match cols {
+        Some(cols) if cols.col_names().is_empty() => {
+            Err(anyhow::format_err!("RETURNING without cols"))
+        }
+        Some(cols) => w.dialect().returning(w, cols.col_names()),
+        None => Ok(()),
+    }
+}
+
+fn check_values(table_name: &str, col_names: &[&str], values: &[Variant]) -> anyhow::Result<()> {
+    if col_names.len() != values.len() {
+        return Err(anyhow::format_err!(
+            "Table {} expect {} values, got {}",
+            table_name,
+            col_names.len(),
+            values.len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn cols(w: &mut Writer<'_>, cols: &SelectColumns<'_>) {
+    match cols {
+        SelectColumns::All => w.push("*"),
+        SelectColumns::NamedColumns(cols) => {
+            for (idx, col) in cols.iter().enumerate() {
+                if idx > 0 {
+                    w.push(", ");
+                }
+
+                match &col.func {
+                    Some(func) => aggregate(w, func, col.table, col.name),
+                    None => w.push_col(col.table, col.name),
+                }
+
+                if let Some(aliase) = col.aliase {
+                    w.push(" AS ");
+                    w.push_ident(aliase);
+                }
+            }
+        }
+    }
+}
+
+fn table(w: &mut Writer<'_>, table_name: &str, aliase: Option<&str>) {
+    w.push_ident(table_name);
+
+    if let Some(aliase) = aliase {
+        w.push(" AS ");
+        w.push_ident(aliase);
+    }
+}
+
+fn from(w: &mut Writer<'_>, from: &SelectFrom<'_>) -> anyhow::Result<()> {
+    match &from.subquery {
+        Some(subquery) => {
+            w.push("(");
+            select(w, subquery)?;
+            w.push(") AS ");
+            w.push_ident(from.aliase.unwrap_or(from.table_name));
+        }
+        None => table(w, from.table_name, from.aliase),
+    }
+
+    for join in &from.joins {
+        w.push(match join.kind {
+            JoinKind::Inner => " INNER JOIN ",
+            JoinKind::Left => " LEFT JOIN ",
+            JoinKind::Right => " RIGHT JOIN ",
+            JoinKind::Cross => " CROSS JOIN ",
+        });
+
+        table(w, join.table_name, join.aliase);
+
+        match (&join.kind, &join.on) {
+            (JoinKind::Cross, None) => {}
+            (JoinKind::Cross, Some(_)) => {
+                return Err(anyhow::format_err!(
+                    "CROSS JOIN {} can't have ON clause",
+                    join.table_name
+                ))
+            }
+            (_, Some(on)) => {
+                w.push(" ON ");
+                cond_expr(w, on)?;
+            }
+            (_, None) => {
+                return Err(anyhow::format_err!(
+                    "JOIN {} miss ON clause",
+                    join.table_name
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn aggregate(w: &mut Writer<'_>, func: &AggregateFunc, table: Option<&str>, name: &str) {
+    w.push(match func {
+        AggregateFunc::Count => "COUNT(",
+        AggregateFunc::Sum => "SUM(",
+        AggregateFunc::Avg => "AVG(",
+        AggregateFunc::Min => "MIN(",
+        AggregateFunc::Max => "MAX(",
+    });
+
+    if name == "*" {
+        w.push("*");
+    } else {
+        w.push_col(table, name);
+    }
+
+    w.push(")");
+}
+
+fn group_by(w: &mut Writer<'_>, group_by: &[GroupBy<'_>]) {
+    for (idx, col) in group_by.iter().enumerate() {
+        w.push(if idx == 0 { " GROUP BY " } else { ", " });
+        w.push_col(col.table, col.col_name);
+    }
+}
+
+fn order(w: &mut Writer<'_>, order_by: &[OrderBy<'_>]) {
+    for (idx, order_by) in order_by.iter().enumerate() {
+        w.push(if idx == 0 { " ORDER BY " } else { ", " });
+
+        w.dialect().order_by(w, order_by);
+    }
+}
+
+fn limits(w: &mut Writer<'_>, limit: &Limit) {
+    w.push(&format!(" LIMIT {}", limit.count));
+
+    if let Some(offset) = limit.offset {
+        w.push(&format!(" OFFSET {}", offset));
+    }
+}
+
+fn is_logical(op: &CondOp) -> bool {
+    matches!(op, CondOp::And | CondOp::Or)
+}
+
+/// Render where clause, values are always bound as parameters.
+pub(super) fn cond_expr(w: &mut Writer<'_>, expr: &CondExpr) -> anyhow::Result<()> {
+    let op = match expr.op {
+        CondOp::NotEq => " <> ",
+        CondOp::Eq => " = ",
+        CondOp::Gt => " > ",
+        CondOp::Lt => " < ",
+        CondOp::Gte => " >= ",
+        CondOp::Lte => " <= ",
+        CondOp::Like => " LIKE ",
+        CondOp::NotLike => " NOT LIKE ",
+        CondOp::In => " IN ",
+        CondOp::NotIn => " NOT IN ",
+        CondOp::Between => " BETWEEN ",
+        CondOp::NotBetween => " NOT BETWEEN ",
+        CondOp::IsNull => " IS NULL",
+        CondOp::IsNotNull => " IS NOT NULL",
+        CondOp::Not => "NOT ",
+        CondOp::Exists => "EXISTS ",
+        CondOp::And => " AND ",
+        CondOp::Or => " OR ",
+    };
+
+    if is_logical(&expr.op) {
+        logical_param(w, &expr.lhs)?;
+        w.push(op);
+        logical_param(w, &expr.rhs)?;
+
+        return Ok(());
+    }
+
+    match (&expr.op, &expr.rhs) {
+        (CondOp::Exists, _) => {
+            if !matches!(expr.lhs, CondParam::Subquery(_)) {
+                return Err(anyhow::format_err!(
+                    "EXISTS operand must be subquery, got {:?}",
+                    expr.lhs
+                ));
+            }
+
+            w.push(op);
+
+            return cond_param(w, &expr.lhs);
+        }
+        (CondOp::Not, _) => {
+            let lhs = match &expr.lhs {
+                CondParam::CondExpr(lhs) => lhs,
+                param => {
+                    return Err(anyhow::format_err!(
+                        "NOT operand must be cond expr, got {:?}",
+                        param
+                    ));
+                }
+            };
+
+            w.push(op);
+            w.push("(");
+            cond_expr(w, lhs)?;
+            w.push(")");
+
+            return Ok(());
+        }
+        (CondOp::In, CondParam::VariantList(values)) if values.is_empty() => {
+            // `IN ()` is a syntax error, and `IN (NULL)` is unknown rather than false,
+            // so `NOT` wouldn't flip it. Render an always false predicate instead.
+            w.push("1 = 0");
+            return Ok(());
+        }
+        (CondOp::NotIn, CondParam::VariantList(values)) if values.is_empty() => {
+            // Nothing is in an empty list, render an always true predicate.
+            w.push("1 = 1");
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    cond_param(w, &expr.lhs)?;
+
+    w.push(op);
+
+    if let CondOp::IsNull | CondOp::IsNotNull = expr.op {
+        return Ok(());
+    }
+
+    cond_param(w, &expr.rhs)
+}
+
+fn logical_param(w: &mut Writer<'_>, param: &CondParam) -> anyhow::Result<()> {
+    match param {
+        CondParam::CondExpr(expr) if is_logical(&expr.op) => {
+            w.push("(");
+            cond_expr(w, expr)?;
+            w.push(")");
+
+            Ok(())
+        }
+        CondParam::CondExpr(expr) => cond_expr(w, expr),
+        _ => Err(anyhow::format_err!(
+            "AND/OR operands must be cond exprs, got {:?}",
+            param
+        )),
+    }
+}
+
+fn cond_param(w: &mut Writer<'_>, param: &CondParam) -> anyhow::Result<()> {
+    match param {
+        CondParam::Param(value) => w.push_param(value.clone()),
+        CondParam::VariantList(values) => {
+            w.push("(");
+
+            for (idx, value) in values.iter().enumerate() {
+                if idx > 0 {
+                    w.push(", ");
+                }
+
+                w.push_param(value.clone());
+            }
+
+            w.push(")");
+        }
+        CondParam::CondExpr(expr) => {
+            w.push("(");
+            cond_expr(w, expr)?;
+            w.push(")");
+        }
+        CondParam::Column(col) => w.push_col(col.table.as_deref(), &col.name),
+        CondParam::Aggregate(func, col) => aggregate(w, func, col.table.as_deref(), &col.name),
+        CondParam::Range(low, high) => {
+            cond_param(w, low)?;
+            w.push(" AND ");
+            cond_param(w, high)?;
+        }
+        CondParam::Subquery(selecter) => {
+            w.push("(");
+            select(w, selecter)?;
+            w.push(")");
+        }
+        CondParam::Empty => {
+            return Err(anyhow::format_err!(
+                "Empty cond param is only valid for unary ops"
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use crate::{
+    ddl::Column,
+    dml::{ConflictAction, Nulls, OnConflict, OrderBy},
+    IrType,
+};
+
+use super::{Dialect, Writer};
+
+/// [`MySQL`](https://dev.mysql.com/doc/refman/8.0/en/sql-statements.html) dialect,
+/// backtick quoted identifiers and `?` placeholders.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MySql;
+
+/// Upsert alias of the row being inserted.
+const INSERTED_ROW: &str = "new";
+
+impl Dialect for MySql {
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("`{}`", ident.replace('`', "``"))
+    }
+
+    fn col_type(&self, col_type: &IrType) -> anyhow::Result<String> {
+        let name = match col_type {
+            IrType::Bool => "BOOLEAN",
+            IrType::Int => "INT",
+            IrType::BigInt => "BIGINT",
+            IrType::Float => "DOUBLE",
+            IrType::Decimal => "DECIMAL(65, 30)",
+            // TEXT columns can't be indexed without prefix length.
+            IrType::String => "VARCHAR(255)",
+            IrType::Bytes => "LONGBLOB",
+            IrType::Date => "DATE",
+            IrType::Time => "TIME(6)",
+            IrType::Timestamp => "DATETIME(6)",
+            // No utc offset support, stored as utc time.
+            IrType::DateTime => "DATETIME(6)",
+            // No interval type, stored as integer microseconds.
+            IrType::Interval => "BIGINT",
+            IrType::Json => "JSON",
+            IrType::Uuid => "BINARY(16)",
+        };
+
+        Ok(name.to_owned())
+    }
+
+    fn auto_inc_col_type(&self, col_type: &IrType) -> anyhow::Result<String> {
+        match col_type {
+            IrType::Int | IrType::BigInt => Ok(format!(
+                "{} AUTO_INCREMENT PRIMARY KEY",
+                self.col_type(col_type)?
+            )),
+            _ => Err(anyhow::format_err!(
+                "Auto increment column must be integer, got {:?}",
+                col_type
+            )),
+        }
+    }
+
+    fn insert_default_values(&self, w: &mut Writer<'_>, table_name: &str) -> anyhow::Result<()> {
+        w.push("INSERT INTO ");
+        w.push_ident(table_name);
+        w.push(" () VALUES ()");
+
+        Ok(())
+    }
+
+    fn alter_column(
+        &self,
+        w: &mut Writer<'_>,
+        table_name: &str,
+        col: &Column<'_>,
+    ) -> anyhow::Result<()> {
+        w.push("ALTER TABLE ");
+        w.push_ident(table_name);
+        w.push(" MODIFY COLUMN ");
+
+        super::ddl::column(w, col)
+    }
+
+    fn rename_constraint(
+        &self,
+        w: &mut Writer<'_>,
+        table_name: &str,
+        from: &str,
+        to: &str,
+    ) -> anyhow::Result<()> {
+        // Named UNIQUE/INDEX constraints are indexes in MySQL.
+        w.push("ALTER TABLE ");
+        w.push_ident(table_name);
+        w.push(" RENAME INDEX ");
+        w.push_ident(from);
+        w.push(" TO ");
+        w.push_ident(to);
+
+        Ok(())
+    }
+
+    fn drop_index(
+        &self,
+        w: &mut Writer<'_>,
+        table_name: &str,
+        index_name: &str,
+    ) -> anyhow::Result<()> {
+        w.push("DROP INDEX ");
+        w.push_ident(index_name);
+        w.push(" ON ");
+        w.push_ident(table_name);
+
+        Ok(())
+    }
+
+    fn on_conflict(&self, w: &mut Writer<'_>, on_conflict: &OnConflict<'_>) -> anyhow::Result<()> {
+        // Row alias of the inserted values, `VALUES(col)` is deprecated since MySQL 8.0.20.
+        if let ConflictAction::DoUpdate(_) = &on_conflict.action {
+            w.push(" AS ");
+            w.push_ident(INSERTED_ROW);
+        }
+
+        w.push(" ON DUPLICATE KEY UPDATE ");
+
+        let cols = match &on_conflict.action {
+            // No `DO NOTHING`, assign a conflict col to itself instead.
+            ConflictAction::DoNothing => on_conflict.target.col_names(),
+            ConflictAction::DoUpdate(cols) => cols.col_names(),
+        };
+
+        if cols.is_empty() {
+            return Err(anyhow::format_err!("Upsert without cols"));
+        }
+
+        for (idx, col) in cols.iter().enumerate() {
+            if idx > 0 {
+                w.push(", ");
+            }
+
+            w.push_ident(col);
+
+            match &on_conflict.action {
+                ConflictAction::DoNothing => {
+                    w.push(" = ");
+                    w.push_ident(col);
+                }
+                ConflictAction::DoUpdate(_) => {
+                    w.push(" = ");
+                    w.push_ident(INSERTED_ROW);
+                    w.push(".");
+                    w.push_ident(col);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn returning(&self, _w: &mut Writer<'_>, _cols: &[&str]) -> anyhow::Result<()> {
+        Err(anyhow::format_err!("MySQL doesn't support RETURNING"))
+    }
+
+    fn order_by(&self, w: &mut Writer<'_>, order_by: &OrderBy<'_>) {
+        // MySQL has no `NULLS FIRST/LAST`, sort by `IS NULL` first instead.
+        if let Some(nulls) = &order_by.nulls {
+            w.push_col(order_by.table, order_by.col_name);
+            w.push(match nulls {
+                Nulls::First => " IS NULL DESC, ",
+                Nulls::Last => " IS NULL ASC, ",
+            });
+        }
+
+        w.push_col(order_by.table, order_by.col_name);
+
+        w.push(if order_by.desc { " DESC" } else { " ASC" });
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{ddl::Column, IrType, Variant};
+
+use super::{Dialect, Writer};
+
+/// [`PostgreSQL`](https://www.postgresql.org/docs/current/sql-commands.html) dialect,
+/// `$1`, `$2` ... placeholders.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Postgres;
+
+impl Dialect for Postgres {
+    fn placeholder(&self, index: usize) -> String {
+        format!("${}", index)
+    }
+
+    fn col_type(&self, col_type: &IrType) -> anyhow::Result<String> {
+        let name = match col_type {
+            IrType::Bool => "BOOLEAN",
+            IrType::Int => "INTEGER",
+            IrType::BigInt => "BIGINT",
+            IrType::Float => "DOUBLE PRECISION",
+            IrType::Decimal => "NUMERIC",
+            IrType::String => "TEXT",
+            IrType::Bytes => "BYTEA",
+            IrType::Date => "DATE",
+            IrType::Time => "TIME",
+            IrType::Timestamp => "TIMESTAMP",
+            IrType::DateTime => "TIMESTAMPTZ",
+            IrType::Interval => "INTERVAL",
+            IrType::Json => "JSONB",
+            IrType::Uuid => "UUID",
+        };
+
+        Ok(name.to_owned())
+    }
+
+    fn auto_inc_col_type(&self, col_type: &IrType) -> anyhow::Result<String> {
+        match col_type {
+            IrType::Int | IrType::BigInt => Ok(format!(
+                "{} GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY",
+                self.col_type(col_type)?
+            )),
+            _ => Err(anyhow::format_err!(
+                "Auto increment column must be integer, got {:?}",
+                col_type
+            )),
+        }
+    }
+
+    fn literal(&self, value: &Variant) -> anyhow::Result<String> {
+        match value {
+            Variant::Bytes(v) => Ok(format!("'\\x{}'::bytea", super::hex(v))),
+            Variant::Uuid(_) => Ok(format!("'{}'::uuid", value)),
+            Variant::Interval(v) => Ok(format!(
+                "'{} microseconds'::interval",
+                super::interval_micros(v)?
+            )),
+            _ => super::default_literal(value),
+        }
+    }
+
+    fn alter_column(
+        &self,
+        w: &mut Writer<'_>,
+        table_name: &str,
+        col: &Column<'_>,
+    ) -> anyhow::Result<()> {
+        w.push("ALTER TABLE ");
+        w.push_ident(table_name);
+        w.push(" ALTER COLUMN ");
+        w.push_ident(col.name);
+        w.push(" TYPE ");
+        w.push(&self.col_type(&col.col_type)?);
+
+        w.push(", ALTER COLUMN ");
+        w.push_ident(col.name);
+
+        if col.not_null {
+            w.push(" SET NOT NULL");
+        } else {
+            w.push(" DROP NOT NULL");
+        }
+
+        w.push(", ALTER COLUMN ");
+        w.push_ident(col.name);
+
+        if let Some(default_value) = &col.default_value {
+            w.push(" SET DEFAULT ");
+            w.push_literal(default_value)?;
+        } else {
+            w.push(" DROP DEFAULT");
+        }
+
+        Ok(())
+    }
+}
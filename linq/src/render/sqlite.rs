@@ -0,0 +1,70 @@
+use crate::IrType;
+
+use super::{Dialect, Writer};
+
+/// [`SQLite`](https://www.sqlite.org/lang.html) dialect, `?` placeholders.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sqlite;
+
+impl Dialect for Sqlite {
+    fn col_type(&self, col_type: &IrType) -> anyhow::Result<String> {
+        let name = match col_type {
+            // Stored as 0/1 integer, the declared type tells drivers how to decode it.
+            IrType::Bool => "BOOLEAN",
+            IrType::Int => "INTEGER",
+            IrType::BigInt => "BIGINT",
+            IrType::Float => "REAL",
+            IrType::Decimal => "NUMERIC",
+            IrType::String => "TEXT",
+            IrType::Bytes => "BLOB",
+            // Stored as ISO 8601 text, the declared type tells drivers how to decode it.
+            IrType::Date => "DATE",
+            IrType::Time => "TIME",
+            IrType::Timestamp => "TIMESTAMP",
+            IrType::DateTime => "DATETIME",
+            // Stored as integer microseconds.
+            IrType::Interval => "INTERVAL",
+            IrType::Json => "JSON",
+            // Stored as 16 bytes blob.
+            IrType::Uuid => "UUID",
+        };
+
+        Ok(name.to_owned())
+    }
+
+    fn auto_inc_col_type(&self, col_type: &IrType) -> anyhow::Result<String> {
+        match col_type {
+            // Only `INTEGER PRIMARY KEY` is an alias of ROWID
+            IrType::Int | IrType::BigInt => Ok("INTEGER PRIMARY KEY AUTOINCREMENT".to_owned()),
+            _ => Err(anyhow::format_err!(
+                "Auto increment column must be integer, got {:?}",
+                col_type
+            )),
+        }
+    }
+
+    fn truncate(&self, w: &mut Writer<'_>, table_name: &str) -> anyhow::Result<()> {
+        w.push("DELETE FROM ");
+        w.push_ident(table_name);
+
+        Ok(())
+    }
+
+    fn rename_constraint(
+        &self,
+        _w: &mut Writer<'_>,
+        table_name: &str,
+        from: &str,
+        _to: &str,
+    ) -> anyhow::Result<()> {
+        Err(anyhow::format_err!(
+            "SQLite not support rename constraint {}.{}",
+            table_name,
+            from
+        ))
+    }
+
+    fn alter_constraint_support(&self) -> bool {
+        false
+    }
+}
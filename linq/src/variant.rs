@@ -1,30 +1,55 @@
 use std::fmt::Display;
 
-use num::{BigInt, BigRational};
+pub use num::BigInt;
 
-/// LINQ ir datetime type import from chrono.
-pub type DateTime = chrono::DateTime<chrono::Utc>;
+/// LINQ ir date type import from chrono, calendar date without time zone.
+pub type Date = chrono::NaiveDate;
 
-/// LINQ ir timestamp type import from chrono.
-pub type Timestamp = chrono::NaiveTime;
+/// LINQ ir time type import from chrono, time of day without time zone.
+pub type Time = chrono::NaiveTime;
+
+/// LINQ ir timestamp type import from chrono, date and time without time zone.
+pub type Timestamp = chrono::NaiveDateTime;
+
+/// LINQ ir datetime type import from chrono, date and time with utc offset.
+pub type DateTime = chrono::DateTime<chrono::FixedOffset>;
+
+/// LINQ ir interval type import from chrono, exact elapsed time.
+pub type Interval = chrono::Duration;
+
+/// LINQ ir decimal type import from num, stored as exact rational.
+pub type Decimal = num::BigRational;
+
+/// LINQ ir json type import from serde_json.
+pub type Json = serde_json::Value;
 
 /// LINQ ir basic type value enum
 #[derive(Debug, Clone, PartialEq)]
 pub enum Variant {
+    Bool(bool),
     Int(i64),
     BigInt(BigInt),
     Float(f64),
-    BigFloat(BigRational),
+    BigFloat(Decimal),
     String(String),
     Bytes(Vec<u8>),
-    DateTime(DateTime),
+    Date(Date),
+    Time(Time),
     Timestamp(Timestamp),
+    DateTime(DateTime),
+    Interval(Interval),
+    Json(Json),
+    /// UUID in big endian bytes
+    Uuid([u8; 16]),
     Null,
 }
 
 impl Display for Variant {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Bool(v) => {
+                write!(f, "{}", v)
+            }
             Self::Int(v) => {
                 write!(f, "{}", v)
             }
@@ -43,12 +68,35 @@ impl Display for Variant {
             Self::Bytes(v) => {
                 write!(f, "{:X?}", v)
             }
-            Self::DateTime(v) => {
+            Self::Date(v) => {
+                write!(f, "{}", v)
+            }
+            Self::Time(v) => {
                 write!(f, "{}", v)
             }
             Self::Timestamp(v) => {
                 write!(f, "{}", v)
             }
+            Self::DateTime(v) => {
+                write!(f, "{}", v)
+            }
+            Self::Interval(v) => {
+                write!(f, "{}", v)
+            }
+            Self::Json(v) => {
+                write!(f, "{}", v)
+            }
+            Self::Uuid(v) => {
+                for (idx, b) in v.iter().enumerate() {
+                    if matches!(idx, 4 | 6 | 8 | 10) {
+                        write!(f, "-")?;
+                    }
+
+                    write!(f, "{:02x}", b)?;
+                }
+
+                Ok(())
+            }
             Self::Null => {
                 write!(f, "NULL",)
             }
@@ -57,22 +105,63 @@ impl Display for Variant {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
-/// Integer like convert
+// Bool convert
+
+impl From<bool> for Variant {
+    fn from(v: bool) -> Self {
+        Variant::Bool(v)
+    }
+}
+
+impl TryFrom<Variant> for bool {
+    type Error = anyhow::Error;
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        match value {
+            Variant::Bool(v) => Ok(v),
+            // Drivers without native bool type store flags as 0/1 integers.
+            Variant::Int(0) => Ok(false),
+            Variant::Int(1) => Ok(true),
+            _ => Err(anyhow::format_err!("Variant type mismatch")),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// Integer like convert
 
-/// Implement int convert macro
+/// Implement int convert macro, `widen` types fall back to [`Variant::BigInt`]
+/// when the value is out of `i64` range.
 macro_rules! impl_int_convert {
     ($num: ty) => {
         impl From<$num> for Variant {
             fn from(v: $num) -> Self {
-                Variant::Int(v as i64)
+                Variant::Int(v.into())
             }
         }
 
+        impl_int_convert!(@try_from $num);
+    };
+    ($num: ty, widen) => {
+        impl From<$num> for Variant {
+            fn from(v: $num) -> Self {
+                match i64::try_from(v) {
+                    Ok(v) => Variant::Int(v),
+                    Err(_) => Variant::BigInt(v.into()),
+                }
+            }
+        }
+
+        impl_int_convert!(@try_from $num);
+    };
+    (@try_from $num: ty) => {
         impl TryFrom<Variant> for $num {
             type Error = anyhow::Error;
             fn try_from(value: Variant) -> Result<Self, Self::Error> {
                 match value {
-                    Variant::Int(v) => Ok(v as $num),
+                    Variant::Int(v) => <$num>::try_from(v).map_err(|_| overflow(&v, stringify!($num))),
+                    Variant::BigInt(v) => {
+                        <$num>::try_from(&v).map_err(|_| overflow(&v, stringify!($num)))
+                    }
                     _ => Err(anyhow::format_err!("Variant type mismatch")),
                 }
             }
@@ -80,25 +169,50 @@ macro_rules! impl_int_convert {
     };
 }
 
+fn overflow(value: &dyn Display, ty: &str) -> anyhow::Error {
+    anyhow::format_err!("Variant {} out of {} range", value, ty)
+}
+
 impl_int_convert!(i8);
 impl_int_convert!(i16);
 impl_int_convert!(i32);
 impl_int_convert!(i64);
-impl_int_convert!(usize);
+impl_int_convert!(i128, widen);
+impl_int_convert!(isize, widen);
 impl_int_convert!(u8);
 impl_int_convert!(u16);
 impl_int_convert!(u32);
-impl_int_convert!(u64);
+impl_int_convert!(u64, widen);
+impl_int_convert!(u128, widen);
+impl_int_convert!(usize, widen);
+
+impl From<BigInt> for Variant {
+    fn from(v: BigInt) -> Self {
+        Variant::BigInt(v)
+    }
+}
+
+impl TryFrom<Variant> for BigInt {
+    type Error = anyhow::Error;
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        match value {
+            Variant::Int(v) => Ok(v.into()),
+            Variant::BigInt(v) => Ok(v),
+            _ => Err(anyhow::format_err!("Variant type mismatch")),
+        }
+    }
+}
 
 ////////////////////////////////////////////////////////////////////////////////////
-/// Float like convert
+// Float like convert
 
-/// Implement float convert macro
+/// Implement float convert macro, `Int` widens only within the `$mantissa` bits
+/// the float can represent exactly, and `Float` narrows only without rounding.
 macro_rules! impl_float_convert {
-    ($num: ty) => {
+    ($num: ty, $mantissa: expr) => {
         impl From<$num> for Variant {
             fn from(v: $num) -> Self {
-                Variant::Float(v as f64)
+                Variant::Float(v.into())
             }
         }
 
@@ -106,7 +220,23 @@ macro_rules! impl_float_convert {
             type Error = anyhow::Error;
             fn try_from(value: Variant) -> Result<Self, Self::Error> {
                 match value {
-                    Variant::Float(v) => Ok(v as $num),
+                    Variant::Float(v) => {
+                        let narrowed = v as $num;
+
+                        if v.is_finite() && narrowed.is_infinite() {
+                            Err(overflow(&v, stringify!($num)))
+                        } else if narrowed as f64 != v && !v.is_nan() {
+                            Err(anyhow::format_err!(
+                                "Variant {} is not exact as {}",
+                                v,
+                                stringify!($num)
+                            ))
+                        } else {
+                            Ok(narrowed)
+                        }
+                    }
+                    Variant::Int(v) if v.unsigned_abs() <= 1 << $mantissa => Ok(v as $num),
+                    Variant::Int(v) => Err(overflow(&v, stringify!($num))),
                     _ => Err(anyhow::format_err!("Variant type mismatch")),
                 }
             }
@@ -114,23 +244,47 @@ macro_rules! impl_float_convert {
     };
 }
 
-impl_float_convert!(f32);
-impl_float_convert!(f64);
+impl_float_convert!(f32, 24);
+impl_float_convert!(f64, 53);
 
-impl From<BigInt> for Variant {
-    fn from(v: BigInt) -> Self {
-        Variant::BigInt(v)
+impl From<Decimal> for Variant {
+    fn from(v: Decimal) -> Self {
+        Variant::BigFloat(v)
     }
 }
 
-impl From<BigRational> for Variant {
-    fn from(v: BigRational) -> Self {
-        Variant::BigFloat(v)
+impl TryFrom<Variant> for Decimal {
+    type Error = anyhow::Error;
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        match value {
+            Variant::Int(v) => Ok(Decimal::from_integer(v.into())),
+            Variant::BigInt(v) => Ok(Decimal::from_integer(v)),
+            Variant::Float(v) => decimal_from_float(v)
+                .ok_or_else(|| anyhow::format_err!("Variant {} is not a finite decimal", v)),
+            Variant::BigFloat(v) => Ok(v),
+            _ => Err(anyhow::format_err!("Variant type mismatch")),
+        }
+    }
+}
+
+/// Convert float through its shortest round trip decimal string, so `0.1` is `1/10`
+/// rather than the exact binary fraction.
+fn decimal_from_float(v: f64) -> Option<Decimal> {
+    if !v.is_finite() {
+        return None;
     }
+
+    // `Display` of floats never uses exponent notation.
+    let text = v.to_string();
+    let (int, frac) = text.split_once('.').unwrap_or((&text, ""));
+
+    let numer: BigInt = format!("{}{}", int, frac).parse().ok()?;
+
+    Some(Decimal::new(numer, num::pow(BigInt::from(10), frac.len())))
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
-/// StringLike convert
+// StringLike convert
 
 impl<'a> From<&'a str> for Variant {
     fn from(v: &'a str) -> Self {
@@ -155,7 +309,7 @@ impl TryFrom<Variant> for String {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
-/// Bytes like convert
+// Bytes like convert
 
 impl<'a> From<&'a [u8]> for Variant {
     fn from(v: &'a [u8]) -> Self {
@@ -180,52 +334,139 @@ impl TryFrom<Variant> for Vec<u8> {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////
-/// Date like convert
+// Date like convert
+
+/// Implement date like convert macro
+macro_rules! impl_date_convert {
+    ($ty: ty, $variant: ident) => {
+        impl From<$ty> for Variant {
+            fn from(v: $ty) -> Self {
+                Variant::$variant(v)
+            }
+        }
+
+        impl TryFrom<Variant> for $ty {
+            type Error = anyhow::Error;
+            fn try_from(value: Variant) -> Result<Self, Self::Error> {
+                match value {
+                    Variant::$variant(v) => Ok(v),
+                    _ => Err(anyhow::format_err!("Variant type mismatch")),
+                }
+            }
+        }
+    };
+}
+
+impl_date_convert!(Date, Date);
+impl_date_convert!(Time, Time);
+impl_date_convert!(Timestamp, Timestamp);
+impl_date_convert!(DateTime, DateTime);
+impl_date_convert!(Interval, Interval);
+
+impl From<chrono::DateTime<chrono::Utc>> for Variant {
+    fn from(v: chrono::DateTime<chrono::Utc>) -> Self {
+        Variant::DateTime(v.fixed_offset())
+    }
+}
+
+impl TryFrom<Variant> for chrono::DateTime<chrono::Utc> {
+    type Error = anyhow::Error;
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        match value {
+            Variant::DateTime(v) => Ok(v.to_utc()),
+            _ => Err(anyhow::format_err!("Variant type mismatch")),
+        }
+    }
+}
+
+impl From<chrono::DateTime<chrono::Local>> for Variant {
+    fn from(v: chrono::DateTime<chrono::Local>) -> Self {
+        Variant::DateTime(v.fixed_offset())
+    }
+}
+
+impl TryFrom<Variant> for chrono::DateTime<chrono::Local> {
+    type Error = anyhow::Error;
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        match value {
+            Variant::DateTime(v) => Ok(v.with_timezone(&chrono::Local)),
+            _ => Err(anyhow::format_err!("Variant type mismatch")),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+// Json convert
 
-impl From<DateTime> for Variant {
-    fn from(v: DateTime) -> Self {
-        Variant::DateTime(v)
+impl From<Json> for Variant {
+    fn from(v: Json) -> Self {
+        Variant::Json(v)
     }
 }
 
-impl TryFrom<Variant> for DateTime {
+impl TryFrom<Variant> for Json {
     type Error = anyhow::Error;
     fn try_from(value: Variant) -> Result<Self, Self::Error> {
         match value {
-            Variant::DateTime(datetime) => Ok(datetime),
+            Variant::Json(v) => Ok(v),
             _ => Err(anyhow::format_err!("Variant type mismatch")),
         }
     }
 }
 
-impl From<Timestamp> for Variant {
-    fn from(v: Timestamp) -> Self {
-        Variant::Timestamp(v)
+////////////////////////////////////////////////////////////////////////////////////
+// Uuid convert
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Variant {
+    fn from(v: uuid::Uuid) -> Self {
+        Variant::Uuid(v.into_bytes())
     }
 }
 
-impl TryFrom<Variant> for Timestamp {
+#[cfg(feature = "uuid")]
+impl TryFrom<Variant> for uuid::Uuid {
     type Error = anyhow::Error;
     fn try_from(value: Variant) -> Result<Self, Self::Error> {
         match value {
-            Variant::Timestamp(datetime) => Ok(datetime),
+            Variant::Uuid(v) => Ok(uuid::Uuid::from_bytes(v)),
             _ => Err(anyhow::format_err!("Variant type mismatch")),
         }
     }
 }
 
-/// end convert impl
+// end convert impl
 ////////////////////////////////////////////////////////////////////////////////////
 
 /// LINQ ir basic type enum
+///
+/// Drivers must store date like types without losing precision or meaning:
+///
+/// * [`Date`](IrType::Date), [`Time`](IrType::Time) and [`Timestamp`](IrType::Timestamp)
+///   are stored as is, without any time zone conversion.
+/// * [`DateTime`](IrType::DateTime) must keep the instant, drivers that can't store
+///   the utc offset store the utc time and read it back with zero offset.
+/// * [`Interval`](IrType::Interval) is an exact duration with microsecond precision,
+///   drivers without native interval type store it as integer microseconds.
 #[derive(Debug, Clone, PartialEq)]
 pub enum IrType {
+    Bool,
     Int,
     BigInt,
     Float,
     Decimal,
     String,
     Bytes,
-    DateTime,
+    /// Calendar date, e.g. `2022-12-01`
+    Date,
+    /// Time of day, e.g. `08:00:00.5`
+    Time,
+    /// Date and time without time zone
     Timestamp,
+    /// Date and time with utc offset
+    DateTime,
+    /// Elapsed time
+    Interval,
+    Json,
+    Uuid,
 }
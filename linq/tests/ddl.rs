@@ -1,5 +1,5 @@
 use linq_rs::{
-    ddl::{Column, Constraint, Create, NamedConstraint, DDL},
+    ddl::{Alter, AlterExpr, Column, Constraint, Create, NamedConstraint, DDL},
     *,
 };
 
@@ -121,3 +121,35 @@ fn test_create_table() {
         })
     );
 }
+
+#[test]
+fn test_rename_column() {
+    let qirs = ddl! {
+        ALTER TABLE Card RENAME COLUMN card_no TO no;
+    };
+
+    assert_eq!(
+        qirs[0],
+        DDL::Alter(Alter {
+            table_name: "Card",
+            expr: AlterExpr::RenameColumn("card_no", "no"),
+        })
+    );
+}
+
+#[test]
+fn test_column_default_value() {
+    let qirs = ddl! {
+        CREATE TABLE User(
+            age INT NOT NULL 18,
+            name STRING "guest",
+        );
+    };
+
+    let DDL::Create(create) = &qirs[0] else {
+        panic!("Expect CREATE TABLE");
+    };
+
+    assert_eq!(create.cols[0].default_value, Some(18.into()));
+    assert_eq!(create.cols[1].default_value, Some("guest".into()));
+}
@@ -0,0 +1,1712 @@
+use linq_rs::{
+    driver::{
+        memory::MemoryDriver, DDLSupport, DeleteSupport, InsertSupport, QueryIterator,
+        SelectSupport, Transaction, TransactionSupport, UpdateSupport,
+    },
+    orm::{Limit, Order, Where},
+    *,
+};
+
+#[path = "utils/checks.rs"]
+mod checks;
+
+use checks::*;
+
+/// `user_table` view with a lazy cascade and an optional one-to-one cascade.
+#[derive(ORM, Default, Debug, PartialEq)]
+#[table_name("user_table")]
+struct UserCards {
+    #[column("id_")]
+    #[primary(autoinc)]
+    id: Option<i32>,
+    first_name: String,
+    last_name: String,
+    #[cascade(from=col_id to=col_user_id lazy)]
+    cards: Vec<Card>,
+    #[cascade(from=col_id to=col_user_id)]
+    first_card: Option<Card>,
+}
+
+/// `Card` view with a required owner, whose lazy cards are nested relations.
+#[derive(ORM, Default, Debug, PartialEq)]
+#[table_name("Card")]
+struct CardOwner {
+    #[primary]
+    id: usize,
+    user_id: i32,
+    card_no: String,
+    #[cascade(from=col_user_id to=col_id)]
+    owner: UserCards,
+}
+
+/// `user_table` view with groups linked through `UserGroup` rows.
+#[derive(ORM, Default, Debug, PartialEq)]
+#[table_name("user_table")]
+struct UserGroups {
+    #[column("id_")]
+    #[primary(autoinc)]
+    id: Option<i32>,
+    first_name: String,
+    last_name: String,
+    #[many_to_many(through = UserGroup, from = col_user_id, to = col_group_id)]
+    groups: Option<Vec<Group>>,
+}
+
+#[derive(ORM, Default, Debug, PartialEq)]
+#[table_name("group_table")]
+struct Group {
+    #[primary]
+    id: i32,
+    name: String,
+}
+
+/// Link table keyed by `(user_id, group_id)` alone.
+#[derive(ORM, Default, Debug, PartialEq)]
+struct UserGroup {
+    user_id: i32,
+    group_id: i32,
+}
+
+/// Json can't encode map with non string keys.
+#[derive(ORM, Default, Debug, PartialEq)]
+struct Layout {
+    #[primary]
+    id: i32,
+    #[column(json)]
+    cells: std::collections::HashMap<(i32, i32), String>,
+}
+
+#[derive(LinqEnum, Debug, PartialEq, Default)]
+enum Role {
+    #[default]
+    Guest,
+    #[linq(rename = "admin")]
+    Admin,
+}
+
+#[derive(ORM, Default, Debug, PartialEq)]
+struct Member {
+    #[primary]
+    id: i32,
+    role: Role,
+    prev_role: Option<Role>,
+}
+
+#[derive(ColumnLike, Default, Debug, PartialEq, Clone, Copy)]
+struct HostId(i32);
+
+/// `IpAddr` stored as nullable string column.
+mod ip_addr {
+    use std::net::IpAddr;
+
+    use linq_rs::Variant;
+
+    pub fn to_variant(value: Option<IpAddr>) -> Variant {
+        match value {
+            Some(v) => v.to_string().into(),
+            None => Variant::Null,
+        }
+    }
+
+    pub fn from_variant(value: Variant) -> anyhow::Result<Option<IpAddr>> {
+        match value {
+            Variant::Null => Ok(None),
+            Variant::String(v) => Ok(Some(v.parse()?)),
+            _ => Err(anyhow::format_err!(
+                "Expect ip address string, got {:?}",
+                value
+            )),
+        }
+    }
+}
+
+#[derive(ORM, Default, Debug, PartialEq)]
+struct Host {
+    #[primary]
+    id: HostId,
+    #[column("ip", with = "ip_addr")]
+    addr: Option<std::net::IpAddr>,
+}
+
+#[derive(FromRow, Debug, PartialEq)]
+struct UserTotal {
+    user_id: u32,
+    #[column("n")]
+    count: usize,
+    total: Option<i64>,
+    role: Option<Role>,
+}
+
+async fn open() -> anyhow::Result<MemoryDriver> {
+    let mut d = MemoryDriver::default();
+
+    create_tables(&mut d).await?;
+
+    Ok(d)
+}
+
+async fn collect(rows: &mut impl QueryIterator) -> anyhow::Result<Vec<Variant>> {
+    let mut values = vec![];
+
+    while rows.next().await? {
+        values.push(rows.get(0).await?);
+    }
+
+    Ok(values)
+}
+
+#[async_std::test]
+async fn test_rql() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    for (first_name, last_name) in [("a", "x"), ("b", "y"), ("c", "x")] {
+        d.insert(
+            &rql! { INSERT INTO user_table(first_name,last_name) },
+            vec![first_name.into(), last_name.into()],
+        )
+        .await?;
+    }
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT first_name AS name FROM user_table WHERE last_name = "x" OR id_ in (2) ORDER BY id_ DESC LIMIT 2 OFFSET 1;
+        })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec!["b".into(), "a".into()]);
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT * FROM user_table WHERE first_name LIKE "_" AND id_ >= 2;
+        })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get_by_name("last_name").await?, "y".into());
+
+    let updated = d
+        .update(
+            &rql! { UPDATE user_table(last_name) WHERE last_name = "x" },
+            vec!["z".into()],
+        )
+        .await?;
+
+    assert_eq!(updated.affected_rows, 2);
+
+    let deleted = d
+        .delete(&rql! { DELETE FROM user_table WHERE id_ != 2 })
+        .await?;
+
+    assert_eq!(deleted.affected_rows, 2);
+
+    // Ids are not reused after delete.
+    let id = d
+        .insert(
+            &rql! { INSERT INTO user_table(first_name,last_name) },
+            vec!["d".into(), "w".into()],
+        )
+        .await?;
+
+    assert_eq!(id.last_insert_id(), Some(4));
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_constraints() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let qir = rql! { INSERT INTO Card(id,user_id,card_no) };
+
+    d.insert(&qir, vec![1.into(), 1.into(), "1001".into()])
+        .await?;
+
+    // Duplicate primary key, unique col, null and mismatched type are rejected.
+    assert!(d
+        .insert(&qir, vec![1.into(), 1.into(), "1002".into()])
+        .await
+        .is_err());
+
+    assert!(d
+        .insert(&qir, vec![2.into(), 1.into(), "1001".into()])
+        .await
+        .is_err());
+
+    assert!(d
+        .insert(&qir, vec![2.into(), Variant::Null, "1002".into()])
+        .await
+        .is_err());
+
+    assert!(d
+        .insert(&qir, vec![2.into(), "1".into(), "1002".into()])
+        .await
+        .is_err());
+
+    let ddls = ddl! {
+        ALTER TABLE Card DROP CONSTRAINT card_no_unique;
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    d.insert(&qir, vec![2.into(), 1.into(), "1001".into()])
+        .await?;
+
+    let ddls = ddl! {
+        ALTER TABLE Card ADD COLUMN memo STRING "none";
+        ALTER TABLE Card RENAME COLUMN card_no TO no;
+        ALTER TABLE Card RENAME TABLE TO UserCard;
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let mut rows = d
+        .select(&rql! { SELECT memo FROM UserCard WHERE no = "1001"; })
+        .await?;
+
+    assert_eq!(
+        collect(&mut rows).await?,
+        vec!["none".into(), "none".into()]
+    );
+
+    assert!(d.select(&rql! { SELECT * FROM Card; }).await.is_err());
+
+    let ddls = ddl! {
+        TRUNCATE TABLE UserCard;
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let mut rows = d.select(&rql! { SELECT * FROM UserCard; }).await?;
+
+    assert!(!rows.next().await?);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_null_semantics() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    let ddls = ddl! {
+        CREATE TABLE t(id INT PRIMARY, value INT);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let qir = rql! { INSERT INTO t(id,value) };
+
+    d.insert(&qir, vec![1.into(), 1.into()]).await?;
+    d.insert(&qir, vec![2.into(), Variant::Null]).await?;
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t WHERE value != 1; })
+        .await?;
+
+    assert!(collect(&mut rows).await?.is_empty());
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t WHERE value = 1 OR value = 2; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![1.into()]);
+
+    let mut rows = d
+        .select(&rql! { SELECT value FROM t ORDER BY value; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![Variant::Null, 1.into()]);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_cond_ops() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    let ddls = ddl! {
+        CREATE TABLE t(id INT PRIMARY, value INT, name STRING);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let qir = rql! { INSERT INTO t(id,value,name) };
+
+    for (id, value, name) in [
+        (1, 1.into(), "a1"),
+        (2, Variant::Null, "b2"),
+        (3, 5.into(), "a3"),
+    ] {
+        d.insert(&qir, vec![id.into(), value, name.into()]).await?;
+    }
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t WHERE value IS NULL; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![2.into()]);
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t WHERE value IS NOT NULL AND name NOT LIKE "%1"; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![3.into()]);
+
+    // `NULL` is neither between nor not between the bounds.
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t WHERE value BETWEEN 1 AND 4; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![1.into()]);
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t WHERE value NOT BETWEEN 1 AND 4; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![3.into()]);
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t WHERE value NOT in (1); })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![3.into()]);
+
+    // `NOT UNKNOWN` is still `UNKNOWN`.
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t WHERE NOT (value = 1 OR name = "a3"); })
+        .await?;
+
+    assert!(collect(&mut rows).await?.is_empty());
+
+    let deleted = d
+        .delete(&rql! { DELETE FROM t WHERE NOT value IS NOT NULL })
+        .await?;
+
+    assert_eq!(deleted.affected_rows, 1);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_order() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    let ddls = ddl! {
+        CREATE TABLE t(id INT PRIMARY, value INT, name STRING);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let qir = rql! { INSERT INTO t(id,value,name) };
+
+    for (id, value, name) in [
+        (1, 1.into(), "b"),
+        (2, Variant::Null, "a"),
+        (3, 1.into(), "a"),
+        (4, 2.into(), "c"),
+    ] {
+        d.insert(&qir, vec![id.into(), value, name.into()]).await?;
+    }
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t ORDER BY value, name DESC; })
+        .await?;
+
+    assert_eq!(
+        collect(&mut rows).await?,
+        vec![2.into(), 1.into(), 3.into(), 4.into()]
+    );
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t ORDER BY value NULLS LAST, name; })
+        .await?;
+
+    assert_eq!(
+        collect(&mut rows).await?,
+        vec![3.into(), 1.into(), 4.into(), 2.into()]
+    );
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM t ORDER BY value DESC NULLS FIRST, id DESC; })
+        .await?;
+
+    assert_eq!(
+        collect(&mut rows).await?,
+        vec![2.into(), 4.into(), 3.into(), 1.into()]
+    );
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_cond_column() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let qir = rql! { INSERT INTO user_table(first_name,last_name) };
+
+    for (first_name, last_name) in [("a", "a"), ("b", "last_name"), ("c", "x")] {
+        d.insert(&qir, vec![first_name.into(), last_name.into()])
+            .await?;
+    }
+
+    let mut rows = d
+        .select(&rql! { SELECT first_name FROM user_table WHERE first_name = last_name; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec!["a".into()]);
+
+    let mut rows = d
+        .select(&rql! { SELECT first_name FROM user_table WHERE last_name = "last_name"; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec!["b".into()]);
+
+    let mut rows = d
+        .select(&rql! { SELECT first_name FROM user_table WHERE "x" = last_name; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec!["c".into()]);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_orm() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    for (id, name) in [(1, "hello"), (3, "world")] {
+        User {
+            first_name: name.into(),
+            last_name: "rust".into(),
+            cards: vec![
+                Card {
+                    id,
+                    card_no: format!("100{}", id),
+                    ..Default::default()
+                },
+                Card {
+                    id: id + 1,
+                    card_no: format!("100{}", id + 1),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+        .insert()
+        .exec(&mut d)
+        .await?;
+    }
+
+    let users = Vec::<User>::select()
+        .order_by("id_", true)
+        .limit(10, 0)
+        .exec(&mut d)
+        .await?;
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].first_name, "world");
+
+    for (user, ids) in users.iter().zip([[3, 4], [1, 2]]) {
+        let mut card_ids = user.cards.iter().map(|card| card.id).collect::<Vec<_>>();
+
+        card_ids.sort();
+
+        assert_eq!(card_ids, ids);
+        assert!(user
+            .cards
+            .iter()
+            .all(|card| Some(card.user_id as i32) == user.id));
+    }
+
+    let user = User::select()
+        .cond(rql_where!(first_name = "hello"))
+        .exec(&mut d)
+        .await?
+        .expect("user hello");
+
+    assert_eq!(user.id, Some(1));
+    assert_eq!(user.cards.len(), 2);
+
+    let deleted = <Card as DeleteWhereCond>::delete()
+        .cond(rql_where!(user_id = 1))
+        .exec(&mut d)
+        .await?;
+
+    assert_eq!(deleted, 2);
+
+    // Objects are deleted by primary key, whatever the other fields hold.
+    let deleted = <Card as DeleteObject>::delete(Card {
+        id: 3,
+        user_id: 2,
+        card_no: "1004".into(),
+    })
+    .exec(&mut d)
+    .await?;
+
+    assert_eq!(deleted, 1);
+
+    let cards = Vec::<Card>::select().exec(&mut d).await?;
+
+    assert_eq!(cards.iter().map(|card| card.id).collect::<Vec<_>>(), [4]);
+
+    assert!(<User as DeleteObject>::delete(User::default())
+        .exec(&mut d)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_transaction() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    // The second card violates the primary key, the whole cascade is rolled back.
+    let result = User {
+        first_name: "hello".into(),
+        last_name: "world".into(),
+        cards: vec![
+            Card {
+                id: 1,
+                card_no: "1001".into(),
+                ..Default::default()
+            },
+            Card {
+                id: 1,
+                card_no: "1002".into(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+    .insert()
+    .exec_tx(&mut d)
+    .await;
+
+    assert!(result.is_err());
+
+    let mut rows = d.select(&rql! { SELECT * FROM user_table; }).await?;
+
+    assert!(!rows.next().await?);
+
+    let qir = rql! { INSERT INTO user_table(first_name,last_name) };
+
+    {
+        let mut tx = Transaction::begin(&mut d).await?;
+
+        tx.insert(&qir, vec!["a".into(), "x".into()]).await?;
+
+        tx.savepoint("sp").await?;
+
+        tx.insert(&qir, vec!["b".into(), "x".into()]).await?;
+
+        tx.rollback_to_savepoint("sp").await?;
+
+        tx.release_savepoint("sp").await?;
+
+        tx.commit().await?;
+    }
+
+    {
+        let mut tx = Transaction::begin(&mut d).await?;
+
+        tx.insert(&qir, vec!["c".into(), "x".into()]).await?;
+
+        // Dropped without commit.
+    }
+
+    {
+        let mut tx = Transaction::begin(&mut d).await?;
+
+        tx.insert(&qir, vec!["d".into(), "x".into()]).await?;
+
+        // The nested cascade runs in a savepoint, only its own rows are rolled back.
+        let result = User {
+            first_name: "e".into(),
+            last_name: "x".into(),
+            cards: vec![
+                Card {
+                    id: 1,
+                    card_no: "1001".into(),
+                    ..Default::default()
+                },
+                Card {
+                    id: 1,
+                    card_no: "1002".into(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+        .insert()
+        .exec_tx(&mut *tx)
+        .await;
+
+        assert!(result.is_err());
+
+        tx.commit().await?;
+    }
+
+    let mut rows = d
+        .select(&rql! { SELECT first_name FROM user_table; })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec!["a".into(), "d".into()]);
+
+    assert!(d.commit().await.is_err());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_join() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let qir = rql! { INSERT INTO user_table(first_name,last_name) };
+
+    for name in ["a", "b", "c"] {
+        d.insert(&qir, vec![name.into(), "x".into()]).await?;
+    }
+
+    let qir = rql! { INSERT INTO Card(id,user_id,card_no) };
+
+    for (id, user_id) in [(1, 1), (2, 1), (3, 2), (4, 9)] {
+        d.insert(
+            &qir,
+            vec![id.into(), user_id.into(), format!("100{}", id).into()],
+        )
+        .await?;
+    }
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT c.card_no FROM user_table AS u JOIN Card AS c ON u.id_ = c.user_id WHERE u.first_name = "a" ORDER BY c.id DESC;
+        })
+        .await?;
+
+    assert_eq!(
+        collect(&mut rows).await?,
+        vec!["1002".into(), "1001".into()]
+    );
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT u.first_name, c.card_no FROM user_table AS u LEFT JOIN Card AS c ON u.id_ = c.user_id ORDER BY c.id;
+        })
+        .await?;
+
+    // Unmatched `c` is padded with NULL, which sorts first.
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, "c".into());
+    assert_eq!(rows.get_by_name("card_no").await?, Variant::Null);
+
+    // Card 4 has no user.
+    let mut rows = d
+        .select(&rql! {
+            SELECT Card.id FROM user_table RIGHT JOIN Card ON user_table.id_ = Card.user_id ORDER BY user_table.id_ LIMIT 1;
+        })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![4.into()]);
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT * FROM user_table CROSS JOIN Card;
+        })
+        .await?;
+
+    assert_eq!(rows.col_names().len(), 7);
+    assert_eq!(collect(&mut rows).await?.len(), 12);
+
+    // Unqualified col is ambiguous in self join.
+    assert!(d
+        .select(&rql! { SELECT card_no FROM Card AS a JOIN Card AS b ON a.id = b.id; })
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_aggregate() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    let ddls = ddl! {
+        CREATE TABLE orders(id INT PRIMARY, user_id INT NOT NULL, amount INT);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    // Empty table still yields one row for aggregates without GROUP BY.
+    let mut rows = d
+        .select(&rql! { SELECT COUNT(*), SUM(amount) FROM orders; })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, 0.into());
+    assert_eq!(rows.get(1).await?, Variant::Null);
+
+    let qir = rql! { INSERT INTO orders(id,user_id,amount) };
+
+    for (id, user_id, amount) in [(1, 1, 10), (2, 1, 20), (3, 2, 5), (4, 3, 7), (5, 3, 1)] {
+        d.insert(&qir, vec![id.into(), user_id.into(), amount.into()])
+            .await?;
+    }
+
+    d.insert(&qir, vec![6.into(), 3.into(), Variant::Null])
+        .await?;
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT user_id, COUNT(amount) AS n, SUM(amount) AS total FROM orders GROUP BY user_id HAVING COUNT(*) > 1 ORDER BY total DESC;
+        })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, 1.into());
+    assert_eq!(rows.get_by_name("n").await?, 2.into());
+    assert_eq!(rows.get_by_name("total").await?, 30.into());
+
+    // NULL amount is skipped by COUNT(amount), not by COUNT(*) in HAVING.
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, 3.into());
+    assert_eq!(rows.get_by_name("n").await?, 2.into());
+    assert_eq!(rows.get_by_name("total").await?, 8.into());
+
+    assert!(!rows.next().await?);
+
+    let mut rows = d
+        .select(&rql! { SELECT MIN(amount), MAX(amount) FROM orders WHERE user_id != 2; })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, 1.into());
+    assert_eq!(rows.get(1).await?, 20.into());
+
+    // Non-grouped col in select list is rejected.
+    assert!(d
+        .select(&rql! { SELECT id, COUNT(*) FROM orders GROUP BY user_id; })
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_orm_count() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    assert_eq!(Vec::<User>::select().count(&mut d).await?, 0);
+    assert!(!Vec::<User>::select().exists(&mut d).await?);
+
+    for name in ["hello", "world"] {
+        User {
+            first_name: name.into(),
+            last_name: "rust".into(),
+            ..Default::default()
+        }
+        .insert()
+        .exec(&mut d)
+        .await?;
+    }
+
+    assert_eq!(Vec::<User>::select().count(&mut d).await?, 2);
+
+    assert_eq!(
+        Vec::<User>::select()
+            .cond(rql_where!(first_name = "hello"))
+            .count(&mut d)
+            .await?,
+        1
+    );
+
+    assert!(
+        Vec::<User>::select()
+            .cond(rql_where!(first_name = "world"))
+            .exists(&mut d)
+            .await?
+    );
+
+    assert!(
+        !Vec::<User>::select()
+            .cond(rql_where!(first_name = "none"))
+            .exists(&mut d)
+            .await?
+    );
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_orm_then_by() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    for (first_name, last_name) in [("a", "y"), ("b", "x"), ("c", "y")] {
+        User {
+            first_name: first_name.into(),
+            last_name: last_name.into(),
+            ..Default::default()
+        }
+        .insert()
+        .exec(&mut d)
+        .await?;
+    }
+
+    let users = Vec::<User>::select()
+        .order_by("last_name", true)
+        .then_by("first_name", false)
+        .exec(&mut d)
+        .await?;
+
+    let names = users
+        .iter()
+        .map(|user| user.first_name.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, vec!["a", "c", "b"]);
+
+    // `order_by` drops the previous terms.
+    let users = Vec::<User>::select()
+        .order_by("last_name", true)
+        .then_by("first_name", false)
+        .order_by("first_name", true)
+        .exec(&mut d)
+        .await?;
+
+    let names = users
+        .iter()
+        .map(|user| user.first_name.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, vec!["c", "b", "a"]);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_subquery() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let qir = rql! { INSERT INTO user_table(first_name,last_name) };
+
+    for name in ["a", "b", "c"] {
+        d.insert(&qir, vec![name.into(), "x".into()]).await?;
+    }
+
+    let qir = rql! { INSERT INTO Card(id,user_id,card_no) };
+
+    for (id, user_id) in [(1, 1), (2, 1), (3, 2)] {
+        d.insert(
+            &qir,
+            vec![id.into(), user_id.into(), format!("100{}", id).into()],
+        )
+        .await?;
+    }
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT card_no FROM Card WHERE user_id in (SELECT id_ FROM user_table WHERE first_name = "b");
+        })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec!["1003".into()]);
+
+    // Correlated subquery refers cols of the outer query.
+    let mut rows = d
+        .select(&rql! {
+            SELECT first_name FROM user_table AS u WHERE NOT EXISTS (SELECT * FROM Card WHERE Card.user_id = u.id_);
+        })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec!["c".into()]);
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT first_name FROM user_table WHERE id_ = (SELECT MAX(user_id) FROM Card);
+        })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec!["b".into()]);
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT c.n FROM (SELECT user_id, COUNT(*) AS n FROM Card GROUP BY user_id) AS c WHERE c.user_id = 1;
+        })
+        .await?;
+
+    assert_eq!(collect(&mut rows).await?, vec![2.into()]);
+
+    // Scalar subquery must return at most one row.
+    assert!(d
+        .select(&rql! { SELECT * FROM user_table WHERE id_ = (SELECT user_id FROM Card); })
+        .await
+        .is_err());
+
+    let deleted = d
+        .delete(&rql! { DELETE FROM user_table WHERE id_ NOT in (SELECT user_id FROM Card) })
+        .await?;
+
+    assert_eq!(deleted.affected_rows, 1);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_bool() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    check_bool(&mut d).await
+}
+
+#[async_std::test]
+async fn test_numeric() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    check_numeric(&mut d, u64::MAX).await
+}
+
+#[async_std::test]
+async fn test_json() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    check_json(&mut d).await?;
+
+    let layout = || Layout {
+        id: 1,
+        cells: [((0, 0), "a".to_owned())].into(),
+    };
+
+    // Serialize errors are returned instead of panicking.
+    let errs = [
+        layout().insert().exec(&mut d).await.map(|_| ()),
+        layout().update().exec(&mut d).await,
+        layout().delete().exec(&mut d).await.map(|_| ()),
+        vec![layout()].insert().exec(&mut d).await.map(|_| ()),
+    ];
+
+    for err in errs {
+        assert!(err
+            .unwrap_err()
+            .to_string()
+            .contains("Column(cells) serialize"));
+    }
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_date_time() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    check_date_time(&mut d, 8 * 3600).await
+}
+
+#[async_std::test]
+async fn test_enum() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    let ddls = ddl! {
+        CREATE TABLE Member(id INT PRIMARY, role STRING NOT NULL, prev_role STRING);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    Member {
+        id: 1,
+        role: Role::Admin,
+        prev_role: Some(Role::Guest),
+    }
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    let members = Vec::<Member>::select()
+        .cond(rql_where!(role = "admin"))
+        .exec(&mut d)
+        .await?;
+
+    assert_eq!(
+        members,
+        vec![Member {
+            id: 1,
+            role: Role::Admin,
+            prev_role: Some(Role::Guest),
+        }]
+    );
+
+    d.insert(
+        &rql! { INSERT INTO Member(id,role) },
+        vec![2.into(), "root".into()],
+    )
+    .await?;
+
+    let err = Member::select()
+        .cond(rql_where!(id = 2))
+        .exec(&mut d)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), r#"Unknown Role value "root""#);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_column_with() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    let ddls = ddl! {
+        CREATE TABLE Host(id INT PRIMARY, ip STRING);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let host = Host {
+        id: HostId(1),
+        addr: Some("10.0.0.1".parse()?),
+    };
+
+    host.insert().exec(&mut d).await?;
+
+    let mut rows = d
+        .select(&rql! { SELECT ip FROM Host WHERE id = 1; })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, "10.0.0.1".into());
+
+    let hosts = Vec::<Host>::select().exec(&mut d).await?;
+
+    assert_eq!(
+        hosts,
+        vec![Host {
+            id: HostId(1),
+            addr: Some("10.0.0.1".parse()?),
+        }]
+    );
+
+    d.insert(
+        &rql! { INSERT INTO Host(id,ip) },
+        vec![2.into(), "localhost".into()],
+    )
+    .await?;
+
+    d.insert(&rql! { INSERT INTO Host(id) }, vec![3.into()])
+        .await?;
+
+    let host = Host::select().cond(rql_where!(id = 3)).exec(&mut d).await?;
+
+    assert_eq!(host.map(|v| v.addr), Some(None));
+
+    assert!(Host::select()
+        .cond(rql_where!(id = 2))
+        .exec(&mut d)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_orm_stream() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let mut d = open().await?;
+
+    // Spans multiple stream chunks.
+    let count = orm::STREAM_CHUNK_SIZE * 2 + 10;
+
+    for id in 1..=count {
+        User {
+            first_name: format!("user{}", id),
+            last_name: "rust".into(),
+            cards: vec![Card {
+                id,
+                card_no: format!("100{}", id),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+        .insert()
+        .exec(&mut d)
+        .await?;
+    }
+
+    let mut stream = Vec::<User>::select().order_by("id_", false).stream(&mut d);
+
+    let mut streamed = 0;
+
+    while let Some(user) = stream.try_next().await? {
+        streamed += 1;
+
+        assert_eq!(user.id, Some(streamed as i32));
+        assert_eq!(user.cards.len(), 1);
+        assert_eq!(user.cards[0].user_id, streamed);
+    }
+
+    assert_eq!(streamed, count);
+
+    drop(stream);
+
+    let users: Vec<User> = Vec::<User>::select()
+        .cond(rql_where!(first_name = "user3"))
+        .stream(&mut d)
+        .try_collect()
+        .await?;
+
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].cards[0].card_no, "1003");
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_from_row() -> anyhow::Result<()> {
+    let mut d = MemoryDriver::default();
+
+    let ddls = ddl! {
+        CREATE TABLE orders(id INT PRIMARY, user_id INT NOT NULL, amount INT, role STRING);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let qir = rql! { INSERT INTO orders(id,user_id,amount,role) };
+
+    for (id, user_id, amount) in [(1, 1, 10), (2, 1, 20), (3, 2, 5)] {
+        d.insert(
+            &qir,
+            vec![id.into(), user_id.into(), amount.into(), "admin".into()],
+        )
+        .await?;
+    }
+
+    d.insert(&qir, vec![4.into(), 3.into(), Variant::Null, Variant::Null])
+        .await?;
+
+    let rows = d
+        .select(&rql! {
+            SELECT user_id, COUNT(*) AS n, SUM(amount) AS total, MAX(role) AS role FROM orders GROUP BY user_id ORDER BY user_id;
+        })
+        .await?;
+
+    assert_eq!(
+        orm::collect_rows::<UserTotal, _>(rows).await?,
+        vec![
+            UserTotal {
+                user_id: 1,
+                count: 2,
+                total: Some(30),
+                role: Some(Role::Admin),
+            },
+            UserTotal {
+                user_id: 2,
+                count: 1,
+                total: Some(5),
+                role: Some(Role::Admin),
+            },
+            UserTotal {
+                user_id: 3,
+                count: 1,
+                total: None,
+                role: None,
+            },
+        ]
+    );
+
+    let mut rows = d
+        .select(&rql! { SELECT id, amount FROM orders WHERE id = 1; })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get_as::<u8>("amount").await?, 10);
+    assert_eq!(rows.get_as::<Option<i32>>("id").await?, Some(1));
+    assert!(rows.get_as::<String>("amount").await.is_err());
+
+    // Missing col
+    let rows = d.select(&rql! { SELECT user_id FROM orders; }).await?;
+
+    assert!(orm::collect_rows::<UserTotal, _>(rows).await.is_err());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_insert_many() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    check_insert_many(&mut d).await
+}
+
+#[async_std::test]
+async fn test_upsert() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    check_upsert(&mut d).await?;
+
+    // Conflict target must be a primary or unique key, as in SQL databases.
+    assert!(d
+        .insert(
+            &rql! { INSERT INTO Card(id,user_id,card_no) ON CONFLICT (user_id) DO NOTHING },
+            vec![5.into(), 1.into(), "5005".into()],
+        )
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_returning() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let result = d
+        .insert(
+            &rql! { INSERT INTO user_table(first_name,last_name) RETURNING (id_,first_name) },
+            vec!["a".into(), "x".into()],
+        )
+        .await?;
+
+    assert_eq!(result.affected_rows, 1);
+    assert_eq!(result.generated_keys, vec![1]);
+    assert_eq!(result.returning, vec![vec![1.into(), "a".into()]]);
+
+    let result = d
+        .update(
+            &rql! { UPDATE user_table(last_name) WHERE first_name = "a" RETURNING (id_,last_name) },
+            vec!["z".into()],
+        )
+        .await?;
+
+    assert_eq!(result.affected_rows, 1);
+    assert_eq!(result.returning, vec![vec![1.into(), "z".into()]]);
+
+    let result = d
+        .delete(&rql! { DELETE FROM user_table WHERE id_ = 1 RETURNING (first_name) })
+        .await?;
+
+    assert_eq!(result.affected_rows, 1);
+    assert_eq!(result.returning, vec![vec!["a".into()]]);
+
+    // Generated keys are written back to inserted objects.
+    let user = User {
+        first_name: "hello".into(),
+        last_name: "world".into(),
+        cards: vec![Card {
+            id: 1,
+            card_no: "1001".into(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    assert_eq!(user.id, Some(2));
+    assert_eq!(user.cards[0].user_id, 2);
+
+    let users = vec![
+        User {
+            first_name: "b".into(),
+            last_name: "y".into(),
+            ..Default::default()
+        },
+        User {
+            first_name: "c".into(),
+            last_name: "y".into(),
+            ..Default::default()
+        },
+    ]
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    assert_eq!(
+        users.iter().map(|user| user.id).collect::<Vec<_>>(),
+        vec![Some(3), Some(4)]
+    );
+
+    Ok(())
+}
+
+/// Counts select stmts executed by the inner driver.
+struct CountingDriver {
+    d: MemoryDriver,
+    selects: usize,
+}
+
+#[async_trait::async_trait]
+impl<'a> SelectSupport<'a> for CountingDriver {
+    type SelectResult = <MemoryDriver as SelectSupport<'a>>::SelectResult;
+
+    async fn select(&mut self, selecter: &dml::Selecter<'a>) -> anyhow::Result<Self::SelectResult> {
+        self.selects += 1;
+
+        self.d.select(selecter).await
+    }
+}
+
+#[async_std::test]
+async fn test_cascade_batch() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    // One more user than `CASCADE_CHUNK_SIZE`, every 100th user has two cards.
+    let users = (0..orm::CASCADE_CHUNK_SIZE + 1)
+        .map(|idx| User {
+            first_name: format!("user{}", idx),
+            last_name: "x".into(),
+            cards: if idx % 100 == 0 {
+                vec![
+                    Card {
+                        id: idx * 2,
+                        card_no: format!("{}", idx * 2),
+                        ..Default::default()
+                    },
+                    Card {
+                        id: idx * 2 + 1,
+                        card_no: format!("{}", idx * 2 + 1),
+                        ..Default::default()
+                    },
+                ]
+            } else {
+                vec![]
+            },
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    users.insert().exec(&mut d).await?;
+
+    let mut d = CountingDriver { d, selects: 0 };
+
+    let users = Vec::<User>::select()
+        .order_by("id_", false)
+        .exec(&mut d)
+        .await?;
+
+    // One parent select, two chunks of card selects.
+    assert_eq!(d.selects, 3);
+
+    assert_eq!(users.len(), orm::CASCADE_CHUNK_SIZE + 1);
+
+    for (idx, user) in users.iter().enumerate() {
+        let card_ids = user.cards.iter().map(|card| card.id).collect::<Vec<_>>();
+
+        if idx % 100 == 0 {
+            assert_eq!(card_ids, vec![idx * 2, idx * 2 + 1]);
+        } else {
+            assert!(card_ids.is_empty());
+        }
+
+        for card in &user.cards {
+            assert_eq!(card.user_id as i32, user.id.unwrap());
+        }
+    }
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_lazy_cascade() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    vec![
+        User {
+            first_name: "a".into(),
+            last_name: "x".into(),
+            cards: vec![
+                Card {
+                    id: 1,
+                    card_no: "1001".into(),
+                    ..Default::default()
+                },
+                Card {
+                    id: 2,
+                    card_no: "1002".into(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        User {
+            first_name: "b".into(),
+            last_name: "x".into(),
+            ..Default::default()
+        },
+    ]
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    let users = Vec::<UserCards>::select()
+        .order_by("id_", false)
+        .exec(&mut d)
+        .await?;
+
+    // Lazy cascade is skipped, missing one-to-one child is `None`.
+    assert!(users[0].cards.is_empty());
+    assert_eq!(users[0].first_card.as_ref().map(|card| card.id), Some(1));
+    assert!(users[1].cards.is_empty());
+    assert_eq!(users[1].first_card, None);
+
+    let user = UserCards::select()
+        .cond(rql_where!(id_ = 1))
+        .with_relation("cards")
+        .exec(&mut d)
+        .await?
+        .unwrap();
+
+    assert_eq!(
+        user.cards.iter().map(|card| card.id).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    assert!(Vec::<UserCards>::select()
+        .with_relation("first_name")
+        .exec(&mut d)
+        .await
+        .is_err());
+
+    let mut d = CountingDriver { d, selects: 0 };
+
+    let users = Vec::<User>::select()
+        .without_relations()
+        .exec(&mut d)
+        .await?;
+
+    assert_eq!(d.selects, 1);
+    assert_eq!(users.len(), 2);
+    assert!(users.iter().all(|user| user.cards.is_empty()));
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_nested_relations() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    User {
+        first_name: "a".into(),
+        last_name: "x".into(),
+        cards: vec![
+            Card {
+                id: 1,
+                card_no: "1001".into(),
+                ..Default::default()
+            },
+            Card {
+                id: 2,
+                card_no: "1002".into(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    // Required one-to-one col is loaded even if relations are skipped.
+    let card = CardOwner::select()
+        .cond(rql_where!(id = 2))
+        .without_relations()
+        .exec(&mut d)
+        .await?
+        .unwrap();
+
+    assert_eq!(card.owner.first_name, "a");
+    assert!(card.owner.cards.is_empty());
+    assert_eq!(card.owner.first_card, None);
+
+    // Lazy col of the second level is skipped by default.
+    let card = CardOwner::select()
+        .cond(rql_where!(id = 2))
+        .exec(&mut d)
+        .await?
+        .unwrap();
+
+    assert!(card.owner.cards.is_empty());
+    assert!(card.owner.first_card.is_some());
+
+    let cards = Vec::<CardOwner>::select()
+        .with_relation("owner.cards")
+        .order_by("id", false)
+        .exec(&mut d)
+        .await?;
+
+    assert_eq!(cards.len(), 2);
+
+    for card in &cards {
+        let mut ids = card
+            .owner
+            .cards
+            .iter()
+            .map(|card| card.id)
+            .collect::<Vec<_>>();
+
+        ids.sort();
+
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    // Nested paths are scoped, `without_relations` applies to every level.
+    let card = CardOwner::select()
+        .cond(rql_where!(id = 1))
+        .without_relations()
+        .with_relation("owner.cards")
+        .exec(&mut d)
+        .await?
+        .unwrap();
+
+    assert_eq!(card.owner.cards.len(), 2);
+    assert_eq!(card.owner.first_card, None);
+
+    assert!(CardOwner::select()
+        .with_relation("owner.first_name")
+        .exec(&mut d)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+/// `(user_id, group_id)` of link rows in table order.
+async fn user_groups(d: &mut MemoryDriver) -> anyhow::Result<Vec<(i32, i32)>> {
+    let links = Vec::<UserGroup>::select().exec(d).await?;
+
+    Ok(links
+        .into_iter()
+        .map(|link| (link.user_id, link.group_id))
+        .collect())
+}
+
+#[async_std::test]
+async fn test_many_to_many() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let ddls = ddl! {
+        CREATE TABLE group_table(
+            id INT PRIMARY,
+            name STRING NOT NULL,
+        );
+
+        CREATE TABLE UserGroup(
+            user_id INT NOT NULL,
+            group_id INT NOT NULL,
+            CONSTRAINT user_group_unique UNIQUE(user_id, group_id),
+        );
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let group = |id: i32, name: &str| Group {
+        id,
+        name: name.into(),
+    };
+
+    vec![group(1, "admin"), group(2, "dev"), group(3, "ops")]
+        .insert()
+        .exec(&mut d)
+        .await?;
+
+    let users = vec![
+        UserGroups {
+            first_name: "a".into(),
+            last_name: "x".into(),
+            groups: Some(vec![group(1, "admin"), group(2, "dev")]),
+            ..Default::default()
+        },
+        UserGroups {
+            first_name: "b".into(),
+            last_name: "x".into(),
+            groups: Some(vec![group(2, "dev")]),
+            ..Default::default()
+        },
+    ]
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    assert_eq!(users[0].id, Some(1));
+
+    // Only link rows are inserted for existing groups.
+    assert_eq!(Vec::<Group>::select().count(&mut d).await?, 3);
+    assert_eq!(Vec::<UserGroup>::select().count(&mut d).await?, 3);
+
+    let group_names = |user: &UserGroups| {
+        user.groups
+            .iter()
+            .flatten()
+            .map(|group| group.name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let users = Vec::<UserGroups>::select()
+        .order_by("id_", false)
+        .exec(&mut d)
+        .await?;
+
+    assert_eq!(group_names(&users[0]), vec!["admin", "dev"]);
+    assert_eq!(group_names(&users[1]), vec!["dev"]);
+
+    // Relations not loaded keep their link rows.
+    let mut user = UserGroups::select()
+        .cond(rql_where!(id_ = 2))
+        .without_relations()
+        .exec(&mut d)
+        .await?
+        .unwrap();
+
+    assert_eq!(user.groups, None);
+
+    user.last_name = "z".into();
+    user.update().exec(&mut d).await?;
+
+    assert_eq!(Vec::<UserGroup>::select().count(&mut d).await?, 3);
+
+    // Upsert skips existing link rows.
+    for _ in 0..2 {
+        UserGroups {
+            id: Some(2),
+            first_name: "b".into(),
+            last_name: "z".into(),
+            groups: Some(vec![group(2, "dev"), group(3, "ops")]),
+        }
+        .upsert()
+        .exec(&mut d)
+        .await?;
+    }
+
+    assert_eq!(Vec::<UserGroup>::select().count(&mut d).await?, 4);
+
+    // Update syncs link rows of the user, the kept link stays in place.
+    UserGroups {
+        id: Some(1),
+        first_name: "a".into(),
+        last_name: "y".into(),
+        groups: Some(vec![group(2, "dev"), group(3, "ops")]),
+    }
+    .update()
+    .exec(&mut d)
+    .await?;
+
+    let user = UserGroups::select()
+        .cond(rql_where!(id_ = 1))
+        .exec(&mut d)
+        .await?
+        .unwrap();
+
+    assert_eq!(user.last_name, "y");
+    assert_eq!(group_names(&user), vec!["dev", "ops"]);
+
+    assert_eq!(
+        user_groups(&mut d).await?,
+        vec![(1, 2), (2, 2), (2, 3), (1, 3)]
+    );
+
+    // Deleting the user deletes its link rows.
+    user.delete().exec(&mut d).await?;
+
+    assert_eq!(user_groups(&mut d).await?, vec![(2, 2), (2, 3)]);
+
+    Ok(())
+}
@@ -0,0 +1,685 @@
+use linq_rs::{
+    render::{self, Dialect, MySql, Postgres, Sqlite, Statement},
+    *,
+};
+
+fn sql(stmts: Vec<Statement>) -> Vec<String> {
+    stmts.into_iter().map(|stmt| stmt.sql).collect()
+}
+
+#[test]
+fn test_select() -> anyhow::Result<()> {
+    let qir = rql! {
+        SELECT name, no AS user_no FROM user_table WHERE id != 100 AND (name = "hello" OR name LIKE "w%") ORDER BY name DESC LIMIT 10 OFFSET 2;
+    };
+
+    let stmt = render::select(&Sqlite, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT "name", "no" AS "user_no" FROM "user_table" WHERE "id" <> ? AND ("name" = ? OR "name" LIKE ?) ORDER BY "name" DESC LIMIT 10 OFFSET 2"#
+    );
+
+    assert_eq!(stmt.params, vec![100.into(), "hello".into(), "w%".into()]);
+
+    let stmt = render::select(&Postgres, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT "name", "no" AS "user_no" FROM "user_table" WHERE "id" <> $1 AND ("name" = $2 OR "name" LIKE $3) ORDER BY "name" DESC LIMIT 10 OFFSET 2"#
+    );
+
+    let stmt = render::select(&MySql, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT `name`, `no` AS `user_no` FROM `user_table` WHERE `id` <> ? AND (`name` = ? OR `name` LIKE ?) ORDER BY `name` DESC LIMIT 10 OFFSET 2"#
+    );
+
+    let qir = rql! {
+        SELECT * FROM user_table WHERE id in (1,2,3);
+    };
+
+    let stmt = render::select(&Postgres, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT * FROM "user_table" WHERE "id" IN ($1, $2, $3)"#
+    );
+
+    assert_eq!(stmt.params, vec![1.into(), 2.into(), 3.into()]);
+
+    Ok(())
+}
+
+#[test]
+fn test_join() -> anyhow::Result<()> {
+    let qir = rql! {
+        SELECT u.name, c.card_no AS no FROM user_table AS u INNER JOIN card AS c ON u.id = c.user_id AND c.card_no != "0" RIGHT JOIN tag ON tag.user_id = u.id WHERE u.id > 1 ORDER BY c.card_no;
+    };
+
+    let stmt = render::select(&Postgres, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT "u"."name", "c"."card_no" AS "no" FROM "user_table" AS "u" INNER JOIN "card" AS "c" ON "u"."id" = "c"."user_id" AND "c"."card_no" <> $1 RIGHT JOIN "tag" ON "tag"."user_id" = "u"."id" WHERE "u"."id" > $2 ORDER BY "c"."card_no" ASC"#
+    );
+
+    assert_eq!(stmt.params, vec!["0".into(), 1.into()]);
+
+    let qir = rql! {
+        SELECT * FROM user_table CROSS JOIN card;
+    };
+
+    assert_eq!(
+        render::select(&MySql, &qir)?.sql,
+        "SELECT * FROM `user_table` CROSS JOIN `card`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate() -> anyhow::Result<()> {
+    let qir = rql! {
+        SELECT user_id, COUNT(*) AS n, SUM(amount) FROM orders WHERE amount > 0 GROUP BY user_id HAVING COUNT(*) > 1 ORDER BY n DESC;
+    };
+
+    let stmt = render::select(&Sqlite, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT "user_id", COUNT(*) AS "n", SUM("amount") FROM "orders" WHERE "amount" > ? GROUP BY "user_id" HAVING COUNT(*) > ? ORDER BY "n" DESC"#
+    );
+
+    assert_eq!(stmt.params, vec![0.into(), 1.into()]);
+
+    Ok(())
+}
+
+#[test]
+fn test_order() -> anyhow::Result<()> {
+    let qir = rql! {
+        SELECT * FROM user_table ORDER BY age DESC NULLS LAST, name, id ASC NULLS FIRST;
+    };
+
+    let stmt = render::select(&Postgres, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT * FROM "user_table" ORDER BY "age" DESC NULLS LAST, "name" ASC, "id" ASC NULLS FIRST"#
+    );
+
+    // MySQL has no `NULLS FIRST/LAST`, emulated by sorting on `IS NULL` first.
+    let stmt = render::select(&MySql, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT * FROM `user_table` ORDER BY `age` IS NULL ASC, `age` DESC, `name` ASC, `id` IS NULL DESC, `id` ASC"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_cond_column() -> anyhow::Result<()> {
+    let qir = rql! {
+        SELECT * FROM user_table WHERE first_name = last_name OR "x" = first_name;
+    };
+
+    let stmt = render::select(&Sqlite, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT * FROM "user_table" WHERE "first_name" = "last_name" OR ? = "first_name""#
+    );
+
+    assert_eq!(stmt.params, vec!["x".into()]);
+
+    Ok(())
+}
+
+#[test]
+fn test_cond_ops() -> anyhow::Result<()> {
+    let qir = rql! {
+        SELECT * FROM t WHERE NOT (a IS NULL OR b NOT BETWEEN 1 AND 2) AND c NOT in (3) AND d NOT LIKE "x%" AND e IS NOT NULL AND f BETWEEN 4 AND 5;
+    };
+
+    let stmt = render::select(&Postgres, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT * FROM "t" WHERE NOT ("a" IS NULL OR "b" NOT BETWEEN $1 AND $2) AND ("c" NOT IN ($3) AND ("d" NOT LIKE $4 AND ("e" IS NOT NULL AND "f" BETWEEN $5 AND $6)))"#
+    );
+
+    assert_eq!(
+        stmt.params,
+        vec![
+            1.into(),
+            2.into(),
+            3.into(),
+            "x%".into(),
+            4.into(),
+            5.into()
+        ]
+    );
+
+    let mut qir = rql! {
+        SELECT * FROM t WHERE a NOT in (1);
+    };
+
+    qir.cond.as_mut().expect("cond").rhs = dml::CondParam::VariantList(vec![]);
+
+    assert_eq!(
+        render::select(&Sqlite, &qir)?.sql,
+        r#"SELECT * FROM "t" WHERE 1 = 1"#
+    );
+
+    let mut qir = rql! {
+        SELECT * FROM t WHERE NOT (a in (1));
+    };
+
+    match &mut qir.cond.as_mut().expect("cond").lhs {
+        dml::CondParam::CondExpr(inner) => inner.rhs = dml::CondParam::VariantList(vec![]),
+        param => panic!("Expect cond expr, got {:?}", param),
+    }
+
+    // `NOT` of an always false predicate must match every row.
+    assert_eq!(
+        render::select(&Sqlite, &qir)?.sql,
+        r#"SELECT * FROM "t" WHERE NOT (1 = 0)"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_subquery() -> anyhow::Result<()> {
+    let qir = rql! {
+        SELECT * FROM (SELECT id FROM card WHERE id > 1) AS c WHERE c.id in (SELECT card_id FROM tag WHERE name = "x") AND EXISTS (SELECT * FROM tag WHERE tag.card_id = c.id AND tag.id < 2);
+    };
+
+    let stmt = render::select(&Postgres, &qir)?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"SELECT * FROM (SELECT "id" FROM "card" WHERE "id" > $1) AS "c" WHERE "c"."id" IN (SELECT "card_id" FROM "tag" WHERE "name" = $2) AND EXISTS (SELECT * FROM "tag" WHERE "tag"."card_id" = "c"."id" AND "tag"."id" < $3)"#
+    );
+
+    assert_eq!(stmt.params, vec![1.into(), "x".into(), 2.into()]);
+
+    Ok(())
+}
+
+#[test]
+fn test_quote_ident() -> anyhow::Result<()> {
+    let table_name = "my\"table";
+
+    let qir = rql! {
+        SELECT * FROM #table_name;
+    };
+
+    assert_eq!(
+        render::select(&Sqlite, &qir)?.sql,
+        r#"SELECT * FROM "my""table""#
+    );
+
+    let table_name = "my`table";
+
+    let qir = rql! {
+        SELECT * FROM #table_name;
+    };
+
+    assert_eq!(
+        render::select(&MySql, &qir)?.sql,
+        "SELECT * FROM `my``table`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_insert() -> anyhow::Result<()> {
+    let qir = rql! {
+        INSERT INTO user_table(name,content)
+    };
+
+    let stmt = render::insert(&Postgres, &qir, vec!["hello".into(), "world".into()])?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"INSERT INTO "user_table" ("name", "content") VALUES ($1, $2)"#
+    );
+
+    assert_eq!(stmt.params, vec!["hello".into(), "world".into()]);
+
+    assert!(render::insert(&Postgres, &qir, vec!["hello".into()]).is_err());
+
+    let stmt = render::insert_many(
+        &MySql,
+        &qir,
+        vec![
+            vec!["a".into(), "b".into()],
+            vec!["c".into(), Variant::Null],
+        ],
+    )?;
+
+    assert_eq!(
+        stmt.sql,
+        "INSERT INTO `user_table` (`name`, `content`) VALUES (?, ?), (?, ?)"
+    );
+
+    assert_eq!(
+        stmt.params,
+        vec!["a".into(), "b".into(), "c".into(), Variant::Null]
+    );
+
+    assert!(render::insert_many(&MySql, &qir, vec![vec!["a".into(), "b".into()], vec![]]).is_err());
+    assert!(render::insert_many(&MySql, &qir, vec![]).is_err());
+
+    let cols: &[&str] = &[];
+
+    let qir = rql! {
+        INSERT INTO user_table #(cols)*
+    };
+
+    assert_eq!(
+        render::insert(&Sqlite, &qir, vec![])?.sql,
+        r#"INSERT INTO "user_table" DEFAULT VALUES"#
+    );
+
+    assert_eq!(
+        render::insert(&MySql, &qir, vec![])?.sql,
+        "INSERT INTO `user_table` () VALUES ()"
+    );
+
+    assert!(render::insert_many(&MySql, &qir, vec![vec![], vec![]]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_upsert() -> anyhow::Result<()> {
+    let values = || vec![1.into(), "hello".into(), "world".into()];
+
+    let qir = rql! {
+        INSERT INTO user_table(id,name,content) ON CONFLICT (id) DO UPDATE SET (name,content)
+    };
+
+    assert_eq!(
+        render::insert(&Postgres, &qir, values())?.sql,
+        r#"INSERT INTO "user_table" ("id", "name", "content") VALUES ($1, $2, $3) ON CONFLICT ("id") DO UPDATE SET "name" = excluded."name", "content" = excluded."content""#
+    );
+
+    assert_eq!(
+        render::insert(&MySql, &qir, values())?.sql,
+        "INSERT INTO `user_table` (`id`, `name`, `content`) VALUES (?, ?, ?) AS `new` ON DUPLICATE KEY UPDATE `name` = `new`.`name`, `content` = `new`.`content`"
+    );
+
+    let qir = rql! {
+        INSERT INTO user_table(id,name,content) ON CONFLICT (id) DO NOTHING
+    };
+
+    assert_eq!(
+        render::insert(&Sqlite, &qir, values())?.sql,
+        r#"INSERT INTO "user_table" ("id", "name", "content") VALUES (?, ?, ?) ON CONFLICT ("id") DO NOTHING"#
+    );
+
+    assert_eq!(
+        render::insert(&MySql, &qir, values())?.sql,
+        "INSERT INTO `user_table` (`id`, `name`, `content`) VALUES (?, ?, ?) ON DUPLICATE KEY UPDATE `id` = `id`"
+    );
+
+    let cols: &[&str] = &[];
+
+    let qir = rql! {
+        INSERT INTO user_table(id,name,content) ON CONFLICT (id) DO UPDATE SET #(cols)*
+    };
+
+    assert!(render::insert(&Postgres, &qir, values()).is_err());
+
+    let target: &[&str] = &[];
+
+    let qir = rql! {
+        INSERT INTO user_table(id,name,content) ON CONFLICT #(target)* DO NOTHING
+    };
+
+    assert_eq!(
+        render::insert(&Sqlite, &qir, values())?.sql,
+        r#"INSERT INTO "user_table" ("id", "name", "content") VALUES (?, ?, ?) ON CONFLICT DO NOTHING"#
+    );
+
+    let qir = rql! {
+        INSERT INTO user_table(id,name,content) ON CONFLICT #(target)* DO UPDATE SET (name)
+    };
+
+    assert!(render::insert(&Postgres, &qir, values()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_returning() -> anyhow::Result<()> {
+    let qir = rql! {
+        INSERT INTO user_table(name) RETURNING (id,name)
+    };
+
+    assert_eq!(
+        render::insert(&Postgres, &qir, vec!["hello".into()])?.sql,
+        r#"INSERT INTO "user_table" ("name") VALUES ($1) RETURNING "id", "name""#
+    );
+
+    assert!(render::insert(&MySql, &qir, vec!["hello".into()]).is_err());
+
+    let qir = rql! {
+        UPDATE user_table(name) WHERE id = 1 RETURNING (id)
+    };
+
+    assert_eq!(
+        render::update(&Sqlite, &qir, vec!["hello".into()])?.sql,
+        r#"UPDATE "user_table" SET "name" = ? WHERE "id" = ? RETURNING "id""#
+    );
+
+    let qir = rql! {
+        DELETE FROM user_table WHERE id = 1 RETURNING (name)
+    };
+
+    assert_eq!(
+        render::delete(&Postgres, &qir)?.sql,
+        r#"DELETE FROM "user_table" WHERE "id" = $1 RETURNING "name""#
+    );
+
+    let cols: &[&str] = &[];
+
+    let qir = rql! {
+        DELETE FROM user_table WHERE id = 1 RETURNING #(cols)*
+    };
+
+    assert!(render::delete(&Postgres, &qir).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_update() -> anyhow::Result<()> {
+    let qir = rql! {
+        UPDATE user_table(name,content) WHERE id = 1
+    };
+
+    let stmt = render::update(&Postgres, &qir, vec!["hello".into(), "world".into()])?;
+
+    assert_eq!(
+        stmt.sql,
+        r#"UPDATE "user_table" SET "name" = $1, "content" = $2 WHERE "id" = $3"#
+    );
+
+    assert_eq!(stmt.params, vec!["hello".into(), "world".into(), 1.into()]);
+
+    Ok(())
+}
+
+#[test]
+fn test_delete() -> anyhow::Result<()> {
+    let qir = rql! {
+        DELETE FROM user_table WHERE id = 1
+    };
+
+    let stmt = render::delete(&MySql, &qir)?;
+
+    assert_eq!(stmt.sql, "DELETE FROM `user_table` WHERE `id` = ?");
+
+    assert_eq!(stmt.params, vec![1.into()]);
+
+    Ok(())
+}
+
+#[test]
+fn test_bool() -> anyhow::Result<()> {
+    let qirs = ddl! {
+        CREATE TABLE flag(
+            id INT PRIMARY,
+            active BOOL NOT NULL false,
+        );
+    };
+
+    assert_eq!(
+        sql(render::ddl(&Postgres, &qirs[0])?),
+        vec![
+            r#"CREATE TABLE "flag" ("id" INTEGER PRIMARY KEY, "active" BOOLEAN NOT NULL DEFAULT FALSE)"#,
+        ]
+    );
+
+    let qir = rql! {
+        SELECT id FROM flag WHERE active = true;
+    };
+
+    let stmt = render::select(&Sqlite, &qir)?;
+
+    assert_eq!(stmt.sql, r#"SELECT "id" FROM "flag" WHERE "active" = ?"#);
+    assert_eq!(stmt.params, vec![true.into()]);
+
+    Ok(())
+}
+
+#[test]
+fn test_json() -> anyhow::Result<()> {
+    let qirs = ddl! {
+        CREATE TABLE profile(id INT PRIMARY, settings JSON NOT NULL);
+    };
+
+    assert_eq!(
+        sql(render::ddl(&Postgres, &qirs[0])?),
+        vec![r#"CREATE TABLE "profile" ("id" INTEGER PRIMARY KEY, "settings" JSONB NOT NULL)"#]
+    );
+
+    assert_eq!(
+        sql(render::ddl(&MySql, &qirs[0])?),
+        vec!["CREATE TABLE `profile` (`id` INT PRIMARY KEY, `settings` JSON NOT NULL)"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_uuid() -> anyhow::Result<()> {
+    let qirs = ddl! {
+        CREATE TABLE device(id UUID PRIMARY, name STRING);
+    };
+
+    assert_eq!(
+        sql(render::ddl(&Postgres, &qirs[0])?),
+        vec![r#"CREATE TABLE "device" ("id" UUID PRIMARY KEY, "name" TEXT)"#]
+    );
+
+    assert_eq!(
+        sql(render::ddl(&MySql, &qirs[0])?),
+        vec!["CREATE TABLE `device` (`id` BINARY(16) PRIMARY KEY, `name` VARCHAR(255))"]
+    );
+
+    let id = Variant::Uuid([0xab; 16]);
+
+    assert_eq!(
+        Postgres.literal(&id)?,
+        "'abababab-abab-abab-abab-abababababab'::uuid"
+    );
+    assert_eq!(Sqlite.literal(&id)?, format!("X'{}'", "AB".repeat(16)));
+
+    Ok(())
+}
+
+#[test]
+fn test_date_time() -> anyhow::Result<()> {
+    let qirs = ddl! {
+        CREATE TABLE event(day DATE, at TIME, local_time TIMESTAMP, happened DATETIME, duration INTERVAL);
+    };
+
+    assert_eq!(
+        sql(render::ddl(&Postgres, &qirs[0])?),
+        vec![
+            r#"CREATE TABLE "event" ("day" DATE, "at" TIME, "local_time" TIMESTAMP, "happened" TIMESTAMPTZ, "duration" INTERVAL)"#
+        ]
+    );
+
+    assert_eq!(
+        sql(render::ddl(&MySql, &qirs[0])?),
+        vec![
+            "CREATE TABLE `event` (`day` DATE, `at` TIME(6), `local_time` DATETIME(6), `happened` DATETIME(6), `duration` BIGINT)"
+        ]
+    );
+
+    let duration = Variant::Interval(Interval::milliseconds(1500));
+
+    assert_eq!(Sqlite.literal(&duration)?, "1500000");
+    assert_eq!(
+        Postgres.literal(&duration)?,
+        "'1500000 microseconds'::interval"
+    );
+    assert_eq!(
+        Sqlite.literal(&Variant::Timestamp("2022-12-01T08:00:00".parse()?))?,
+        "'2022-12-01T08:00:00'"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_create_table() -> anyhow::Result<()> {
+    let qirs = ddl! {
+        CREATE TABLE User(
+            id INT PRIMARY AUTOINC,
+            name STRING NOT NULL "anonymous",
+            avatar BYTES,
+            date DATETIME,
+            CONSTRAINT name_unique UNIQUE(name),
+            CONSTRAINT date_index INDEX(date),
+        );
+    };
+
+    assert_eq!(
+        sql(render::ddl(&Sqlite, &qirs[0])?),
+        vec![
+            r#"CREATE TABLE "User" ("id" INTEGER PRIMARY KEY AUTOINCREMENT, "name" TEXT NOT NULL DEFAULT 'anonymous', "avatar" BLOB, "date" DATETIME, CONSTRAINT "name_unique" UNIQUE ("name"))"#,
+            r#"CREATE INDEX "date_index" ON "User" ("date")"#,
+        ]
+    );
+
+    assert_eq!(
+        sql(render::ddl(&Postgres, &qirs[0])?),
+        vec![
+            r#"CREATE TABLE "User" ("id" INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY, "name" TEXT NOT NULL DEFAULT 'anonymous', "avatar" BYTEA, "date" TIMESTAMPTZ, CONSTRAINT "name_unique" UNIQUE ("name"))"#,
+            r#"CREATE INDEX "date_index" ON "User" ("date")"#,
+        ]
+    );
+
+    assert_eq!(
+        sql(render::ddl(&MySql, &qirs[0])?),
+        vec![
+            "CREATE TABLE `User` (`id` INT AUTO_INCREMENT PRIMARY KEY, `name` VARCHAR(255) NOT NULL DEFAULT 'anonymous', `avatar` LONGBLOB, `date` DATETIME(6), CONSTRAINT `name_unique` UNIQUE (`name`))",
+            "CREATE INDEX `date_index` ON `User` (`date`)",
+        ]
+    );
+
+    let qirs = ddl! {
+        CREATE TABLE Card(
+            id INT PRIMARY,
+            user_id INT,
+            CONSTRAINT user_id_foreign_key FOREIGN KEY (user_id) REFERENCES User(id),
+        );
+    };
+
+    assert_eq!(
+        sql(render::ddl(&Postgres, &qirs[0])?),
+        vec![
+            r#"CREATE TABLE "Card" ("id" INTEGER PRIMARY KEY, "user_id" INTEGER, CONSTRAINT "user_id_foreign_key" FOREIGN KEY ("user_id") REFERENCES "User" ("id"))"#,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_alter_table() -> anyhow::Result<()> {
+    let qirs = ddl! {
+        ALTER TABLE Card ALTER COLUMN user_id BIGINT NOT NULL;
+
+        ALTER TABLE Card ADD COLUMN card_no INT;
+
+        ALTER TABLE Card RENAME COLUMN card_no TO no;
+
+        ALTER TABLE Card DROP COLUMN no;
+
+        ALTER TABLE Card ADD CONSTRAINT date_index INDEX(date);
+
+        ALTER TABLE Card ALTER CONSTRAINT no_unique UNIQUE(no);
+
+        ALTER TABLE Card RENAME CONSTRAINT date_index TO date_unique;
+
+        ALTER TABLE Card DROP CONSTRAINT date_unique;
+
+        ALTER TABLE Card RENAME TABLE TO UserCard;
+
+        DROP TABLE Card;
+
+        TRUNCATE TABLE Card;
+    };
+
+    let mut stmts = vec![];
+
+    for qir in &qirs {
+        stmts.append(&mut sql(render::ddl(&Postgres, qir)?));
+    }
+
+    assert_eq!(
+        stmts,
+        vec![
+            r#"ALTER TABLE "Card" ALTER COLUMN "user_id" TYPE BIGINT, ALTER COLUMN "user_id" SET NOT NULL, ALTER COLUMN "user_id" DROP DEFAULT"#,
+            r#"ALTER TABLE "Card" ADD COLUMN "card_no" INTEGER"#,
+            r#"ALTER TABLE "Card" RENAME COLUMN "card_no" TO "no""#,
+            r#"ALTER TABLE "Card" DROP COLUMN "no""#,
+            r#"CREATE INDEX "date_index" ON "Card" ("date")"#,
+            r#"ALTER TABLE "Card" DROP CONSTRAINT "no_unique""#,
+            r#"ALTER TABLE "Card" ADD CONSTRAINT "no_unique" UNIQUE ("no")"#,
+            r#"ALTER TABLE "Card" RENAME CONSTRAINT "date_index" TO "date_unique""#,
+            r#"ALTER TABLE "Card" DROP CONSTRAINT "date_unique""#,
+            r#"ALTER TABLE "Card" RENAME TO "UserCard""#,
+            r#"DROP TABLE "Card""#,
+            r#"TRUNCATE TABLE "Card""#,
+        ]
+    );
+
+    assert_eq!(
+        sql(render::ddl(&MySql, &qirs[0])?),
+        vec!["ALTER TABLE `Card` MODIFY COLUMN `user_id` BIGINT NOT NULL"]
+    );
+
+    assert_eq!(
+        sql(render::ddl(&Sqlite, &qirs[10])?),
+        vec![r#"DELETE FROM "Card""#]
+    );
+
+    // SQLite can't alter column or table constraints.
+    assert!(render::ddl(&Sqlite, &qirs[0]).is_err());
+    assert!(render::ddl(&Sqlite, &qirs[5]).is_err());
+
+    let qir = ddl! {
+        ALTER TABLE Card ALTER CONSTRAINT date_index INDEX(date, user_id);
+    };
+
+    assert_eq!(
+        sql(render::ddl(&Sqlite, &qir[0])?),
+        vec![
+            r#"DROP INDEX "date_index""#,
+            r#"CREATE INDEX "date_index" ON "Card" ("date", "user_id")"#,
+        ]
+    );
+
+    assert_eq!(
+        sql(render::ddl(&MySql, &qir[0])?),
+        vec![
+            "DROP INDEX `date_index` ON `Card`",
+            "CREATE INDEX `date_index` ON `Card` (`date`, `user_id`)",
+        ]
+    );
+
+    Ok(())
+}
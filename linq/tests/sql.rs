@@ -55,20 +55,20 @@ async fn test_cond() {
             op: CondOp::And,
             lhs: CondParam::CondExpr(Box::new(CondExpr {
                 op: CondOp::NotEq,
-                lhs: CondParam::Variant("id".into()),
-                rhs: CondParam::Variant(100.into()),
+                lhs: CondParam::Column("id".into()),
+                rhs: CondParam::Param(100.into()),
             })),
             rhs: CondParam::CondExpr(Box::new(CondExpr {
                 op: CondOp::Or,
                 lhs: CondParam::CondExpr(Box::new(CondExpr {
                     op: CondOp::Eq,
-                    lhs: CondParam::Variant("name".into()),
-                    rhs: CondParam::Variant("hello".into()),
+                    lhs: CondParam::Column("name".into()),
+                    rhs: CondParam::Param("hello".into()),
                 })),
                 rhs: CondParam::CondExpr(Box::new(CondExpr {
                     op: CondOp::Eq,
-                    lhs: CondParam::Variant("name".into()),
-                    rhs: CondParam::Variant("world".into()),
+                    lhs: CondParam::Column("name".into()),
+                    rhs: CondParam::Param("world".into()),
                 })),
             }))
         })
@@ -82,8 +82,8 @@ async fn test_cond() {
         qir.cond,
         Some(CondExpr {
             op: CondOp::Gte,
-            lhs: CondParam::Variant("id".into()),
-            rhs: CondParam::Variant(100.into()),
+            lhs: CondParam::Column("id".into()),
+            rhs: CondParam::Param(100.into()),
         })
     );
 
@@ -95,8 +95,8 @@ async fn test_cond() {
         qir.cond,
         Some(CondExpr {
             op: CondOp::Lte,
-            lhs: CondParam::Variant("id".into()),
-            rhs: CondParam::Variant(100.into()),
+            lhs: CondParam::Column("id".into()),
+            rhs: CondParam::Param(100.into()),
         })
     );
 
@@ -108,8 +108,8 @@ async fn test_cond() {
         qir.cond,
         Some(CondExpr {
             op: CondOp::Gt,
-            lhs: CondParam::Variant("id".into()),
-            rhs: CondParam::Variant(100.into()),
+            lhs: CondParam::Column("id".into()),
+            rhs: CondParam::Param(100.into()),
         })
     );
 
@@ -121,8 +121,8 @@ async fn test_cond() {
         qir.cond,
         Some(CondExpr {
             op: CondOp::Lt,
-            lhs: CondParam::Variant("id".into()),
-            rhs: CondParam::Variant(100.into()),
+            lhs: CondParam::Column("id".into()),
+            rhs: CondParam::Param(100.into()),
         })
     );
 
@@ -134,7 +134,7 @@ async fn test_cond() {
         qir.cond,
         Some(CondExpr {
             op: CondOp::In,
-            lhs: CondParam::Variant("id".into()),
+            lhs: CondParam::Column("id".into()),
             rhs: CondParam::VariantList(vec![100.into(), 200.into(), 300.into()]),
         })
     );
@@ -147,8 +147,98 @@ async fn test_cond() {
         qir.cond,
         Some(CondExpr {
             op: CondOp::Like,
-            lhs: CondParam::Variant("name".into()),
-            rhs: CondParam::Variant("%hello%".into()),
+            lhs: CondParam::Column("name".into()),
+            rhs: CondParam::Param("%hello%".into()),
+        })
+    );
+}
+
+#[test]
+fn test_cond_column() {
+    let name = "name";
+
+    let qir = rql! {
+        SELECT * FROM table WHERE name = "name" AND name = #name AND name = nick;
+    };
+
+    let expect = |rhs| {
+        CondParam::CondExpr(Box::new(CondExpr {
+            op: CondOp::Eq,
+            lhs: CondParam::Column("name".into()),
+            rhs,
+        }))
+    };
+
+    assert_eq!(
+        qir.cond,
+        Some(CondExpr {
+            op: CondOp::And,
+            lhs: expect(CondParam::Param("name".into())),
+            rhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::And,
+                lhs: expect(CondParam::Param("name".into())),
+                rhs: expect(CondParam::Column("nick".into())),
+            })),
+        })
+    );
+}
+
+#[test]
+fn test_cond_ops() {
+    let qir = rql! {
+        SELECT * FROM table WHERE NOT (deleted_at IS NULL OR age NOT BETWEEN 18 AND 65) AND id NOT in (1, 2);
+    };
+
+    assert_eq!(
+        qir.cond,
+        Some(CondExpr {
+            op: CondOp::And,
+            lhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::Not,
+                lhs: CondParam::CondExpr(Box::new(CondExpr {
+                    op: CondOp::Or,
+                    lhs: CondParam::CondExpr(Box::new(CondExpr {
+                        op: CondOp::IsNull,
+                        lhs: CondParam::Column("deleted_at".into()),
+                        rhs: CondParam::Empty,
+                    })),
+                    rhs: CondParam::CondExpr(Box::new(CondExpr {
+                        op: CondOp::NotBetween,
+                        lhs: CondParam::Column("age".into()),
+                        rhs: CondParam::Range(
+                            Box::new(CondParam::Param(18.into())),
+                            Box::new(CondParam::Param(65.into()))
+                        ),
+                    })),
+                })),
+                rhs: CondParam::Empty,
+            })),
+            rhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::NotIn,
+                lhs: CondParam::Column("id".into()),
+                rhs: CondParam::VariantList(vec![1.into(), 2.into()]),
+            })),
+        })
+    );
+
+    let qir = rql! {
+        SELECT * FROM table WHERE name NOT LIKE "a%" AND memo IS NOT NULL;
+    };
+
+    assert_eq!(
+        qir.cond,
+        Some(CondExpr {
+            op: CondOp::And,
+            lhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::NotLike,
+                lhs: CondParam::Column("name".into()),
+                rhs: CondParam::Param("a%".into()),
+            })),
+            rhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::IsNotNull,
+                lhs: CondParam::Column("memo".into()),
+                rhs: CondParam::Empty,
+            })),
         })
     );
 }
@@ -181,10 +271,12 @@ fn test_order() {
 
     assert_eq!(
         qir.order_by,
-        Some(OrderBy {
+        vec![OrderBy {
+            table: None,
             col_name: "hello",
-            desc: false
-        })
+            desc: false,
+            nulls: None,
+        }]
     );
 
     let qir = rql! {
@@ -193,10 +285,12 @@ fn test_order() {
 
     assert_eq!(
         qir.order_by,
-        Some(OrderBy {
+        vec![OrderBy {
+            table: None,
             col_name: "hello",
-            desc: false
-        })
+            desc: false,
+            nulls: None,
+        }]
     );
 
     let qir = rql! {
@@ -205,10 +299,12 @@ fn test_order() {
 
     assert_eq!(
         qir.order_by,
-        Some(OrderBy {
+        vec![OrderBy {
+            table: None,
             col_name: "hello",
-            desc: true
-        })
+            desc: true,
+            nulls: None,
+        }]
     );
 
     let desc = true;
@@ -219,13 +315,70 @@ fn test_order() {
 
     assert_eq!(
         qir.order_by,
-        Some(OrderBy {
+        vec![OrderBy {
+            table: None,
             col_name: "hello",
-            desc: true
+            desc: true,
+            nulls: None,
+        }]
+    );
+}
+
+#[test]
+fn test_bool_literal() {
+    let qir = rql! {
+        SELECT * FROM flag WHERE active = true AND archived != false;
+    };
+
+    assert_eq!(
+        qir.cond,
+        Some(CondExpr {
+            op: CondOp::And,
+            lhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::Eq,
+                lhs: CondParam::Column("active".into()),
+                rhs: CondParam::Param(true.into()),
+            })),
+            rhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::NotEq,
+                lhs: CondParam::Column("archived".into()),
+                rhs: CondParam::Param(false.into()),
+            })),
         })
     );
 }
 
+#[test]
+fn test_order_multi_terms() {
+    let qir = rql! {
+        SELECT * FROM user AS u ORDER BY u.age DESC NULLS LAST, name, id ASC NULLS FIRST;
+    };
+
+    assert_eq!(
+        qir.order_by,
+        vec![
+            OrderBy {
+                table: Some("u"),
+                col_name: "age",
+                desc: true,
+                nulls: Some(Nulls::Last),
+            },
+            OrderBy {
+                table: None,
+                col_name: "name",
+                desc: false,
+                nulls: None,
+            },
+            OrderBy {
+                table: None,
+                col_name: "id",
+                desc: false,
+                nulls: Some(Nulls::First),
+            },
+        ]
+    );
+}
+
 #[test]
 fn test_select_where_order_limits() {
     let cols = vec!["name", "id"];
@@ -253,17 +406,19 @@ fn test_select_where_order_limits() {
         qir.cond,
         Some(CondExpr {
             op: CondOp::Eq,
-            lhs: CondParam::Variant("id".into()),
-            rhs: CondParam::Variant(100.into()),
+            lhs: CondParam::Column("id".into()),
+            rhs: CondParam::Param(100.into()),
         })
     );
 
     assert_eq!(
         qir.order_by,
-        Some(OrderBy {
+        vec![OrderBy {
+            table: None,
             col_name: "name",
-            desc: true
-        })
+            desc: true,
+            nulls: None,
+        }]
     );
 }
 
@@ -296,6 +451,60 @@ fn test_insert() {
     assert_eq!(qir.table_name, "table");
 
     assert_eq!(qir.cols, vec!["name", "content"].into());
+
+    assert_eq!(qir.on_conflict, None);
+}
+
+#[test]
+fn test_upsert() {
+    let qir = rql! {
+        INSERT INTO table(id,name,content) ON CONFLICT (id) DO NOTHING
+    };
+
+    assert_eq!(
+        qir.on_conflict,
+        Some(OnConflict {
+            target: vec!["id"].into(),
+            action: ConflictAction::DoNothing,
+        })
+    );
+
+    let cols = &["name", "content"];
+
+    let qir = rql! {
+        INSERT INTO table(id,name,content) ON CONFLICT (id) DO UPDATE SET #(cols)*
+    };
+
+    assert_eq!(
+        qir.on_conflict,
+        Some(OnConflict {
+            target: vec!["id"].into(),
+            action: ConflictAction::DoUpdate(vec!["name", "content"].into()),
+        })
+    );
+}
+
+#[test]
+fn test_returning() {
+    let qir = rql! {
+        INSERT INTO table(name) RETURNING (id,name)
+    };
+
+    assert_eq!(qir.returning, Some(vec!["id", "name"].into()));
+
+    let cols = &["id"];
+
+    let qir = rql! {
+        UPDATE table(name) WHERE id = 1 RETURNING #(cols)*
+    };
+
+    assert_eq!(qir.returning, Some(vec!["id"].into()));
+
+    let qir = rql! {
+        DELETE FROM table WHERE id = 1
+    };
+
+    assert_eq!(qir.returning, None);
 }
 
 #[test]
@@ -342,8 +551,148 @@ fn test_delete() {
         qir.cond,
         CondExpr {
             op: CondOp::Eq,
-            lhs: CondParam::Variant("id".into()),
-            rhs: CondParam::Variant(1.into()),
+            lhs: CondParam::Column("id".into()),
+            rhs: CondParam::Param(1.into()),
+        }
+    );
+}
+
+#[test]
+fn test_join() {
+    let qir = rql! {
+        SELECT u.id, c.card_no AS no FROM user_table AS u LEFT OUTER JOIN card AS c ON u.id = c.user_id CROSS JOIN tag ORDER BY c.card_no DESC;
+    };
+
+    assert_eq!(
+        qir.cols,
+        SelectColumns::NamedColumns(vec!["u.id".into(), ("c.card_no", "no").into()])
+    );
+
+    assert_eq!(
+        qir.from,
+        SelectFrom {
+            table_name: "user_table",
+            aliase: Some("u"),
+            subquery: None,
+            joins: vec![
+                Join {
+                    kind: JoinKind::Left,
+                    table_name: "card",
+                    aliase: Some("c"),
+                    on: Some(CondExpr {
+                        op: CondOp::Eq,
+                        lhs: CondParam::Column(ColumnRef {
+                            table: Some("u".into()),
+                            name: "id".into(),
+                        }),
+                        rhs: CondParam::Column(ColumnRef {
+                            table: Some("c".into()),
+                            name: "user_id".into(),
+                        }),
+                    }),
+                },
+                Join {
+                    kind: JoinKind::Cross,
+                    table_name: "tag",
+                    aliase: None,
+                    on: None,
+                },
+            ],
+        }
+    );
+
+    assert_eq!(
+        qir.order_by,
+        vec![OrderBy {
+            table: Some("c"),
+            col_name: "card_no",
+            desc: true,
+            nulls: None,
+        }]
+    );
+}
+
+#[test]
+fn test_aggregate() {
+    let qir = rql! {
+        SELECT user_id, COUNT(*) AS n, MAX(c.card_no) FROM card AS c GROUP BY user_id, c.kind HAVING COUNT(*) > 1;
+    };
+
+    assert_eq!(
+        qir.cols,
+        SelectColumns::NamedColumns(vec![
+            "user_id".into(),
+            SelectNamedColumn::aggregate(AggregateFunc::Count, "*", Some("n")),
+            SelectNamedColumn {
+                table: Some("c"),
+                name: "card_no",
+                aliase: None,
+                func: Some(AggregateFunc::Max),
+            },
+        ])
+    );
+
+    assert_eq!(qir.group_by, vec!["user_id".into(), "c.kind".into()]);
+
+    assert_eq!(
+        qir.having,
+        Some(CondExpr {
+            op: CondOp::Gt,
+            lhs: CondParam::Aggregate(
+                AggregateFunc::Count,
+                ColumnRef {
+                    table: None,
+                    name: "*".into(),
+                }
+            ),
+            rhs: CondParam::Param(1.into()),
+        })
+    );
+}
+
+#[test]
+fn test_subquery() {
+    let qir = rql! {
+        SELECT * FROM card WHERE user_id in (SELECT id FROM user_table WHERE name = "a") AND NOT EXISTS (SELECT * FROM tag WHERE tag.card_id = card.id);
+    };
+
+    let mut users = rql! { SELECT id FROM user_table WHERE name = "a"; };
+    let tags = rql! { SELECT * FROM tag WHERE tag.card_id = card.id; };
+
+    assert_eq!(
+        qir.cond,
+        Some(CondExpr {
+            op: CondOp::And,
+            lhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::In,
+                lhs: CondParam::Column("user_id".into()),
+                rhs: CondParam::Subquery(Box::new(users.clone())),
+            })),
+            rhs: CondParam::CondExpr(Box::new(CondExpr {
+                op: CondOp::Not,
+                lhs: CondParam::CondExpr(Box::new(CondExpr {
+                    op: CondOp::Exists,
+                    lhs: CondParam::Subquery(Box::new(tags)),
+                    rhs: CondParam::Empty,
+                })),
+                rhs: CondParam::Empty,
+            })),
+        })
+    );
+
+    let qir = rql! {
+        SELECT u.name FROM (SELECT id, name FROM user_table WHERE name = "a") AS u;
+    };
+
+    users.cols = SelectColumns::NamedColumns(vec!["id".into(), "name".into()]);
+
+    assert_eq!(
+        qir.from,
+        SelectFrom {
+            table_name: "u",
+            aliase: None,
+            subquery: Some(Box::new(users)),
+            joins: vec![],
         }
     );
 }
@@ -0,0 +1,560 @@
+#![cfg(feature = "sqlite")]
+
+use linq_rs::{
+    driver::{
+        sqlite::SqliteDriver, DDLSupport, DeleteSupport, InsertSupport, QueryIterator,
+        SelectSupport, Transaction, TransactionSupport, UpdateSupport,
+    },
+    orm::{Order, Where},
+    *,
+};
+
+#[path = "utils/checks.rs"]
+mod checks;
+
+use checks::*;
+
+/// Note of a user, whose `TEXT` owner key decodes as a string.
+#[derive(ORM, Default, Debug, PartialEq)]
+struct Note {
+    #[primary]
+    id: i32,
+    user_id: String,
+    body: String,
+}
+
+#[derive(ORM, Default, Debug, PartialEq)]
+#[table_name("user_table")]
+struct UserNotes {
+    #[column("id_")]
+    #[primary(autoinc)]
+    id: Option<i32>,
+    first_name: String,
+    #[cascade(from=col_id to=col_user_id)]
+    notes: Vec<Note>,
+}
+
+async fn open() -> anyhow::Result<SqliteDriver> {
+    let mut d = SqliteDriver::open_in_memory()?;
+
+    create_tables(&mut d).await?;
+
+    Ok(d)
+}
+
+#[async_std::test]
+async fn test_rql() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let id = d
+        .insert(
+            &rql! { INSERT INTO user_table(first_name,last_name) },
+            vec!["hello".into(), "world".into()],
+        )
+        .await?;
+
+    assert_eq!(id.last_insert_id(), Some(1));
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT id_, first_name AS name FROM user_table WHERE last_name = "world";
+        })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, Variant::Int(1));
+    assert_eq!(rows.get_by_name("name").await?, "hello".into());
+    assert!(!rows.next().await?);
+
+    let updated = d
+        .update(
+            &rql! { UPDATE user_table(last_name) WHERE id_ = 1 },
+            vec!["rust".into()],
+        )
+        .await?;
+
+    assert_eq!(updated.affected_rows, 1);
+
+    let deleted = d
+        .delete(&rql! { DELETE FROM user_table WHERE last_name = "rust" })
+        .await?;
+
+    assert_eq!(deleted.affected_rows, 1);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_orm() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let created_time: DateTime = "2022-12-01T08:00:00Z".parse()?;
+
+    User {
+        first_name: "hello".into(),
+        last_name: "world".into(),
+        cards: vec![
+            Card {
+                id: 1,
+                card_no: "1001".into(),
+                ..Default::default()
+            },
+            Card {
+                id: 2,
+                card_no: "1002".into(),
+                ..Default::default()
+            },
+        ],
+        created_time: Some(created_time),
+        ..Default::default()
+    }
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    let users = Vec::<User>::select().exec(&mut d).await?;
+
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].id, Some(1));
+    assert_eq!(users[0].created_time, Some(created_time));
+    assert_eq!(users[0].cards.len(), 2);
+    assert_eq!(users[0].cards[1].user_id, 1);
+    assert_eq!(users[0].cards[1].card_no, "1002");
+
+    User {
+        id: Some(1),
+        first_name: "hello".into(),
+        last_name: "rust".into(),
+        ..Default::default()
+    }
+    .update()
+    .exec(&mut d)
+    .await?;
+
+    let user = User::select()
+        .cond(rql_where!(id_ = 1))
+        .exec(&mut d)
+        .await?
+        .expect("user 1");
+
+    assert_eq!(user.last_name, "rust");
+
+    let deleted = <Card as DeleteWhereCond>::delete()
+        .cond(rql_where!(user_id = 1))
+        .exec(&mut d)
+        .await?;
+
+    assert_eq!(deleted, 2);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_transaction() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let result = User {
+        first_name: "hello".into(),
+        last_name: "world".into(),
+        cards: vec![
+            Card {
+                id: 1,
+                card_no: "1001".into(),
+                ..Default::default()
+            },
+            Card {
+                id: 2,
+                card_no: "1001".into(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+    .insert()
+    .exec_tx(&mut d)
+    .await;
+
+    assert!(result.is_err());
+
+    let mut rows = d.select(&rql! { SELECT * FROM user_table; }).await?;
+
+    assert!(!rows.next().await?);
+
+    let qir = rql! { INSERT INTO user_table(first_name,last_name) };
+
+    {
+        let mut tx = Transaction::begin(&mut d).await?;
+
+        tx.insert(&qir, vec!["a".into(), "x".into()]).await?;
+
+        tx.savepoint("sp").await?;
+
+        tx.insert(&qir, vec!["b".into(), "x".into()]).await?;
+
+        tx.rollback_to_savepoint("sp").await?;
+
+        tx.release_savepoint("sp").await?;
+
+        tx.commit().await?;
+    }
+
+    {
+        let mut tx = Transaction::begin(&mut d).await?;
+
+        tx.insert(&qir, vec!["c".into(), "x".into()]).await?;
+    }
+
+    {
+        let mut tx = Transaction::begin(&mut d).await?;
+
+        tx.insert(&qir, vec!["d".into(), "x".into()]).await?;
+
+        // The nested cascade runs in a savepoint, only its own rows are rolled back.
+        let result = User {
+            first_name: "e".into(),
+            last_name: "x".into(),
+            cards: vec![
+                Card {
+                    id: 1,
+                    card_no: "1001".into(),
+                    ..Default::default()
+                },
+                Card {
+                    id: 1,
+                    card_no: "1002".into(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+        .insert()
+        .exec_tx(&mut *tx)
+        .await;
+
+        assert!(result.is_err());
+
+        tx.commit().await?;
+    }
+
+    let mut rows = d
+        .select(&rql! { SELECT first_name FROM user_table; })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, "a".into());
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, "d".into());
+    assert!(!rows.next().await?);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_join() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    d.insert(
+        &rql! { INSERT INTO user_table(first_name,last_name) },
+        vec!["hello".into(), "world".into()],
+    )
+    .await?;
+
+    let qir = rql! { INSERT INTO Card(id,user_id,card_no) };
+
+    d.insert(&qir, vec![1.into(), 1.into(), "1001".into()])
+        .await?;
+    d.insert(&qir, vec![2.into(), 2.into(), "1002".into()])
+        .await?;
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT u.first_name, c.card_no FROM user_table AS u RIGHT JOIN Card AS c ON u.id_ = c.user_id ORDER BY c.id;
+        })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, "hello".into());
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, Variant::Null);
+    assert_eq!(rows.get_by_name("card_no").await?, "1002".into());
+    assert!(!rows.next().await?);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_aggregate() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let qir = rql! { INSERT INTO Card(id,user_id,card_no) };
+
+    for (id, user_id) in [(1, 1), (2, 1), (3, 2)] {
+        d.insert(
+            &qir,
+            vec![id.into(), user_id.into(), format!("100{}", id).into()],
+        )
+        .await?;
+    }
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT user_id, COUNT(*) AS n, MAX(card_no) FROM Card GROUP BY user_id HAVING COUNT(*) > 1;
+        })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, 1.into());
+    assert_eq!(rows.get_by_name("n").await?, 2.into());
+    assert_eq!(rows.get(2).await?, "1002".into());
+    assert!(!rows.next().await?);
+
+    assert_eq!(Vec::<Card>::select().count(&mut d).await?, 3);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_subquery() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let qir = rql! { INSERT INTO user_table(first_name,last_name) };
+
+    for name in ["a", "b"] {
+        d.insert(&qir, vec![name.into(), "x".into()]).await?;
+    }
+
+    d.insert(
+        &rql! { INSERT INTO Card(id,user_id,card_no) },
+        vec![1.into(), 2.into(), "1001".into()],
+    )
+    .await?;
+
+    let mut rows = d
+        .select(&rql! {
+            SELECT first_name FROM user_table AS u WHERE EXISTS (SELECT * FROM Card WHERE Card.user_id = u.id_);
+        })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, "b".into());
+    assert!(!rows.next().await?);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_bool() -> anyhow::Result<()> {
+    let mut d = SqliteDriver::open_in_memory()?;
+
+    check_bool(&mut d).await
+}
+
+#[async_std::test]
+async fn test_numeric() -> anyhow::Result<()> {
+    let mut d = SqliteDriver::open_in_memory()?;
+
+    check_numeric(&mut d, i64::MAX as u64).await
+}
+
+#[async_std::test]
+async fn test_json() -> anyhow::Result<()> {
+    let mut d = SqliteDriver::open_in_memory()?;
+
+    check_json(&mut d).await
+}
+
+#[cfg(feature = "uuid")]
+#[async_std::test]
+async fn test_uuid() -> anyhow::Result<()> {
+    let mut d = SqliteDriver::open_in_memory()?;
+
+    let ddls = ddl! {
+        CREATE TABLE device(id UUID PRIMARY, name STRING NOT NULL);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let id = uuid::Uuid::new_v4();
+
+    d.insert(
+        &rql! { INSERT INTO device(id,name) },
+        vec![id.into(), "router".into()],
+    )
+    .await?;
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM device WHERE id = #id; })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, id.into());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_date_time() -> anyhow::Result<()> {
+    let mut d = SqliteDriver::open_in_memory()?;
+
+    check_date_time(&mut d, 0).await
+}
+
+#[async_std::test]
+async fn test_insert_many() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    check_insert_many(&mut d).await?;
+
+    // Explicit keys break rowid order, each row still gets its own key.
+    let ids = d
+        .insert_many(
+            &rql! { INSERT INTO user_table(id_,first_name,last_name) },
+            vec![
+                vec![100.into(), "c".into(), "x".into()],
+                vec![50.into(), "d".into(), "y".into()],
+            ],
+        )
+        .await?;
+
+    assert_eq!(ids.generated_keys, vec![100, 50]);
+
+    let err = d
+        .insert_many(
+            &rql! { INSERT INTO user_table(id_,first_name,last_name) },
+            vec![
+                vec![200.into(), "e".into(), "x".into()],
+                vec![100.into(), "f".into(), "y".into()],
+            ],
+        )
+        .await;
+
+    assert!(err.is_err());
+    assert!(User::select()
+        .cond(rql_where!(first_name = "e"))
+        .exec(&mut d)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_upsert() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    check_upsert(&mut d).await
+}
+
+#[async_std::test]
+async fn test_returning() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let result = d
+        .insert(
+            &rql! { INSERT INTO user_table(first_name,last_name) RETURNING (id_,first_name) },
+            vec!["a".into(), "x".into()],
+        )
+        .await?;
+
+    assert_eq!(result.affected_rows, 1);
+    assert_eq!(result.generated_keys, vec![1]);
+    assert_eq!(result.returning, vec![vec![1.into(), "a".into()]]);
+
+    let result = d
+        .update(
+            &rql! { UPDATE user_table(last_name) WHERE first_name = "a" RETURNING (id_,last_name) },
+            vec!["z".into()],
+        )
+        .await?;
+
+    assert_eq!(result.affected_rows, 1);
+    assert_eq!(result.returning, vec![vec![1.into(), "z".into()]]);
+
+    let result = d
+        .delete(&rql! { DELETE FROM user_table WHERE id_ = 1 RETURNING (first_name) })
+        .await?;
+
+    assert_eq!(result.affected_rows, 1);
+    assert_eq!(result.returning, vec![vec!["a".into()]]);
+
+    // Generated keys are written back to inserted objects.
+    let user = User {
+        first_name: "hello".into(),
+        last_name: "world".into(),
+        cards: vec![Card {
+            id: 1,
+            card_no: "1001".into(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    assert_eq!(user.id, Some(2));
+    assert_eq!(user.cards[0].user_id, 2);
+
+    let users = vec![
+        User {
+            first_name: "b".into(),
+            last_name: "y".into(),
+            ..Default::default()
+        },
+        User {
+            first_name: "c".into(),
+            last_name: "y".into(),
+            ..Default::default()
+        },
+    ]
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    assert_eq!(
+        users.iter().map(|user| user.id).collect::<Vec<_>>(),
+        vec![Some(3), Some(4)]
+    );
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_cascade_key_variants() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    let ddls = ddl! {
+        CREATE TABLE Note(id INT PRIMARY, user_id STRING NOT NULL, body STRING NOT NULL);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let qir = rql! { INSERT INTO user_table(first_name,last_name) };
+
+    for name in ["a", "b"] {
+        d.insert(&qir, vec![name.into(), "x".into()]).await?;
+    }
+
+    let qir = rql! { INSERT INTO Note(id,user_id,body) };
+
+    for (id, user_id) in [(1, "1"), (2, "2"), (3, "1")] {
+        d.insert(&qir, vec![id.into(), user_id.into(), "note".into()])
+            .await?;
+    }
+
+    // `INT` user ids match the `TEXT` note keys.
+    let users = Vec::<UserNotes>::select()
+        .order_by("id_", false)
+        .exec(&mut d)
+        .await?;
+
+    assert_eq!(
+        users
+            .iter()
+            .map(|user| user.notes.iter().map(|note| note.id).collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+        vec![vec![1, 3], vec![2]]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,535 @@
+//! Driver-generic checks shared by the driver test suites, each one runs
+//! against the driver passed in by the suite.
+
+use linq_rs::{
+    driver::{
+        DDLSupport, DeleteSupport, InsertSupport, QueryIterator, SelectSupport, UpdateSupport,
+    },
+    orm::{Order, Where},
+    *,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Driver under check, implementing every support trait.
+pub trait Driver:
+    DDLSupport
+    + InsertSupport<'static>
+    + SelectSupport<'static>
+    + UpdateSupport<'static>
+    + DeleteSupport<'static>
+    + Sync
+    + Send
+{
+}
+
+impl<D> Driver for D where
+    D: DDLSupport
+        + InsertSupport<'static>
+        + SelectSupport<'static>
+        + UpdateSupport<'static>
+        + DeleteSupport<'static>
+        + Sync
+        + Send
+{
+}
+
+#[derive(ORM, Serialize, Deserialize, Default, Debug, PartialEq)]
+#[table_name("user_table")]
+pub struct User {
+    #[column("id_")]
+    #[primary(autoinc)]
+    pub id: Option<i32>,
+    pub first_name: String,
+    pub last_name: String,
+    #[cascade(from=col_id to=col_user_id)]
+    pub cards: Vec<Card>,
+    pub created_time: Option<DateTime>,
+}
+
+#[derive(ORM, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Card {
+    #[primary]
+    pub id: usize,
+    pub user_id: usize,
+    pub card_no: String,
+}
+
+#[derive(ORM, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Flag {
+    #[primary]
+    pub id: i32,
+    pub active: bool,
+    pub archived: Option<bool>,
+}
+
+#[derive(ORM, Default, Debug, PartialEq)]
+pub struct Amount {
+    #[primary]
+    pub id: u64,
+    pub price: f64,
+    pub ratio: Option<f32>,
+    pub total: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Settings {
+    pub theme: String,
+    pub font_size: u32,
+}
+
+#[derive(ORM, Default, Debug, PartialEq)]
+pub struct Profile {
+    #[primary]
+    pub id: i32,
+    #[column(json)]
+    pub settings: Settings,
+    #[column("extra_tags", json)]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(ORM, Default, Debug, PartialEq)]
+pub struct Event {
+    #[primary]
+    pub id: i32,
+    pub day: Option<Date>,
+    pub at: Option<Time>,
+    pub local_time: Option<Timestamp>,
+    pub happened: Option<DateTime>,
+    pub duration: Option<Interval>,
+}
+
+/// Create `user_table` and `Card` tables of [`User`] and [`Card`].
+pub async fn create_tables<D: Driver>(d: &mut D) -> anyhow::Result<()> {
+    let ddls = ddl! {
+        CREATE TABLE user_table(
+            id_ INT PRIMARY AUTOINC,
+            first_name STRING NOT NULL,
+            last_name STRING NOT NULL,
+            created_time DATETIME,
+            CONSTRAINT first_name_index INDEX(first_name),
+        );
+
+        CREATE TABLE Card(
+            id INT PRIMARY,
+            user_id INT NOT NULL,
+            card_no STRING NOT NULL,
+            CONSTRAINT card_no_unique UNIQUE(card_no),
+        );
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    Ok(())
+}
+
+/// Bool columns, with defaults and nulls.
+pub async fn check_bool<D: Driver>(d: &mut D) -> anyhow::Result<()> {
+    let ddls = ddl! {
+        CREATE TABLE Flag(id INT PRIMARY, active BOOL NOT NULL false, archived BOOL);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    Flag {
+        id: 1,
+        active: true,
+        archived: Some(false),
+    }
+    .insert()
+    .exec(d)
+    .await?;
+
+    d.insert(&rql! { INSERT INTO Flag(id) }, vec![2.into()])
+        .await?;
+
+    let flags = Vec::<Flag>::select()
+        .cond(rql_where!(active = true))
+        .exec(d)
+        .await?;
+
+    assert_eq!(
+        flags,
+        vec![Flag {
+            id: 1,
+            active: true,
+            archived: Some(false),
+        }]
+    );
+
+    let flag = Flag::select()
+        .cond(rql_where!(id = 2))
+        .exec(d)
+        .await?
+        .expect("flag 2");
+
+    assert!(!flag.active);
+    assert_eq!(flag.archived, None);
+
+    Ok(())
+}
+
+/// Float and decimal columns, `max_id` is the largest `BIGINT` the driver stores.
+pub async fn check_numeric<D: Driver>(d: &mut D, max_id: u64) -> anyhow::Result<()> {
+    let ddls = ddl! {
+        CREATE TABLE Amount(id BIGINT PRIMARY, price FLOAT NOT NULL, ratio FLOAT, total DECIMAL NOT NULL);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let amount = Amount {
+        id: max_id,
+        price: 9.5,
+        ratio: Some(0.5),
+        total: Decimal::new(10.into(), 3.into()),
+    };
+
+    Amount {
+        total: amount.total.clone(),
+        ..amount
+    }
+    .insert()
+    .exec(d)
+    .await?;
+
+    // Integers widen into float and decimal columns.
+    d.insert(
+        &rql! { INSERT INTO Amount(id,price,total) },
+        vec![2.into(), 3.into(), 4.into()],
+    )
+    .await?;
+
+    let amounts = Vec::<Amount>::select()
+        .order_by("id", false)
+        .exec(d)
+        .await?;
+
+    assert_eq!(
+        amounts,
+        vec![
+            Amount {
+                id: 2,
+                price: 3.0,
+                ratio: None,
+                total: Decimal::from_integer(4.into()),
+            },
+            amount,
+        ]
+    );
+
+    // Floats convert through their shortest decimal string, big decimals keep every digit.
+    let qir = rql! { INSERT INTO Amount(id,price,ratio,total) };
+
+    let exact: Decimal = "123456789012345678901234567/1000".parse()?;
+    let integral: Decimal = "100000000000000000000000001".parse()?;
+
+    for (id, total) in [
+        (3, 0.1.into()),
+        (4, exact.clone().into()),
+        (5, integral.clone().into()),
+    ] {
+        d.insert(&qir, vec![id.into(), 0.5.into(), Variant::Null, total])
+            .await?;
+    }
+
+    let totals = Vec::<Amount>::select()
+        .cond(rql_where!(id > 2 AND id < 100))
+        .order_by("id", false)
+        .exec(d)
+        .await?
+        .into_iter()
+        .map(|amount| amount.total)
+        .collect::<Vec<_>>();
+
+    assert_eq!(totals, [Decimal::new(1.into(), 10.into()), exact, integral]);
+
+    // Narrowing a float into `f32` must not round.
+    d.insert(&qir, vec![6.into(), 0.5.into(), 0.1.into(), 1.into()])
+        .await?;
+
+    assert!(Amount::select()
+        .cond(rql_where!(id = 6))
+        .exec(d)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+/// Json columns of serde types.
+pub async fn check_json<D: Driver>(d: &mut D) -> anyhow::Result<()> {
+    let ddls = ddl! {
+        CREATE TABLE Profile(id INT PRIMARY, settings JSON NOT NULL, extra_tags JSON);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    for (id, tags) in [(1, Some(vec!["a".to_owned()])), (2, None)] {
+        Profile {
+            id,
+            settings: Settings {
+                theme: "dark".into(),
+                font_size: 12,
+            },
+            tags,
+        }
+        .insert()
+        .exec(d)
+        .await?;
+    }
+
+    let profiles = Vec::<Profile>::select()
+        .order_by("id", false)
+        .exec(d)
+        .await?;
+
+    assert_eq!(profiles.len(), 2);
+    assert_eq!(profiles[0].settings.theme, "dark");
+    assert_eq!(profiles[0].tags, Some(vec!["a".to_owned()]));
+    assert_eq!(profiles[1].tags, None);
+
+    let mut rows = d
+        .select(&rql! { SELECT settings FROM Profile WHERE id = 1; })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(
+        rows.get(0).await?,
+        Variant::Json(serde_json::json!({ "theme": "dark", "font_size": 12 }))
+    );
+
+    Ok(())
+}
+
+/// Date and time columns, `happened_offset` is the utc offset `DATETIME` decodes with.
+pub async fn check_date_time<D: Driver>(d: &mut D, happened_offset: i32) -> anyhow::Result<()> {
+    let ddls = ddl! {
+        CREATE TABLE Event(
+            id INT PRIMARY,
+            day DATE,
+            at TIME,
+            local_time TIMESTAMP,
+            happened DATETIME,
+            duration INTERVAL,
+        );
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    let event = Event {
+        id: 1,
+        day: Some("2022-12-01".parse()?),
+        at: Some("08:00:00.5".parse()?),
+        local_time: Some("2022-12-01T08:00:00.5".parse()?),
+        happened: Some("2022-12-01T08:00:00+08:00".parse()?),
+        duration: Some(Interval::milliseconds(1500)),
+    };
+
+    Event { ..event }.insert().exec(d).await?;
+
+    Event {
+        id: 2,
+        ..Default::default()
+    }
+    .insert()
+    .exec(d)
+    .await?;
+
+    let found = Event::select()
+        .cond(rql_where!(id = 1))
+        .exec(d)
+        .await?
+        .expect("event 1");
+
+    assert_eq!(found, event);
+
+    // The instant is kept, in the offset the driver decodes it with.
+    assert_eq!(
+        found.happened.map(|v| v.offset().local_minus_utc()),
+        Some(happened_offset)
+    );
+
+    let at: chrono::DateTime<chrono::Utc> = "2022-12-01T00:00:00Z".parse()?;
+
+    let mut rows = d
+        .select(&rql! { SELECT id FROM Event WHERE happened = #at; })
+        .await?;
+
+    assert!(rows.next().await?);
+    assert_eq!(rows.get(0).await?, 1.into());
+
+    Ok(())
+}
+
+/// Multi-row inserts of [`User`] rows on the [`create_tables`] tables.
+pub async fn check_insert_many<D: Driver>(d: &mut D) -> anyhow::Result<()> {
+    let users = (1..=25)
+        .map(|id| User {
+            first_name: format!("user{}", id),
+            last_name: if id % 2 == 0 { "even" } else { "odd" }.into(),
+            cards: (0..id % 3)
+                .map(|n| Card {
+                    id: id * 10 + n,
+                    card_no: format!("{}-{}", id, n),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    users.insert().chunk_size(10).exec(d).await?;
+
+    let users = Vec::<User>::select().order_by("id_", false).exec(d).await?;
+
+    assert_eq!(users.len(), 25);
+
+    for (idx, user) in users.iter().enumerate() {
+        let id = idx + 1;
+
+        assert_eq!(user.id, Some(id as i32));
+        assert_eq!(user.first_name, format!("user{}", id));
+        assert_eq!(user.cards.len(), id % 3);
+        assert!(user.cards.iter().all(|card| card.user_id == id));
+    }
+
+    let ids = d
+        .insert_many(
+            &rql! { INSERT INTO user_table(first_name,last_name) },
+            vec![vec!["a".into(), "x".into()], vec!["b".into(), "y".into()]],
+        )
+        .await?;
+
+    assert_eq!(ids.generated_keys, vec![26, 27]);
+
+    // Failed batch keeps no rows.
+    let err = vec![
+        Card {
+            id: 1000,
+            user_id: 1,
+            card_no: "dup".into(),
+        },
+        Card {
+            id: 1001,
+            user_id: 1,
+            card_no: "dup".into(),
+        },
+    ]
+    .insert()
+    .exec(d)
+    .await;
+
+    assert!(err.is_err());
+    assert!(Card::select()
+        .cond(rql_where!(card_no = "dup"))
+        .exec(d)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+/// Upserts of [`User`] rows on the [`create_tables`] tables.
+pub async fn check_upsert<D: Driver>(d: &mut D) -> anyhow::Result<()> {
+    User {
+        first_name: "hello".into(),
+        last_name: "rust".into(),
+        cards: vec![Card {
+            id: 1,
+            card_no: "1001".into(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+    .upsert()
+    .exec(d)
+    .await?;
+
+    User {
+        id: Some(1),
+        first_name: "world".into(),
+        last_name: "rust".into(),
+        cards: vec![
+            Card {
+                id: 1,
+                card_no: "2001".into(),
+                ..Default::default()
+            },
+            Card {
+                id: 2,
+                card_no: "2002".into(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+    .upsert()
+    .exec(d)
+    .await?;
+
+    let users = Vec::<User>::select().exec(d).await?;
+
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].first_name, "world");
+
+    let mut cards = users[0]
+        .cards
+        .iter()
+        .map(|card| (card.id, card.user_id, card.card_no.as_str()))
+        .collect::<Vec<_>>();
+
+    cards.sort();
+
+    assert_eq!(cards, vec![(1, 1, "2001"), (2, 1, "2002")]);
+
+    vec![
+        Card {
+            id: 2,
+            user_id: 1,
+            card_no: "3002".into(),
+        },
+        Card {
+            id: 3,
+            user_id: 1,
+            card_no: "3003".into(),
+        },
+    ]
+    .upsert()
+    .exec(d)
+    .await?;
+
+    let mut rows = d
+        .select(&rql! { SELECT card_no FROM Card ORDER BY id; })
+        .await?;
+
+    let mut card_nos = vec![];
+
+    while rows.next().await? {
+        card_nos.push(rows.get(0).await?);
+    }
+
+    assert_eq!(card_nos, vec!["2001".into(), "3002".into(), "3003".into()]);
+
+    d.insert(
+        &rql! { INSERT INTO Card(id,user_id,card_no) ON CONFLICT (id) DO NOTHING },
+        vec![3.into(), 1.into(), "4003".into()],
+    )
+    .await?;
+
+    let card = Card::select().cond(rql_where!(id = 3)).exec(d).await?;
+
+    assert_eq!(card.map(|card| card.card_no), Some("3003".into()));
+
+    // Conflict on other unique cols still fails.
+    assert!(Card {
+        id: 4,
+        user_id: 1,
+        card_no: "3003".into(),
+    }
+    .upsert()
+    .exec(d)
+    .await
+    .is_err());
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use linq_rs::{
-    driver::{InsertSupport, QueryIterator, SelectSupport},
+    driver::{ExecResult, InsertSupport, QueryIterator, SelectSupport},
     orm::ColumnValue,
     *,
 };
@@ -16,11 +16,15 @@ impl<'a> InsertSupport<'a> for InsertDriver<'a> {
         &mut self,
         inserter: &dml::Inserter<'a>,
         values: Vec<Variant>,
-    ) -> anyhow::Result<usize> {
+    ) -> anyhow::Result<ExecResult> {
         self.values = values;
         self.inserter.push(inserter.clone());
 
-        Ok(1)
+        Ok(ExecResult {
+            affected_rows: 1,
+            generated_keys: vec![1],
+            ..Default::default()
+        })
     }
 }
 
@@ -0,0 +1,109 @@
+#![cfg(feature = "uuid")]
+
+use linq_rs::{
+    driver::{memory::MemoryDriver, DDLSupport},
+    orm::Where,
+    *,
+};
+
+use uuid::Uuid;
+
+#[derive(ORM, Default, Debug, PartialEq)]
+struct Device {
+    #[primary(uuid)]
+    id: Option<Uuid>,
+    name: String,
+    #[cascade(from=col_id to=col_device_id)]
+    ports: Vec<Port>,
+}
+
+#[derive(ORM, Default, Debug, PartialEq)]
+struct Port {
+    #[primary(uuid)]
+    id: Uuid,
+    device_id: Uuid,
+    no: i32,
+}
+
+async fn open() -> anyhow::Result<MemoryDriver> {
+    let mut d = MemoryDriver::default();
+
+    let ddls = ddl! {
+        CREATE TABLE Device(id UUID PRIMARY, name STRING NOT NULL);
+        CREATE TABLE Port(id UUID PRIMARY, device_id UUID NOT NULL, no INT NOT NULL);
+    };
+
+    d.exec_ddl(&ddls).await?;
+
+    Ok(d)
+}
+
+#[test]
+fn test_convert() -> anyhow::Result<()> {
+    let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+
+    let variant = Variant::from(id);
+
+    assert_eq!(variant.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(Uuid::try_from(variant)?, id);
+
+    Ok(())
+}
+
+#[async_std::test]
+async fn test_generate_primary() -> anyhow::Result<()> {
+    let mut d = open().await?;
+
+    Device {
+        name: "router".into(),
+        ports: vec![
+            Port {
+                no: 1,
+                ..Default::default()
+            },
+            Port {
+                no: 2,
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    let fixed = Uuid::new_v4();
+
+    Device {
+        id: Some(fixed),
+        name: "switch".into(),
+        ..Default::default()
+    }
+    .insert()
+    .exec(&mut d)
+    .await?;
+
+    let device = Device::select()
+        .cond(rql_where!(name = "router"))
+        .exec(&mut d)
+        .await?
+        .expect("router");
+
+    let id = device.id.expect("generated id");
+
+    assert_ne!(id, Uuid::nil());
+    assert_eq!(device.ports.len(), 2);
+    assert!(device.ports.iter().all(|port| port.device_id == id));
+    assert!(device.ports.iter().all(|port| !port.id.is_nil()));
+    assert_ne!(device.ports[0].id, device.ports[1].id);
+
+    let device = Device::select()
+        .cond(rql_where!(name = "switch"))
+        .exec(&mut d)
+        .await?
+        .expect("switch");
+
+    assert_eq!(device.id, Some(fixed));
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use linq_rs::{BigInt, Decimal, LinqEnum, Variant};
+
+#[derive(LinqEnum, Debug, PartialEq)]
+enum Status {
+    Active,
+    #[linq(rename = "banned")]
+    Disabled,
+}
+
+const FLAG: isize = 1;
+
+/// Discriminants of string enums are not parsed.
+#[derive(LinqEnum, Debug, PartialEq)]
+enum Flag {
+    Read = FLAG,
+    Write = FLAG << 1,
+}
+
+#[derive(LinqEnum, Debug, PartialEq)]
+#[linq(int)]
+enum Level {
+    Low = -1,
+    Normal,
+    #[linq(value = 10)]
+    High,
+}
+
+#[test]
+fn test_int_convert() -> anyhow::Result<()> {
+    assert_eq!(Variant::from(u64::MAX), Variant::BigInt(u64::MAX.into()));
+    assert_eq!(Variant::from(i128::from(i64::MIN)), Variant::Int(i64::MIN));
+
+    assert_eq!(u64::try_from(Variant::from(u64::MAX))?, u64::MAX);
+    assert_eq!(i128::try_from(Variant::from(i128::MAX))?, i128::MAX);
+    assert_eq!(u8::try_from(Variant::Int(255))?, 255);
+
+    assert!(u8::try_from(Variant::Int(256)).is_err());
+    assert!(u8::try_from(Variant::Int(-1)).is_err());
+    assert!(i64::try_from(Variant::from(u64::MAX)).is_err());
+
+    assert_eq!(BigInt::try_from(Variant::Int(-3))?, BigInt::from(-3));
+
+    Ok(())
+}
+
+#[test]
+fn test_float_convert() -> anyhow::Result<()> {
+    assert_eq!(f64::try_from(Variant::Float(1.5))?, 1.5);
+    assert_eq!(f32::try_from(Variant::Float(1.5))?, 1.5);
+    assert_eq!(f64::try_from(Variant::Int(1 << 53))?, (1u64 << 53) as f64);
+
+    assert!(f32::try_from(Variant::Float(f64::MAX)).is_err());
+    assert!(f32::try_from(Variant::Int((1 << 24) + 1)).is_err());
+    assert!(f64::try_from(Variant::Int(i64::MAX)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_decimal_convert() -> anyhow::Result<()> {
+    let third = Decimal::new(1.into(), 3.into());
+
+    assert_eq!(
+        Decimal::try_from(Variant::from(third.clone()))?,
+        third.clone()
+    );
+    assert_eq!(
+        Decimal::try_from(Variant::Int(2))?,
+        Decimal::from_integer(2.into())
+    );
+    assert_eq!(
+        Decimal::try_from(Variant::Float(0.25))?,
+        Decimal::new(1.into(), 4.into())
+    );
+
+    assert!(Decimal::try_from(Variant::Float(f64::NAN)).is_err());
+    assert!(Decimal::try_from(Variant::String("1".into())).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_enum_convert() -> anyhow::Result<()> {
+    assert_eq!(
+        Variant::from(Status::Active),
+        Variant::String("Active".into())
+    );
+    assert_eq!(Status::try_from(Variant::from("banned"))?, Status::Disabled);
+
+    assert_eq!(Variant::from(Level::Normal), Variant::Int(0));
+    assert_eq!(Level::try_from(Variant::Int(-1))?, Level::Low);
+    assert_eq!(Level::try_from(Variant::Int(10))?, Level::High);
+
+    let err = Status::try_from(Variant::from("Disabled")).unwrap_err();
+    assert_eq!(err.to_string(), r#"Unknown Status value "Disabled""#);
+
+    let err = Level::try_from(Variant::Int(1)).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown Level value 1");
+
+    assert!(Level::try_from(Variant::from("High")).is_err());
+
+    assert_eq!(Variant::from(Flag::Write), Variant::String("Write".into()));
+    assert_eq!(Flag::try_from(Variant::from("Read"))?, Flag::Read);
+
+    Ok(())
+}
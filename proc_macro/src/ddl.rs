@@ -14,10 +14,10 @@ use linq_sql_parser::{DDLs, DDL};
 impl CodeGen for DDL {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
         match self {
-            Self::Create(create) => return create.gen_ir_code(),
-            Self::Alter(alter) => return alter.gen_ir_code(),
-            Self::Drop(drop) => return drop.gen_ir_code(),
-            Self::Truncate(truncate) => return truncate.gen_ir_code(),
+            Self::Create(create) => create.gen_ir_code(),
+            Self::Alter(alter) => alter.gen_ir_code(),
+            Self::Drop(drop) => drop.gen_ir_code(),
+            Self::Truncate(truncate) => truncate.gen_ir_code(),
         }
     }
 }
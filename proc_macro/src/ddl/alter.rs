@@ -58,7 +58,7 @@ impl CodeGen for AlterExpr {
                 ::linq_rs::ddl::AlterExpr::RenameConstraint(#from,#to)
             }),
             Self::RenameColumn(from, to) => Ok(quote! {
-                ::linq_rs::ddl::AlterExpr::RenameConstraint(#from,#to)
+                ::linq_rs::ddl::AlterExpr::RenameColumn(#from,#to)
             }),
             Self::RenameTable(to) => Ok(quote! {
                 ::linq_rs::ddl::AlterExpr::RenameTable(#to)
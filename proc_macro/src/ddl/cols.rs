@@ -12,7 +12,7 @@ impl CodeGen for Column {
 
         let default_value = if let Some(default_value) = &self.default_value {
             let default_value = default_value.gen_ir_code()?;
-            quote!(Some(#default_value))
+            quote!(Some(#default_value.into()))
         } else {
             quote!(None)
         };
@@ -38,14 +38,20 @@ impl CodeGen for Column {
 impl CodeGen for IrType {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
         let name = match self {
+            Self::Bool(_) => quote!(Bool),
             Self::Int(_) => quote!(Int),
             Self::Float(_) => quote!(Float),
             Self::String(_) => quote!(String),
             Self::Bytes(_) => quote!(Bytes),
             Self::BigInt(_) => quote!(BigInt),
             Self::Decimal(_) => quote!(Decimal),
-            Self::DateTime(_) => quote!(DateTime),
+            Self::Date(_) => quote!(Date),
+            Self::Time(_) => quote!(Time),
             Self::Timestamp(_) => quote!(Timestamp),
+            Self::DateTime(_) => quote!(DateTime),
+            Self::Interval(_) => quote!(Interval),
+            Self::Json(_) => quote!(Json),
+            Self::Uuid(_) => quote!(Uuid),
         };
 
         Ok(quote! {
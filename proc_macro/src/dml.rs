@@ -1,33 +1,18 @@
-mod select;
 use quote::quote;
-pub use select::*;
-
-mod kw;
-pub use kw::*;
 
+mod aggregate;
+mod cols;
 mod cond;
-pub use cond::*;
-
-mod limit;
-pub use limit::*;
-
-mod order;
-pub use order::*;
-
+mod delete;
 mod from;
-pub use from::*;
-
+mod group;
 mod insert;
-pub use insert::*;
-
+mod join;
+mod kw;
+mod limit;
+mod order;
+mod select;
 mod update;
-pub use update::*;
-
-mod cols;
-pub use cols::*;
-
-mod delete;
-pub use delete::*;
 
 use crate::CodeGen;
 
@@ -36,10 +21,10 @@ use linq_sql_parser::{RQLs, RQL};
 impl CodeGen for RQL {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
         match self {
-            Self::Select(select) => return select.gen_ir_code(),
-            Self::Insert(insert) => return insert.gen_ir_code(),
-            Self::Update(update) => return update.gen_ir_code(),
-            Self::Delete(delete) => return delete.gen_ir_code(),
+            Self::Select(select) => select.gen_ir_code(),
+            Self::Insert(insert) => insert.gen_ir_code(),
+            Self::Update(update) => update.gen_ir_code(),
+            Self::Delete(delete) => delete.gen_ir_code(),
         }
     }
 }
@@ -0,0 +1,17 @@
+use quote::quote;
+
+use crate::gen::CodeGen;
+
+use linq_sql_parser::AggregateFunc;
+
+impl CodeGen for AggregateFunc {
+    fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
+        match self {
+            Self::Count => Ok(quote!(::linq_rs::dml::AggregateFunc::Count)),
+            Self::Sum => Ok(quote!(::linq_rs::dml::AggregateFunc::Sum)),
+            Self::Avg => Ok(quote!(::linq_rs::dml::AggregateFunc::Avg)),
+            Self::Min => Ok(quote!(::linq_rs::dml::AggregateFunc::Min)),
+            Self::Max => Ok(quote!(::linq_rs::dml::AggregateFunc::Max)),
+        }
+    }
+}
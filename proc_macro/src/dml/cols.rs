@@ -4,6 +4,16 @@ use crate::gen::CodeGen;
 
 use linq_sql_parser::Columns;
 
+/// Generate `Option<Columns>` of `RETURNING` clause
+pub fn gen_returning(returning: Option<&Columns>) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some(cols) = returning {
+        let token_stream = cols.gen_ir_code()?;
+        Ok(quote!(Some(#token_stream)))
+    } else {
+        Ok(quote!(None))
+    }
+}
+
 impl CodeGen for Columns {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
         match self {
@@ -1,6 +1,6 @@
 use quote::quote;
 
-use crate::gen::CodeGen;
+use crate::{gen::CodeGen, variant::gen_option};
 
 use linq_sql_parser::{CondExpr, CondParameter, Op, Variant};
 
@@ -14,7 +14,15 @@ impl CodeGen for Op {
             Self::Gte(_) => Ok(quote!(::linq_rs::dml::CondOp::Gte)),
             Self::Lte(_) => Ok(quote!(::linq_rs::dml::CondOp::Lte)),
             Self::Like(_) => Ok(quote!(::linq_rs::dml::CondOp::Like)),
+            Self::NotLike(..) => Ok(quote!(::linq_rs::dml::CondOp::NotLike)),
             Self::In(_) => Ok(quote!(::linq_rs::dml::CondOp::In)),
+            Self::NotIn(..) => Ok(quote!(::linq_rs::dml::CondOp::NotIn)),
+            Self::Between(_) => Ok(quote!(::linq_rs::dml::CondOp::Between)),
+            Self::NotBetween(..) => Ok(quote!(::linq_rs::dml::CondOp::NotBetween)),
+            Self::IsNull(..) => Ok(quote!(::linq_rs::dml::CondOp::IsNull)),
+            Self::IsNotNull(..) => Ok(quote!(::linq_rs::dml::CondOp::IsNotNull)),
+            Self::Not(_) => Ok(quote!(::linq_rs::dml::CondOp::Not)),
+            Self::Exists(_) => Ok(quote!(::linq_rs::dml::CondOp::Exists)),
             Self::And(_) => Ok(quote!(::linq_rs::dml::CondOp::And)),
             Self::Or(_) => Ok(quote!(::linq_rs::dml::CondOp::Or)),
         }
@@ -31,20 +39,52 @@ impl CodeGen for CondParameter {
                     ::linq_rs::dml::CondParam::CondExpr(Box::new(#expr ))
                 })
             }
-            CondParameter::Variant(v) => match v {
-                Variant::Expr(expr) => Ok(quote! {
-                    ::linq_rs::dml::CondParam::Variant(#expr.into())
-                }),
-                Variant::Ident(ident) => {
-                    let v = format!("{}", ident);
-                    Ok(quote! {
-                        ::linq_rs::dml::CondParam::Variant(#v.into())
+            CondParameter::Param(v) => {
+                let v = v.gen_ir_code()?;
+
+                Ok(quote! {
+                    ::linq_rs::dml::CondParam::Param(#v.into())
+                })
+            }
+            CondParameter::Column(table, name) => {
+                let table = gen_option(table)?;
+                let name = name.gen_ir_code()?;
+
+                Ok(quote! {
+                    ::linq_rs::dml::CondParam::Column(::linq_rs::dml::ColumnRef {
+                        table: #table.map(|table: &str| table.to_string()),
+                        name: (#name).to_string(),
                     })
-                }
-                Variant::Lit(lit) => Ok(quote! {
-                    ::linq_rs::dml::CondParam::Variant(#lit.into())
-                }),
-            },
+                })
+            }
+            CondParameter::Aggregate(aggregate) => {
+                let func = aggregate.func.gen_ir_code()?;
+                let table = gen_option(&aggregate.table)?;
+                let name = aggregate.name.gen_ir_code()?;
+
+                Ok(quote! {
+                    ::linq_rs::dml::CondParam::Aggregate(#func, ::linq_rs::dml::ColumnRef {
+                        table: #table.map(|table: &str| table.to_string()),
+                        name: (#name).to_string(),
+                    })
+                })
+            }
+            CondParameter::Range(low, high) => {
+                let low = low.gen_ir_code()?;
+                let high = high.gen_ir_code()?;
+
+                Ok(quote! {
+                    ::linq_rs::dml::CondParam::Range(Box::new(#low), Box::new(#high))
+                })
+            }
+            CondParameter::Empty => Ok(quote!(::linq_rs::dml::CondParam::Empty)),
+            CondParameter::Subquery(select) => {
+                let select = select.gen_ir_code()?;
+
+                Ok(quote! {
+                    ::linq_rs::dml::CondParam::Subquery(Box::new(#select))
+                })
+            }
             CondParameter::VariantList(variants) => {
                 let mut token_streams = vec![];
 
@@ -1,5 +1,6 @@
 use quote::quote;
 
+use super::cols::gen_returning;
 use crate::gen::CodeGen;
 
 use linq_sql_parser::Delete;
@@ -8,11 +9,13 @@ impl CodeGen for Delete {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
         let table_name = self.table_name.gen_ir_code()?;
         let cond = self.cond.gen_ir_code()?;
+        let returning = gen_returning(self.returning.as_ref())?;
 
         Ok(quote! {
             ::linq_rs::dml::Deleter {
                 table_name: #table_name,
                 cond: #cond,
+                returning: #returning,
             }
         })
     }
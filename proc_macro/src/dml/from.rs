@@ -1,16 +1,33 @@
 use quote::quote;
 
-use crate::gen::CodeGen;
+use crate::{gen::CodeGen, variant::gen_option};
 
 use linq_sql_parser::From;
 
 impl CodeGen for From {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
         let table_name = self.table_name.gen_ir_code()?;
+        let aliase = gen_option(&self.aliase)?;
+
+        let subquery = if let Some(subquery) = &self.subquery {
+            let token_stream = subquery.gen_ir_code()?;
+            quote!(Some(Box::new(#token_stream)))
+        } else {
+            quote!(None)
+        };
+
+        let mut joins = vec![];
+
+        for join in &self.joins {
+            joins.push(join.gen_ir_code()?);
+        }
 
         Ok(quote! {
             ::linq_rs::dml::SelectFrom {
                 table_name: #table_name,
+                aliase: #aliase,
+                subquery: #subquery,
+                joins: vec![#(#joins,)*],
             }
         })
     }
@@ -0,0 +1,25 @@
+use quote::quote;
+
+use crate::{gen::CodeGen, variant::gen_option};
+
+use linq_sql_parser::GroupBy;
+
+impl CodeGen for GroupBy {
+    fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let mut token_streams = vec![];
+
+        for col in &self.cols {
+            let table = gen_option(&col.table)?;
+            let col_name = col.name.gen_ir_code()?;
+
+            token_streams.push(quote! {
+                ::linq_rs::dml::GroupBy {
+                    table: #table,
+                    col_name: #col_name,
+                }
+            });
+        }
+
+        Ok(quote!(vec![#(#token_streams,)*]))
+    }
+}
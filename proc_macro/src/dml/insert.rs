@@ -1,8 +1,9 @@
 use quote::quote;
 
+use super::cols::gen_returning;
 use crate::gen::CodeGen;
 
-use linq_sql_parser::Insert;
+use linq_sql_parser::{Insert, OnConflict};
 
 impl CodeGen for Insert {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
@@ -10,10 +11,41 @@ impl CodeGen for Insert {
 
         let cols = self.cols.gen_ir_code()?;
 
+        let on_conflict = if let Some(on_conflict) = &self.on_conflict {
+            let token_stream = on_conflict.gen_ir_code()?;
+            quote!(Some(#token_stream))
+        } else {
+            quote!(None)
+        };
+
+        let returning = gen_returning(self.returning.as_ref())?;
+
         Ok(quote! {
             ::linq_rs::dml::Inserter {
                 table_name: #table_name,
                 cols: #cols,
+                on_conflict: #on_conflict,
+                returning: #returning,
+            }
+        })
+    }
+}
+
+impl CodeGen for OnConflict {
+    fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let target = self.target.gen_ir_code()?;
+
+        let action = if let Some(update) = &self.update {
+            let cols = update.gen_ir_code()?;
+            quote!(::linq_rs::dml::ConflictAction::DoUpdate(#cols))
+        } else {
+            quote!(::linq_rs::dml::ConflictAction::DoNothing)
+        };
+
+        Ok(quote! {
+            ::linq_rs::dml::OnConflict {
+                target: #target,
+                action: #action,
             }
         })
     }
@@ -0,0 +1,35 @@
+use quote::quote;
+
+use crate::{gen::CodeGen, variant::gen_option};
+
+use linq_sql_parser::{Join, JoinKind};
+
+impl CodeGen for Join {
+    fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let kind = match self.kind {
+            JoinKind::Inner => quote!(::linq_rs::dml::JoinKind::Inner),
+            JoinKind::Left => quote!(::linq_rs::dml::JoinKind::Left),
+            JoinKind::Right => quote!(::linq_rs::dml::JoinKind::Right),
+            JoinKind::Cross => quote!(::linq_rs::dml::JoinKind::Cross),
+        };
+
+        let table_name = self.table_name.gen_ir_code()?;
+        let aliase = gen_option(&self.aliase)?;
+
+        let on = if let Some(on) = &self.on {
+            let token_stream = on.gen_ir_code()?;
+            quote!(Some(#token_stream))
+        } else {
+            quote!(None)
+        };
+
+        Ok(quote! {
+            ::linq_rs::dml::Join {
+                kind: #kind,
+                table_name: #table_name,
+                aliase: #aliase,
+                on: #on,
+            }
+        })
+    }
+}
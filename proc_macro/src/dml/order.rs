@@ -1,11 +1,12 @@
 use quote::quote;
 
-use crate::gen::CodeGen;
+use crate::{gen::CodeGen, variant::gen_option};
 
-use linq_sql_parser::{Order, OrderBy};
+use linq_sql_parser::{Nulls, Order, OrderBy};
 
 impl CodeGen for OrderBy {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let table = gen_option(&self.table)?;
         let col_name = self.name.gen_ir_code()?;
 
         let order = match &self.order {
@@ -18,10 +19,18 @@ impl CodeGen for OrderBy {
             Order::Variant(v) => v.gen_ir_code()?,
         };
 
+        let nulls = match &self.nulls {
+            Some(Nulls::First) => quote!(Some(::linq_rs::dml::Nulls::First)),
+            Some(Nulls::Last) => quote!(Some(::linq_rs::dml::Nulls::Last)),
+            None => quote!(None),
+        };
+
         Ok(quote! {
             ::linq_rs::dml::OrderBy {
+                table: #table,
                 col_name: #col_name,
                 desc: #order,
+                nulls: #nulls,
             }
         })
     }
@@ -2,7 +2,7 @@ use quote::quote;
 
 use linq_sql_parser::{NamedColumn, Select, SelectColumns};
 
-use crate::gen::CodeGen;
+use crate::{gen::CodeGen, variant::gen_option};
 
 impl CodeGen for Select {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
@@ -17,27 +17,41 @@ impl CodeGen for Select {
             quote!(None)
         };
 
-        let limit = if let Some(limit) = &self.limit {
-            let token_stream = limit.gen_ir_code()?;
+        let group_by = if let Some(group_by) = &self.group_by {
+            group_by.gen_ir_code()?
+        } else {
+            quote!(vec![])
+        };
+
+        let having = if let Some(having) = &self.having {
+            let token_stream = having.gen_ir_code()?;
             quote!(Some(#token_stream))
         } else {
             quote!(None)
         };
 
-        let order = if let Some(order) = &self.order {
-            let token_stream = order.gen_ir_code()?;
+        let limit = if let Some(limit) = &self.limit {
+            let token_stream = limit.gen_ir_code()?;
             quote!(Some(#token_stream))
         } else {
             quote!(None)
         };
 
+        let mut order = vec![];
+
+        for order_by in &self.order {
+            order.push(order_by.gen_ir_code()?);
+        }
+
         Ok(quote! {
             ::linq_rs::dml::Selecter {
                 cols: #cols,
                 from: #from,
                 cond: #cond,
+                group_by: #group_by,
+                having: #having,
                 limit: #limit,
-                order_by: #order,
+                order_by: vec![#(#order,)*],
             }
         })
     }
@@ -68,6 +82,7 @@ impl CodeGen for SelectColumns {
 
 impl CodeGen for NamedColumn {
     fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let table = gen_option(&self.table)?;
         let name = self.name.gen_ir_code()?;
         let aliase = if let Some(aliase) = &self.aliase {
             let stream = aliase.gen_ir_code()?;
@@ -77,10 +92,18 @@ impl CodeGen for NamedColumn {
         } else {
             quote!(None)
         };
+        let func = if let Some(func) = &self.func {
+            let stream = func.gen_ir_code()?;
+            quote!(Some(#stream))
+        } else {
+            quote!(None)
+        };
         Ok(quote! {
             ::linq_rs::dml::SelectNamedColumn {
+                table: #table,
                 name: #name,
-                aliase: #aliase
+                aliase: #aliase,
+                func: #func,
             }
         })
     }
@@ -1,5 +1,6 @@
 use quote::quote;
 
+use super::cols::gen_returning;
 use crate::gen::CodeGen;
 
 use linq_sql_parser::Update;
@@ -9,12 +10,14 @@ impl CodeGen for Update {
         let table_name = self.table_name.gen_ir_code()?;
         let cols = self.cols.gen_ir_code()?;
         let cond = self.cond.gen_ir_code()?;
+        let returning = gen_returning(self.returning.as_ref())?;
 
         Ok(quote! {
             ::linq_rs::dml::Updater {
                 table_name: #table_name,
                 cols: #cols,
                 cond: Some(#cond),
+                returning: #returning,
             }
         })
     }
@@ -60,14 +60,43 @@ pub fn ddl(input: TokenStream) -> TokenStream {
     token_stream.into()
 }
 
-#[proc_macro_derive(ORM, attributes(table_name, column, primary, cascade))]
+/// Map unit-variant enum to string or integer column
+#[proc_macro_derive(LinqEnum, attributes(linq))]
+pub fn linq_enum(item: TokenStream) -> TokenStream {
+    let token_stream = LinqEnum::new(parse_macro_input!(item))
+        .and_then(|linq_enum| linq_enum.gen_ir_code())
+        .unwrap_or_else(|err| err.to_compile_error());
+
+    token_stream.into()
+}
+
+/// Implement `ColumnLike` for single-field struct by delegating to the inner type
+#[proc_macro_derive(ColumnLike)]
+pub fn column_like(item: TokenStream) -> TokenStream {
+    let token_stream = NewType::new(parse_macro_input!(item))
+        .and_then(|newtype| newtype.gen_ir_code())
+        .unwrap_or_else(|err| err.to_compile_error());
+
+    token_stream.into()
+}
+
+/// Decode ad-hoc query rows into struct fields
+#[proc_macro_derive(FromRow, attributes(column))]
+pub fn from_row(item: TokenStream) -> TokenStream {
+    let token_stream = FromRow::new(parse_macro_input!(item))
+        .and_then(|from_row| from_row.gen_ir_code())
+        .unwrap_or_else(|err| err.to_compile_error());
+
+    token_stream.into()
+}
+
+#[proc_macro_derive(ORM, attributes(table_name, column, primary, cascade, many_to_many))]
 pub fn table(item: TokenStream) -> TokenStream {
     // let table_name = parse_macro_input!(attrs as Option<LitStr>);
 
     let token_stream = Table::new(parse_macro_input!(item))
-        .expect("Parse table struct error")
-        .gen_ir_code()
-        .expect("Generate table code error");
+        .and_then(|table| table.gen_ir_code())
+        .unwrap_or_else(|err| err.to_compile_error());
 
     // eprintln!("{}", token_stream);
 
@@ -3,18 +3,28 @@ use quote::{format_ident, quote};
 use syn::{Fields, ItemStruct, LitStr, Visibility};
 
 use crate::gen::CodeGen;
-use linq_sql_parser::extract_type_from_vec;
+use linq_sql_parser::{extract_type_from_option, extract_type_from_vec};
 
 mod col;
 use col::*;
 
+mod linq_enum;
+pub use linq_enum::*;
+
+mod newtype;
+pub use newtype::*;
+
+mod from_row;
+pub use from_row::*;
+
 #[allow(dead_code)]
 pub struct Table {
     table_name: Option<LitStr>,
     ident: Ident,
     cols: Vec<ColumnDef>,
-    primary_field: Ident,
-    auto_inc: bool,
+    /// `None` for link tables without primary col.
+    primary_field: Option<Ident>,
+    key_gen: KeyGen,
     vis: Visibility,
     item: ItemStruct,
 }
@@ -26,11 +36,8 @@ impl Table {
         for attr in &item.attrs {
             if let Some(path) = attr.path.get_ident() {
                 let name = path.to_string();
-                match name.as_str() {
-                    "table_name" => {
-                        table_name = Some(attr.parse_args()?);
-                    }
-                    _ => {}
+                if name.as_str() == "table_name" {
+                    table_name = Some(attr.parse_args()?);
                 };
             }
         }
@@ -44,8 +51,8 @@ impl Table {
                 let mut attrs = vec![];
 
                 for attr in &field.attrs {
-                    if let Some(col_attr) = ColumnAttr::new(&field.ident.clone().unwrap(), attr)? {
-                        if let ColumnAttr::Primary(auto_inc) = col_attr {
+                    for col_attr in ColumnAttr::new(&field.ident.clone().unwrap(), attr)? {
+                        if let ColumnAttr::Primary(key_gen) = col_attr {
                             if primary_col.is_some() {
                                 return Err(syn::Error::new_spanned(
                                     attr,
@@ -53,7 +60,7 @@ impl Table {
                                 ));
                             }
 
-                            primary_col = Some((field.ident.clone().unwrap(), auto_inc));
+                            primary_col = Some((field.ident.clone().unwrap(), key_gen));
                         }
 
                         attrs.push(col_attr);
@@ -68,22 +75,20 @@ impl Table {
             }
         }
 
-        let (primary_field, auto_inc) = primary_col.ok_or(syn::Error::new(
-            item.ident.span(),
-            "Table must define primary col",
-        ))?;
+        // Link tables may be keyed by their `(from, to)` cols alone.
+        let (primary_field, key_gen) = match primary_col {
+            Some((primary_field, key_gen)) => (Some(primary_field), key_gen),
+            None => (None, KeyGen::Manual),
+        };
 
         for col in &cols {
-            if col.name == primary_field {
+            if Some(&col.name) == primary_field.as_ref() {
                 for attr in &col.attrs {
-                    match attr {
-                        ColumnAttr::Cascade(_) => {
-                            return Err(syn::Error::new(
-                                primary_field.span(),
-                                "Tag primary key on one_to_many col",
-                            ));
-                        }
-                        _ => {}
+                    if let ColumnAttr::Cascade(_) | ColumnAttr::ManyToMany(_) = attr {
+                        return Err(syn::Error::new(
+                            col.name.span(),
+                            "Tag primary key on one_to_many col",
+                        ));
                     }
                 }
             }
@@ -94,7 +99,7 @@ impl Table {
             ident: item.ident.clone(),
             cols,
             primary_field,
-            auto_inc,
+            key_gen,
             vis: item.vis.clone(),
             item,
         })
@@ -165,8 +170,8 @@ impl Table {
                     quote!(::linq_rs::orm::Column::Simple(#col_name))
                 }
                 ColumnType::Primary => {
-                    let auto_inc = self.auto_inc;
-                    quote!(::linq_rs::orm::Column::Primary(#col_name,#auto_inc))
+                    let key_gen = self.key_gen.gen_ir_code()?;
+                    quote!(::linq_rs::orm::Column::Primary(#col_name,#key_gen))
                 }
                 ColumnType::Cascade => {
                     let related = col.related()?;
@@ -178,6 +183,7 @@ impl Table {
                         format_ident!("{}", related.to, span = related.to.span());
 
                     let self_type = &self.ident;
+                    let lazy = related.lazy;
 
                     if let Some(vec_type) = extract_type_from_vec(col_type) {
                         quote!(::linq_rs::orm::Column::OneToMany(::linq_rs::orm::Cascade {
@@ -186,17 +192,69 @@ impl Table {
                            table_name: || #vec_type::table_name(),
                            foreign_key_col: #vec_type::#foreign_key_col_name_fn(),
                            table_cols: || #vec_type::cols(),
+                           lazy: #lazy,
+                           required: false,
                         }))
                     } else {
+                        let required = extract_type_from_option(col_type).is_none();
+
+                        // Skipped required col can't be built, nor filled by default,
+                        // which would overwrite the real row on update.
+                        if required && lazy {
+                            return Err(syn::Error::new_spanned(
+                                col_type,
+                                "lazy one-to-one col must be Option<T>",
+                            ));
+                        }
+
+                        let col_type = extract_type_from_option(col_type).unwrap_or(col_type);
+
                         quote!(::linq_rs::orm::Column::OneToOne(::linq_rs::orm::Cascade {
                            name: #col_name,
                            ref_col: #self_type::#ref_col_name_fn(),
-                           table_name: || #col_type::table_name(),
-                           foreign_key_col: #col_type::#foreign_key_col_name_fn(),
-                           table_cols: || #col_type::cols(),
+                           table_name: || <#col_type as ::linq_rs::orm::Table>::table_name(),
+                           foreign_key_col: <#col_type>::#foreign_key_col_name_fn(),
+                           table_cols: || <#col_type as ::linq_rs::orm::Table>::cols(),
+                           lazy: #lazy,
+                           required: #required,
                         }))
                     }
                 }
+                ColumnType::ManyToMany => {
+                    let through = col.through()?;
+                    let link_type = &through.through;
+                    let from_col_name_fn = &through.from;
+                    let to_col_name_fn = &through.to;
+                    let lazy = through.lazy;
+
+                    let self_type = &self.ident;
+                    let primary_field = self.primary_field.as_ref().ok_or(syn::Error::new(
+                        col.name.span(),
+                        "many_to_many col requires table primary col",
+                    ))?;
+                    let primary_col_name_fn =
+                        format_ident!("col_{}", primary_field, span = primary_field.span());
+
+                    // `None` marks links not loaded, which update leaves untouched.
+                    let target_type = extract_type_from_option(&col.col_type)
+                        .and_then(extract_type_from_vec)
+                        .ok_or(syn::Error::new_spanned(
+                            &col.col_type,
+                            "many_to_many col must be Option<Vec<T>>",
+                        ))?;
+
+                    quote!(::linq_rs::orm::Column::ManyToMany(::linq_rs::orm::ManyToMany {
+                       name: #col_name,
+                       ref_col: #self_type::#primary_col_name_fn(),
+                       through_table_name: || <#link_type as ::linq_rs::orm::Table>::table_name(),
+                       through_table_cols: || <#link_type as ::linq_rs::orm::Table>::cols(),
+                       from_col: #link_type::#from_col_name_fn(),
+                       to_col: #link_type::#to_col_name_fn(),
+                       table_name: || <#target_type as ::linq_rs::orm::Table>::table_name(),
+                       table_cols: || <#target_type as ::linq_rs::orm::Table>::cols(),
+                       lazy: #lazy,
+                    }))
+                }
             });
         }
 
@@ -231,26 +289,43 @@ impl Table {
         for col in &self.cols {
             let col_name = col.col_name();
             let ident = &col.name;
-            let ty = &col.col_type;
 
-            cols.push(quote! {
-                assert_eq!(values[0].col_name(), #col_name);
-                let #ident = ::linq_rs::orm::from_column_value::<#ty>(values.remove(0))?;
+            let from_column_value = col.gen_from_column_value(quote!(values.remove(0)));
+
+            cols.push(match col.col_type() {
+                // Cascade values not loaded by select are missing.
+                ColumnType::Cascade | ColumnType::ManyToMany => {
+                    let missing = if extract_type_from_vec(&col.col_type).is_some() {
+                        quote!(::linq_rs::orm::ColumnValue::OneToMany(#col_name, vec![]))
+                    } else {
+                        quote!(::linq_rs::orm::ColumnValue::Simple(#col_name, ::linq_rs::Variant::Null))
+                    };
+
+                    quote! {
+                        if values.first().map(|value| value.col_name()) != Some(#col_name) {
+                            values.insert(0, #missing);
+                        }
+
+                        let #ident = #from_column_value?;
+                    }
+                }
+                _ => quote! {
+                    assert_eq!(values[0].col_name(), #col_name);
+                    let #ident = #from_column_value?;
+                },
             });
 
             idents.push(ident);
         }
 
-        let count = self.cols.len();
-
         Ok(quote! {
             fn from_values(mut values: Vec<::linq_rs::orm::ColumnValue>) -> ::linq_rs::anyhow::Result<Self> {
                 use ::linq_rs::orm::ColumnLike;
 
-                assert_eq!(values.len(), #count);
-
                 #(#cols)*
 
+                assert!(values.is_empty());
+
                 Ok(Self {
                     #(#idents,)*
                 })
@@ -265,16 +340,23 @@ impl Table {
             let col_name = col.col_name();
             let ident = &col.name;
 
-            cols.push(quote! {
-                self.#ident.into_column_value(#col_name)
+            cols.push(match col.converter() {
+                Converter::ColumnLike => quote!(self.#ident.into_column_value(#col_name)?),
+                Converter::Json => {
+                    quote!(::linq_rs::orm::into_json_column_value(self.#ident, #col_name)?)
+                }
+                Converter::With(path) => quote!(::linq_rs::orm::ColumnValue::Simple(
+                    #col_name,
+                    #path::to_variant(self.#ident)
+                )),
             });
         }
 
         Ok(quote! {
-            fn into_values(mut self) ->  Vec<::linq_rs::orm::ColumnValue> {
+            fn into_values(mut self) -> ::linq_rs::anyhow::Result<Vec<::linq_rs::orm::ColumnValue>> {
                 use ::linq_rs::orm::ColumnLike;
 
-                vec![#(#cols,)*]
+                Ok(vec![#(#cols,)*])
             }
         })
     }
@@ -288,8 +370,11 @@ impl Table {
 
             let fn_name = format_ident!("col_{}", ident, span = ident.span());
 
+            let vis = &self.vis;
+
+            // Same visibility as the struct, so cascades can name cols across modules.
             cols.push(quote! {
-                const fn #fn_name() -> &'static str {
+                #vis const fn #fn_name() -> &'static str {
                     #col_name
                 }
             });
@@ -1,6 +1,6 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{parse::Parse, Attribute, LitStr, Token, Type};
+use syn::{parse::Parse, Attribute, LitStr, Path, Token, Type};
 
 use crate::gen::CodeGen;
 
@@ -10,6 +10,11 @@ mod kw {
     custom_keyword!(from);
     custom_keyword!(to);
     custom_keyword!(autoinc);
+    custom_keyword!(uuid);
+    custom_keyword!(json);
+    custom_keyword!(with);
+    custom_keyword!(lazy);
+    custom_keyword!(through);
 }
 
 #[derive(Clone)]
@@ -22,13 +27,12 @@ pub struct ColumnDef {
 impl ColumnDef {
     pub fn col_name(&self) -> LitStr {
         for attr in &self.attrs {
-            match attr {
-                ColumnAttr::Name(name) => return name.clone(),
-                _ => {}
+            if let ColumnAttr::Name(name) = attr {
+                return name.clone();
             }
         }
 
-        return LitStr::new(&self.name.to_string(), self.name.span());
+        LitStr::new(&self.name.to_string(), self.name.span())
     }
 
     // pub fn primary_autoinc(&self) -> bool {
@@ -46,26 +50,66 @@ impl ColumnDef {
         for attr in &self.attrs {
             match attr {
                 ColumnAttr::Cascade(_) => return ColumnType::Cascade,
+                ColumnAttr::ManyToMany(_) => return ColumnType::ManyToMany,
                 ColumnAttr::Primary(_) => return ColumnType::Primary,
                 _ => {}
             }
         }
 
-        return ColumnType::Simple;
+        ColumnType::Simple
     }
 
-    pub fn related(&self) -> syn::Result<Related> {
+    /// How col value is converted from/to `linq_rs::Variant`
+    pub fn converter(&self) -> Converter {
         for attr in &self.attrs {
             match attr {
-                ColumnAttr::Cascade(related) => return Ok(related.clone()),
+                ColumnAttr::Json => return Converter::Json,
+                ColumnAttr::With(path) => return Converter::With(path.clone()),
                 _ => {}
             }
         }
 
-        return Err(syn::Error::new(
+        Converter::ColumnLike
+    }
+
+    /// Generate expr converting `value` to field type by [`Self::converter`]
+    pub fn gen_from_column_value(&self, value: TokenStream) -> TokenStream {
+        let ty = &self.col_type;
+
+        match self.converter() {
+            Converter::ColumnLike => quote!(::linq_rs::orm::from_column_value::<#ty>(#value)),
+            Converter::Json => quote!(::linq_rs::orm::from_json_column_value::<#ty>(#value)),
+            Converter::With(path) => quote!(::linq_rs::orm::from_column_value_with::<#ty>(
+                #value,
+                #path::from_variant
+            )),
+        }
+    }
+
+    pub fn related(&self) -> syn::Result<Related> {
+        for attr in &self.attrs {
+            if let ColumnAttr::Cascade(related) = attr {
+                return Ok(related.clone());
+            }
+        }
+
+        Err(syn::Error::new(
             self.name.span(),
             "Can't get related from simple column",
-        ));
+        ))
+    }
+
+    pub fn through(&self) -> syn::Result<Through> {
+        for attr in &self.attrs {
+            if let ColumnAttr::ManyToMany(through) = attr {
+                return Ok(through.clone());
+            }
+        }
+
+        Err(syn::Error::new(
+            self.name.span(),
+            "Can't get through table from non many_to_many column",
+        ))
     }
 }
 
@@ -80,51 +124,129 @@ impl CodeGen for ColumnDef {
     }
 }
 
+pub enum Converter {
+    /// `ColumnLike` impl of field type
+    ColumnLike,
+    /// serde json, tagged by `#[column(json)]`
+    Json,
+    /// `to_variant`/`from_variant` fns of module, tagged by `#[column(with = "path")]`
+    With(Path),
+}
+
 pub enum ColumnType {
     Simple,
     Cascade,
+    ManyToMany,
     Primary,
 }
 
 #[derive(Clone)]
 pub enum ColumnAttr {
     Name(LitStr),
+    Json,
+    With(Path),
     Cascade(Related),
-    Primary(bool),
+    ManyToMany(Through),
+    Primary(KeyGen),
 }
 
 impl ColumnAttr {
-    pub fn new(field: &Ident, attr: &Attribute) -> syn::Result<Option<Self>> {
+    pub fn new(field: &Ident, attr: &Attribute) -> syn::Result<Vec<Self>> {
         if let Some(path) = attr.path.get_ident() {
             let name = path.to_string();
             let attr = match name.as_str() {
                 "primary" => Self::parse_primary(field, attr)?,
-                "column" => Self::parse_col_name(field, attr)?,
+                "column" => return Self::parse_column(field, attr),
                 "cascade" => Self::parse_cascade(field, attr)?,
-                _ => return Ok(None),
+                "many_to_many" => Self::parse_many_to_many(field, attr)?,
+                _ => return Ok(vec![]),
             };
 
-            return Ok(Some(attr));
+            Ok(vec![attr])
         } else {
-            return Ok(None);
+            Ok(vec![])
         }
     }
 
     fn parse_primary(_: &Ident, attr: &Attribute) -> syn::Result<Self> {
         if attr.tokens.is_empty() {
-            return Ok(Self::Primary(false));
+            return Ok(Self::Primary(KeyGen::Manual));
         }
 
-        let auto_inc: Option<kw::autoinc> = attr.parse_args()?;
+        attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(kw::autoinc) {
+                input.parse::<kw::autoinc>()?;
+                Ok(Self::Primary(KeyGen::AutoInc))
+            } else if lookahead.peek(kw::uuid) {
+                input.parse::<kw::uuid>()?;
+                Ok(Self::Primary(KeyGen::Uuid))
+            } else {
+                Err(lookahead.error())
+            }
+        })
+    }
 
-        Ok(Self::Primary(auto_inc.map(|_| true).unwrap_or(false)))
+    /// Parse `#[column(..)]` args, comma separated col name literal,
+    /// `json` or `with = "path::to::module"`.
+    fn parse_column(field: &Ident, attr: &Attribute) -> syn::Result<Vec<Self>> {
+        let attrs = attr
+            .parse_args_with(|input: syn::parse::ParseStream| {
+                let mut attrs = vec![];
+
+                while !input.is_empty() {
+                    let lookahead = input.lookahead1();
+
+                    if lookahead.peek(LitStr) {
+                        attrs.push(Self::Name(input.parse()?));
+                    } else if lookahead.peek(kw::json) {
+                        input.parse::<kw::json>()?;
+                        attrs.push(Self::Json);
+                    } else if lookahead.peek(kw::with) {
+                        input.parse::<kw::with>()?;
+                        input.parse::<Token!(=)>()?;
+
+                        let path: LitStr = input.parse()?;
+                        attrs.push(Self::With(path.parse()?));
+                    } else {
+                        return Err(lookahead.error());
+                    }
+
+                    if !input.is_empty() {
+                        input.parse::<Token!(,)>()?;
+                    }
+                }
+
+                Ok(attrs)
+            })
+            .map_err(|err| {
+                syn::Error::new(
+                    err.span(),
+                    format!("parse column attr({}) error: {}", field, err),
+                )
+            })?;
+
+        let converters = attrs
+            .iter()
+            .filter(|attr| matches!(attr, Self::Json | Self::With(_)))
+            .count();
+
+        if converters > 1 {
+            return Err(syn::Error::new_spanned(
+                attr,
+                format!("column({}) can't be both json and with", field),
+            ));
+        }
+
+        Ok(attrs)
     }
 
-    fn parse_col_name(field: &Ident, attr: &Attribute) -> syn::Result<Self> {
-        Ok(Self::Name(attr.parse_args().map_err(|err| {
+    fn parse_many_to_many(field: &Ident, attr: &Attribute) -> syn::Result<Self> {
+        Ok(Self::ManyToMany(attr.parse_args().map_err(|err| {
             syn::Error::new(
                 err.span(),
-                format!("parse col name attr({}) error: {}", field, err),
+                format!("parse many_to_many({}) attr error: {}", field, err),
             )
         })?))
     }
@@ -139,16 +261,39 @@ impl ColumnAttr {
     }
 }
 
+/// Primary key generation mode, mirror of `linq_rs::orm::KeyGen`
+#[derive(Clone, Copy)]
+pub enum KeyGen {
+    Manual,
+    AutoInc,
+    Uuid,
+}
+
+impl CodeGen for KeyGen {
+    fn gen_ir_code(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let name = match self {
+            Self::Manual => quote!(Manual),
+            Self::AutoInc => quote!(AutoInc),
+            Self::Uuid => quote!(Uuid),
+        };
+
+        Ok(quote!(::linq_rs::orm::KeyGen::#name))
+    }
+}
+
 #[derive(Clone)]
 pub struct Related {
     pub from: Ident,
     pub to: Ident,
+    /// Not loaded by select unless requested, tagged by `lazy` arg.
+    pub lazy: bool,
 }
 
 impl Parse for Related {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut from_col: Option<Ident> = None;
         let mut to_col: Option<Ident> = None;
+        let mut lazy = false;
 
         while !input.is_empty() {
             if input.lookahead1().peek(kw::from) {
@@ -170,6 +315,11 @@ impl Parse for Related {
                 input.parse::<Token!(=)>()?;
                 to_col = Some(input.parse()?);
 
+                continue;
+            } else if input.lookahead1().peek(kw::lazy) {
+                input.parse::<kw::lazy>()?;
+                lazy = true;
+
                 continue;
             }
 
@@ -188,6 +338,63 @@ impl Parse for Related {
         Ok(Related {
             from: from_col,
             to: to_col,
+            lazy,
+        })
+    }
+}
+
+/// `#[many_to_many(through = Link, from = col_x, to = col_y)]` args,
+/// `from`/`to` are col name fns of the link table.
+#[derive(Clone)]
+pub struct Through {
+    pub through: Path,
+    pub from: Ident,
+    pub to: Ident,
+    /// Not loaded by select unless requested, tagged by `lazy` arg.
+    pub lazy: bool,
+}
+
+impl Parse for Through {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut through: Option<Path> = None;
+        let mut from_col: Option<Ident> = None;
+        let mut to_col: Option<Ident> = None;
+        let mut lazy = false;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(kw::through) {
+                input.parse::<kw::through>()?;
+                input.parse::<Token!(=)>()?;
+                through = Some(input.parse()?);
+            } else if lookahead.peek(kw::from) {
+                input.parse::<kw::from>()?;
+                input.parse::<Token!(=)>()?;
+                from_col = Some(input.parse()?);
+            } else if lookahead.peek(kw::to) {
+                input.parse::<kw::to>()?;
+                input.parse::<Token!(=)>()?;
+                to_col = Some(input.parse()?);
+            } else if lookahead.peek(kw::lazy) {
+                input.parse::<kw::lazy>()?;
+                lazy = true;
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token!(,)>()?;
+            }
+        }
+
+        let expect = "many_to_many expect args (through = Link, from = col_x, to = col_y)";
+
+        Ok(Through {
+            through: through.ok_or(syn::Error::new(input.span(), expect))?,
+            from: from_col.ok_or(syn::Error::new(input.span(), expect))?,
+            to: to_col.ok_or(syn::Error::new(input.span(), expect))?,
+            lazy,
         })
     }
 }
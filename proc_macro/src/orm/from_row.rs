@@ -0,0 +1,116 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Fields, ItemStruct};
+
+use super::col::{ColumnAttr, ColumnDef};
+use crate::gen::CodeGen;
+
+/// Struct decoded from ad-hoc query rows by `#[derive(FromRow)]`
+pub struct FromRow {
+    ident: Ident,
+    cols: Vec<ColumnDef>,
+}
+
+impl FromRow {
+    pub fn new(item: ItemStruct) -> syn::Result<Self> {
+        let fields = match &item.fields {
+            Fields::Named(fields) => fields,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &item,
+                    "FromRow expect struct with named fields",
+                ))
+            }
+        };
+
+        if !item.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &item.generics,
+                "FromRow not support generic struct",
+            ));
+        }
+
+        let mut cols = vec![];
+
+        for field in &fields.named {
+            let name = field.ident.clone().unwrap();
+
+            let mut attrs = vec![];
+
+            for attr in &field.attrs {
+                for col_attr in ColumnAttr::new(&name, attr)? {
+                    if !matches!(
+                        col_attr,
+                        ColumnAttr::Name(_) | ColumnAttr::Json | ColumnAttr::With(_)
+                    ) {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "FromRow only support #[column(..)] attr",
+                        ));
+                    }
+
+                    attrs.push(col_attr);
+                }
+            }
+
+            cols.push(ColumnDef {
+                name,
+                attrs,
+                col_type: field.ty.clone(),
+            });
+        }
+
+        Ok(Self {
+            ident: item.ident,
+            cols,
+        })
+    }
+}
+
+impl CodeGen for FromRow {
+    fn gen_ir_code(&self) -> syn::Result<TokenStream> {
+        let ident = &self.ident;
+
+        let col_names = self
+            .cols
+            .iter()
+            .map(|col| col.col_name())
+            .collect::<Vec<_>>();
+
+        let idents = self.cols.iter().map(|col| &col.name).collect::<Vec<_>>();
+
+        let from_column_values = self
+            .cols
+            .iter()
+            .map(|col| col.gen_from_column_value(quote!(values.remove(0))))
+            .collect::<Vec<_>>();
+
+        let count = self.cols.len();
+
+        Ok(quote! {
+            impl ::linq_rs::orm::FromRow for #ident {
+                fn col_names() -> &'static [&'static str] {
+                    &[#(#col_names,)*]
+                }
+
+                fn from_values(
+                    mut values: Vec<::linq_rs::orm::ColumnValue>,
+                ) -> ::linq_rs::anyhow::Result<Self> {
+                    if values.len() != #count {
+                        return Err(::linq_rs::anyhow::format_err!(
+                            "Expect {} col values, got {}",
+                            #count,
+                            values.len()
+                        ));
+                    }
+
+                    #(let #idents = #from_column_values?;)*
+
+                    Ok(Self {
+                        #(#idents,)*
+                    })
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,255 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{parse::Parse, Attribute, Expr, Fields, ItemEnum, Lit, LitInt, LitStr, Token};
+
+use crate::gen::CodeGen;
+
+mod kw {
+    use syn::custom_keyword;
+
+    custom_keyword!(int);
+    custom_keyword!(string);
+    custom_keyword!(rename);
+    custom_keyword!(value);
+}
+
+/// Column representation of enum
+enum Repr {
+    String,
+    Int,
+}
+
+/// `#[linq(..)]` attr args
+enum LinqAttr {
+    Repr(Repr),
+    Rename(LitStr),
+    Value(i64),
+}
+
+impl Parse for LinqAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(kw::int) {
+            input.parse::<kw::int>()?;
+            Ok(Self::Repr(Repr::Int))
+        } else if lookahead.peek(kw::string) {
+            input.parse::<kw::string>()?;
+            Ok(Self::Repr(Repr::String))
+        } else if lookahead.peek(kw::rename) {
+            input.parse::<kw::rename>()?;
+            input.parse::<Token!(=)>()?;
+            Ok(Self::Rename(input.parse()?))
+        } else if lookahead.peek(kw::value) {
+            input.parse::<kw::value>()?;
+            input.parse::<Token!(=)>()?;
+
+            let neg: Option<Token!(-)> = input.parse()?;
+            let value: i64 = input.parse::<LitInt>()?.base10_parse()?;
+
+            Ok(Self::Value(if neg.is_some() { -value } else { value }))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+fn linq_attrs(attrs: &[Attribute]) -> syn::Result<Vec<(&Attribute, LinqAttr)>> {
+    let mut linq_attrs = vec![];
+
+    for attr in attrs {
+        if attr.path.is_ident("linq") {
+            linq_attrs.push((attr, attr.parse_args()?));
+        }
+    }
+
+    Ok(linq_attrs)
+}
+
+struct EnumVariant {
+    ident: Ident,
+    name: LitStr,
+    value: i64,
+}
+
+/// Unit-variant enum mapped to string or integer column by `#[derive(LinqEnum)]`
+pub struct LinqEnum {
+    ident: Ident,
+    repr: Repr,
+    variants: Vec<EnumVariant>,
+}
+
+impl LinqEnum {
+    pub fn new(item: ItemEnum) -> syn::Result<Self> {
+        let mut repr = Repr::String;
+
+        for (attr, linq_attr) in linq_attrs(&item.attrs)? {
+            match linq_attr {
+                LinqAttr::Repr(r) => repr = r,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "Expect #[linq(string)] or #[linq(int)] on enum",
+                    ))
+                }
+            }
+        }
+
+        let mut variants = vec![];
+
+        let mut next_value = 0i64;
+
+        for variant in &item.variants {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "LinqEnum only support unit variants",
+                ));
+            }
+
+            let mut name = LitStr::new(&variant.ident.to_string(), variant.ident.span());
+
+            // String enums ignore discriminants, which may be any const expr.
+            let mut value = match (&repr, &variant.discriminant) {
+                (Repr::Int, Some((_, expr))) => discriminant(expr)?,
+                _ => next_value,
+            };
+
+            for (attr, linq_attr) in linq_attrs(&variant.attrs)? {
+                match (&repr, linq_attr) {
+                    (Repr::String, LinqAttr::Rename(rename)) => name = rename,
+                    (Repr::Int, LinqAttr::Value(v)) => value = v,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "Expect #[linq(rename = \"..\")] for string enum or #[linq(value = ..)] for int enum",
+                        ))
+                    }
+                }
+            }
+
+            let duplicate = variants.iter().any(|v: &EnumVariant| match repr {
+                Repr::String => v.name.value() == name.value(),
+                Repr::Int => v.value == value,
+            });
+
+            if duplicate {
+                return Err(syn::Error::new_spanned(variant, "Duplicate LinqEnum value"));
+            }
+
+            next_value = value.wrapping_add(1);
+
+            variants.push(EnumVariant {
+                ident: variant.ident.clone(),
+                name,
+                value,
+            });
+        }
+
+        Ok(Self {
+            ident: item.ident,
+            repr,
+            variants,
+        })
+    }
+}
+
+/// Integer literal discriminant, e.g. `A = 3` or `B = -1`
+fn discriminant(expr: &Expr) -> syn::Result<i64> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(v) => v.base10_parse(),
+            _ => Err(syn::Error::new_spanned(expr, "Expect integer discriminant")),
+        },
+        Expr::Unary(unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            Ok(-discriminant(&unary.expr)?)
+        }
+        _ => Err(syn::Error::new_spanned(expr, "Expect integer discriminant")),
+    }
+}
+
+impl CodeGen for LinqEnum {
+    fn gen_ir_code(&self) -> syn::Result<TokenStream> {
+        let ident = &self.ident;
+        let enum_name = ident.to_string();
+
+        let idents = self.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+
+        let (encoded, variant, decode) = match self.repr {
+            Repr::String => (
+                self.variants
+                    .iter()
+                    .map(|v| v.name.to_token_stream())
+                    .collect::<Vec<_>>(),
+                quote!(::linq_rs::Variant::String(value.to_owned())),
+                quote!(::linq_rs::Variant::String(value) => value.as_str()),
+            ),
+            Repr::Int => (
+                self.variants
+                    .iter()
+                    .map(|v| v.value.to_token_stream())
+                    .collect::<Vec<_>>(),
+                quote!(::linq_rs::Variant::Int(value)),
+                quote!(::linq_rs::Variant::Int(value) => *value),
+            ),
+        };
+
+        Ok(quote! {
+            impl From<#ident> for ::linq_rs::Variant {
+                fn from(v: #ident) -> Self {
+                    let value = match v {
+                        #(#ident::#idents => #encoded,)*
+                    };
+
+                    #variant
+                }
+            }
+
+            impl TryFrom<::linq_rs::Variant> for #ident {
+                type Error = ::linq_rs::anyhow::Error;
+
+                fn try_from(value: ::linq_rs::Variant) -> Result<Self, Self::Error> {
+                    let value = match &value {
+                        #decode,
+                        _ => {
+                            return Err(::linq_rs::anyhow::format_err!(
+                                "Variant type mismatch, expect {}",
+                                #enum_name
+                            ))
+                        }
+                    };
+
+                    match value {
+                        #(#encoded => Ok(Self::#idents),)*
+                        _ => Err(::linq_rs::anyhow::format_err!(
+                            "Unknown {} value {:?}",
+                            #enum_name,
+                            value
+                        )),
+                    }
+                }
+            }
+
+            impl ::linq_rs::orm::ColumnLike for #ident {
+                fn into_column_value(
+                    self,
+                    col_name: &'static str,
+                ) -> ::linq_rs::anyhow::Result<::linq_rs::orm::ColumnValue> {
+                    Ok(::linq_rs::orm::ColumnValue::Simple(col_name, self.into()))
+                }
+
+                fn from_column_value(
+                    value: ::linq_rs::orm::ColumnValue,
+                ) -> ::linq_rs::anyhow::Result<Self> {
+                    match value {
+                        ::linq_rs::orm::ColumnValue::Simple(col_name, ::linq_rs::Variant::Null) => {
+                            Err(::linq_rs::anyhow::format_err!("Column({}) can't be none", col_name))
+                        }
+                        ::linq_rs::orm::ColumnValue::Simple(_, value) => value.try_into(),
+                        _ => Err(::linq_rs::anyhow::format_err!("Column type mismatch")),
+                    }
+                }
+            }
+        })
+    }
+}
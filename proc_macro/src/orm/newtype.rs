@@ -0,0 +1,82 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Fields, ItemStruct, Member, Type};
+
+use crate::gen::CodeGen;
+
+/// Single-field struct delegating `ColumnLike` to the inner type by `#[derive(ColumnLike)]`
+pub struct NewType {
+    ident: Ident,
+    member: Member,
+    inner: Type,
+}
+
+impl NewType {
+    pub fn new(item: ItemStruct) -> syn::Result<Self> {
+        let mut fields = match &item.fields {
+            Fields::Named(fields) => fields.named.iter(),
+            Fields::Unnamed(fields) => fields.unnamed.iter(),
+            Fields::Unit => {
+                return Err(syn::Error::new_spanned(
+                    &item,
+                    "ColumnLike expect single-field struct",
+                ))
+            }
+        };
+
+        let field = match (fields.next(), fields.next()) {
+            (Some(field), None) => field,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &item.fields,
+                    "ColumnLike expect single-field struct",
+                ))
+            }
+        };
+
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(0.into()),
+        };
+
+        if !item.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &item.generics,
+                "ColumnLike not support generic struct",
+            ));
+        }
+
+        Ok(Self {
+            ident: item.ident,
+            member,
+            inner: field.ty.clone(),
+        })
+    }
+}
+
+impl CodeGen for NewType {
+    fn gen_ir_code(&self) -> syn::Result<TokenStream> {
+        let ident = &self.ident;
+        let member = &self.member;
+        let inner = &self.inner;
+
+        Ok(quote! {
+            impl ::linq_rs::orm::ColumnLike for #ident {
+                fn into_column_value(
+                    self,
+                    col_name: &'static str,
+                ) -> ::linq_rs::anyhow::Result<::linq_rs::orm::ColumnValue> {
+                    ::linq_rs::orm::ColumnLike::into_column_value(self.#member, col_name)
+                }
+
+                fn from_column_value(
+                    value: ::linq_rs::orm::ColumnValue,
+                ) -> ::linq_rs::anyhow::Result<Self> {
+                    Ok(Self {
+                        #member: <#inner as ::linq_rs::orm::ColumnLike>::from_column_value(value)?,
+                    })
+                }
+            }
+        })
+    }
+}
@@ -15,3 +15,15 @@ impl CodeGen for Variant {
         }
     }
 }
+
+/// Generate `Option` code of optional variant
+pub fn gen_option(variant: &Option<Variant>) -> syn::Result<proc_macro2::TokenStream> {
+    match variant {
+        Some(variant) => {
+            let stream = variant.gen_ir_code()?;
+
+            Ok(quote!(Some(#stream)))
+        }
+        None => Ok(quote!(None)),
+    }
+}
@@ -89,14 +89,20 @@ impl Parse for Column {
 }
 
 pub enum IrType {
+    Bool(kw::BOOL),
     Int(kw::INT),
     BigInt(kw::BIGINT),
     Float(kw::FLOAT),
     Decimal(kw::DECIMAL),
     String(kw::STRING),
     Bytes(kw::BYTES),
-    DateTime(kw::DATETIME),
+    Date(kw::DATE),
+    Time(kw::TIME),
     Timestamp(kw::TIMESTAMP),
+    DateTime(kw::DATETIME),
+    Interval(kw::INTERVAL),
+    Json(kw::JSON),
+    Uuid(kw::UUID),
 }
 
 impl Parse for IrType {
@@ -115,10 +121,22 @@ impl Parse for IrType {
             Ok(Self::BigInt(input.parse()?))
         } else if lookahead.peek(kw::DECIMAL) {
             Ok(Self::Decimal(input.parse()?))
-        } else if lookahead.peek(kw::DATETIME) {
-            Ok(Self::DateTime(input.parse()?))
+        } else if lookahead.peek(kw::DATE) {
+            Ok(Self::Date(input.parse()?))
+        } else if lookahead.peek(kw::TIME) {
+            Ok(Self::Time(input.parse()?))
         } else if lookahead.peek(kw::TIMESTAMP) {
             Ok(Self::Timestamp(input.parse()?))
+        } else if lookahead.peek(kw::DATETIME) {
+            Ok(Self::DateTime(input.parse()?))
+        } else if lookahead.peek(kw::INTERVAL) {
+            Ok(Self::Interval(input.parse()?))
+        } else if lookahead.peek(kw::JSON) {
+            Ok(Self::Json(input.parse()?))
+        } else if lookahead.peek(kw::UUID) {
+            Ok(Self::Uuid(input.parse()?))
+        } else if lookahead.peek(kw::BOOL) {
+            Ok(Self::Bool(input.parse()?))
         } else {
             Err(syn::Error::new(input.span(), "Unknown column type"))
         }
@@ -40,6 +40,8 @@ impl NamedConstraint {
     }
 }
 
+// `ForeignKey` carries a `Variant` table name; constraints are few per table.
+#[allow(clippy::large_enum_variant)]
 pub enum Constraint {
     Unique(ColumnNames),
     Index(ColumnNames),
@@ -71,10 +73,10 @@ impl Parse for Constraint {
             return Ok(Self::ForeignKey(cols, ref_table, ref_cols));
         }
 
-        return Err(syn::Error::new(
+        Err(syn::Error::new(
             input.span(),
             "Expect keyword UNIQUE/INDEX/FOREIGN KEY",
-        ));
+        ))
     }
 }
 
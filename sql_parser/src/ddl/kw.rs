@@ -2,6 +2,7 @@ use syn::custom_keyword;
 
 custom_keyword!(CREATE);
 custom_keyword!(TABLE);
+custom_keyword!(BOOL);
 custom_keyword!(INT);
 custom_keyword!(BIGINT);
 custom_keyword!(FLOAT);
@@ -13,8 +14,13 @@ custom_keyword!(NULL);
 custom_keyword!(CONSTRAINT);
 custom_keyword!(PRIMARY);
 custom_keyword!(AUTOINC);
+custom_keyword!(DATE);
+custom_keyword!(TIME);
 custom_keyword!(DATETIME);
 custom_keyword!(TIMESTAMP);
+custom_keyword!(INTERVAL);
+custom_keyword!(JSON);
+custom_keyword!(UUID);
 custom_keyword!(UNIQUE);
 custom_keyword!(INDEX);
 custom_keyword!(FOREIGN);
@@ -17,6 +17,15 @@ pub use order::*;
 mod from;
 pub use from::*;
 
+mod join;
+pub use join::*;
+
+mod aggregate;
+pub use aggregate::*;
+
+mod group;
+pub use group::*;
+
 mod insert;
 pub use insert::*;
 
@@ -31,6 +40,8 @@ pub use delete::*;
 
 use syn::{parse::Parse, Token};
 
+// One `RQL` per `rql!` invocation; `Select` is large, but it is never stored in bulk.
+#[allow(clippy::large_enum_variant)]
 pub enum RQL {
     Select(Select),
     Insert(Insert),
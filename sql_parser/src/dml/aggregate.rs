@@ -0,0 +1,79 @@
+use syn::{parenthesized, parse::Parse, token::Paren, Lit, LitStr, Token};
+
+use super::{kw, parse_qualifier, Variant};
+
+pub enum AggregateFunc {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+/// Aggregate function call like `COUNT(*)` or `SUM(o.amount)`
+pub struct Aggregate {
+    pub func: AggregateFunc,
+    pub table: Option<Variant>,
+    /// String literal `*` for `COUNT(*)`
+    pub name: Variant,
+}
+
+impl Aggregate {
+    pub fn peek(input: syn::parse::ParseStream) -> bool {
+        (input.peek(kw::COUNT)
+            || input.peek(kw::SUM)
+            || input.peek(kw::AVG)
+            || input.peek(kw::MIN)
+            || input.peek(kw::MAX))
+            && input.peek2(Paren)
+    }
+}
+
+impl Parse for Aggregate {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        let func = if lookahead.peek(kw::COUNT) {
+            let _: kw::COUNT = input.parse()?;
+            AggregateFunc::Count
+        } else if lookahead.peek(kw::SUM) {
+            let _: kw::SUM = input.parse()?;
+            AggregateFunc::Sum
+        } else if lookahead.peek(kw::AVG) {
+            let _: kw::AVG = input.parse()?;
+            AggregateFunc::Avg
+        } else if lookahead.peek(kw::MIN) {
+            let _: kw::MIN = input.parse()?;
+            AggregateFunc::Min
+        } else if lookahead.peek(kw::MAX) {
+            let _: kw::MAX = input.parse()?;
+            AggregateFunc::Max
+        } else {
+            return Err(lookahead.error());
+        };
+
+        let content;
+
+        parenthesized!(content in input);
+
+        if content.peek(Token![*]) {
+            let star: Token![*] = content.parse()?;
+
+            if !matches!(func, AggregateFunc::Count) {
+                return Err(syn::Error::new(star.span, "Only COUNT accepts *"));
+            }
+
+            return Ok(Aggregate {
+                func,
+                table: None,
+                name: Variant::Lit(Lit::Str(LitStr::new("*", star.span))),
+            });
+        }
+
+        let table = parse_qualifier(&content)?;
+
+        let name = content.parse()?;
+
+        Ok(Aggregate { func, table, name })
+    }
+}
@@ -2,11 +2,24 @@ use syn::{parse::Parse, Expr, Token};
 
 use super::Variant;
 
+// Exactly one `Columns` per statement, so the unboxed `syn::Expr` costs nothing.
+#[allow(clippy::large_enum_variant)]
 pub enum Columns {
     NamedColumns(Vec<Variant>),
     Expr(Expr),
 }
 
+/// Optional `RETURNING (cols)` clause of insert, update and delete
+pub fn parse_returning(input: syn::parse::ParseStream) -> syn::Result<Option<Columns>> {
+    if input.lookahead1().peek(super::kw::RETURNING) {
+        let _: super::kw::RETURNING = input.parse()?;
+
+        Ok(Some(input.parse()?))
+    } else {
+        Ok(None)
+    }
+}
+
 impl Parse for Columns {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         if input.lookahead1().peek(Token!(#)) {
@@ -17,7 +30,7 @@ impl Parse for Columns {
 
             let _: Token!(*) = input.parse()?;
 
-            return Ok(Columns::Expr(content.parse()?));
+            Ok(Columns::Expr(content.parse()?))
         } else {
             let content;
 
@@ -36,7 +49,7 @@ impl Parse for Columns {
                 break;
             }
 
-            return Ok(Columns::NamedColumns(cols));
+            Ok(Columns::NamedColumns(cols))
         }
     }
 }
@@ -1,6 +1,6 @@
 use syn::{parenthesized, parse::Parse, token::Paren, Token};
 
-use super::{kw, Variant};
+use super::{kw, parse_qualifier, Aggregate, Select, Variant};
 
 pub enum Op {
     NotEq(Token!(!=)),
@@ -10,7 +10,17 @@ pub enum Op {
     Gte(Token!(>=)),
     Lte(Token!(<=)),
     Like(kw::LIKE),
+    NotLike(kw::NOT, kw::LIKE),
     In(Token!(in)),
+    NotIn(kw::NOT, Token!(in)),
+    Between(kw::BETWEEN),
+    NotBetween(kw::NOT, kw::BETWEEN),
+    IsNull(kw::IS, kw::NULL),
+    IsNotNull(kw::IS, kw::NOT, kw::NULL),
+    /// Prefix `NOT` of cond expr
+    Not(kw::NOT),
+    /// Prefix `EXISTS` of subquery
+    Exists(kw::EXISTS),
     And(kw::AND),
     Or(kw::OR),
 }
@@ -35,23 +45,60 @@ impl Parse for Op {
             Ok(Op::In(input.parse()?))
         } else if lookahead.peek(kw::LIKE) {
             Ok(Op::Like(input.parse()?))
+        } else if lookahead.peek(kw::BETWEEN) {
+            Ok(Op::Between(input.parse()?))
+        } else if lookahead.peek(kw::IS) {
+            let is = input.parse()?;
+
+            if input.lookahead1().peek(kw::NOT) {
+                Ok(Op::IsNotNull(is, input.parse()?, input.parse()?))
+            } else {
+                Ok(Op::IsNull(is, input.parse()?))
+            }
+        } else if lookahead.peek(kw::NOT) {
+            let not = input.parse()?;
+
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(Token!(in)) {
+                Ok(Op::NotIn(not, input.parse()?))
+            } else if lookahead.peek(kw::LIKE) {
+                Ok(Op::NotLike(not, input.parse()?))
+            } else if lookahead.peek(kw::BETWEEN) {
+                Ok(Op::NotBetween(not, input.parse()?))
+            } else {
+                Err(lookahead.error())
+            }
         } else if lookahead.peek(kw::AND) {
             Ok(Op::And(input.parse()?))
         } else if lookahead.peek(kw::OR) {
             Ok(Op::Or(input.parse()?))
         } else {
-            return Err(syn::Error::new(
+            Err(syn::Error::new(
                 input.span(),
                 "Expect cond op look like <,>,in..",
-            ));
+            ))
         }
     }
 }
 
+// `Variant` may carry a `syn::Expr`; a parameter is turned into IR code right away.
+#[allow(clippy::large_enum_variant)]
 pub enum CondParameter {
     VariantList(Vec<Variant>),
-    Variant(Variant),
+    /// Literal or `#expr` value, bound as parameter
+    Param(Variant),
     CondExpr(Box<CondExpr>),
+    /// Ident or table qualified column, e.g. `id` or `u.id`
+    Column(Option<Variant>, Variant),
+    /// Aggregate function call in `HAVING` clause
+    Aggregate(Aggregate),
+    /// Bounds of `BETWEEN`
+    Range(Box<CondParameter>, Box<CondParameter>),
+    /// Rhs placeholder of unary ops
+    Empty,
+    /// Nested `SELECT` in parentheses
+    Subquery(Box<Select>),
 }
 
 impl Parse for CondParameter {
@@ -63,6 +110,10 @@ impl Parse for CondParameter {
 
             parenthesized!(content in input);
 
+            if content.lookahead1().peek(kw::SELECT) {
+                return Ok(CondParameter::Subquery(Box::new(content.parse()?)));
+            }
+
             loop {
                 variants.push(content.parse()?);
 
@@ -74,8 +125,15 @@ impl Parse for CondParameter {
             }
 
             Ok(CondParameter::VariantList(variants))
+        } else if Aggregate::peek(input) {
+            Ok(CondParameter::Aggregate(input.parse()?))
+        } else if let Some(table) = parse_qualifier(input)? {
+            Ok(CondParameter::Column(Some(table), input.parse()?))
         } else {
-            Ok(CondParameter::Variant(input.parse()?))
+            match input.parse()? {
+                Variant::Ident(ident) => Ok(CondParameter::Column(None, Variant::Ident(ident))),
+                variant => Ok(CondParameter::Param(variant)),
+            }
         }
     }
 }
@@ -86,32 +144,77 @@ pub struct CondExpr {
     pub rhs: CondParameter,
 }
 
-impl Parse for CondExpr {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let lhs = if input.lookahead1().peek(Paren) {
+impl CondExpr {
+    /// Parse cond expr without trailing `AND`/`OR`, with optional `NOT` prefix.
+    fn parse_unary(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.lookahead1().peek(kw::NOT) {
+            let not = input.parse()?;
+
+            return Ok(CondExpr {
+                op: Op::Not(not),
+                lhs: CondParameter::CondExpr(Box::new(Self::parse_unary(input)?)),
+                rhs: CondParameter::Empty,
+            });
+        }
+
+        if input.lookahead1().peek(kw::EXISTS) {
+            let exists = input.parse()?;
+
+            let span = input.span();
+
+            let lhs = match input.parse()? {
+                CondParameter::Subquery(select) => CondParameter::Subquery(select),
+                _ => return Err(syn::Error::new(span, "expect subquery")),
+            };
+
+            return Ok(CondExpr {
+                op: Op::Exists(exists),
+                lhs,
+                rhs: CondParameter::Empty,
+            });
+        }
+
+        if input.lookahead1().peek(Paren) {
             let content;
 
             parenthesized!(content in input);
 
-            content.parse()?
-        } else {
-            let lhs: CondParameter = input.parse()?;
+            return content.parse();
+        }
 
-            let op: Op = input.parse()?;
+        let lhs: CondParameter = input.parse()?;
 
-            if let Op::In(_) = op {
+        let op: Op = input.parse()?;
+
+        let rhs = match op {
+            Op::In(_) | Op::NotIn(..) => {
                 let span = input.span();
 
                 if !input.lookahead1().peek(Paren) {
                     return Err(syn::Error::new(span, "expect ("));
                 }
-            }
 
-            let rhs: CondParameter = input.parse()?;
+                input.parse()?
+            }
+            Op::IsNull(..) | Op::IsNotNull(..) => CondParameter::Empty,
+            Op::Between(_) | Op::NotBetween(..) => {
+                let low: CondParameter = input.parse()?;
+                let _: kw::AND = input.parse()?;
+                let high: CondParameter = input.parse()?;
 
-            CondExpr { lhs, op, rhs }
+                CondParameter::Range(Box::new(low), Box::new(high))
+            }
+            _ => input.parse()?,
         };
 
+        Ok(CondExpr { lhs, op, rhs })
+    }
+}
+
+impl Parse for CondExpr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lhs = Self::parse_unary(input)?;
+
         let lookahead = input.lookahead1();
 
         if lookahead.peek(kw::AND) || lookahead.peek(kw::OR) {
@@ -1,10 +1,11 @@
 use syn::parse::Parse;
 
-use super::{kw, CondExpr, Variant};
+use super::{kw, parse_returning, Columns, CondExpr, Variant};
 
 pub struct Delete {
     pub table_name: Variant,
     pub cond: CondExpr,
+    pub returning: Option<Columns>,
 }
 
 impl Parse for Delete {
@@ -19,6 +20,12 @@ impl Parse for Delete {
 
         let cond = input.parse()?;
 
-        Ok(Delete { table_name, cond })
+        let returning = parse_returning(input)?;
+
+        Ok(Delete {
+            table_name,
+            cond,
+            returning,
+        })
     }
 }
@@ -1,17 +1,59 @@
-use syn::parse::Parse;
+use syn::{parenthesized, parse::Parse, token::Paren};
 
-use super::{kw, Variant};
+use super::{kw, Join, Select, Variant};
 
 pub struct From {
+    /// Table name, or the alias of derived table
     pub table_name: Variant,
+    pub aliase: Option<Variant>,
+    /// Derived table, e.g. `FROM (SELECT ...) AS t`
+    pub subquery: Option<Box<Select>>,
+    pub joins: Vec<Join>,
 }
 
 impl Parse for From {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let _: kw::FROM = input.parse()?;
 
-        let table_name: Variant = input.parse()?;
+        let (table_name, aliase, subquery) = if input.lookahead1().peek(Paren) {
+            let content;
 
-        Ok(From { table_name })
+            parenthesized!(content in input);
+
+            let subquery: Select = content.parse()?;
+
+            let span = input.span();
+
+            let aliase = parse_aliase(input)?
+                .ok_or_else(|| syn::Error::new(span, "Derived table must have an alias"))?;
+
+            (aliase, None, Some(Box::new(subquery)))
+        } else {
+            (input.parse()?, parse_aliase(input)?, None)
+        };
+
+        let mut joins = vec![];
+
+        while Join::peek(input) {
+            joins.push(input.parse()?);
+        }
+
+        Ok(From {
+            table_name,
+            aliase,
+            subquery,
+            joins,
+        })
+    }
+}
+
+/// Parse optional `AS alias` of table
+pub fn parse_aliase(input: syn::parse::ParseStream) -> syn::Result<Option<Variant>> {
+    if input.lookahead1().peek(kw::AS) {
+        let _: kw::AS = input.parse()?;
+
+        Ok(Some(input.parse()?))
+    } else {
+        Ok(None)
     }
 }
@@ -0,0 +1,40 @@
+use syn::{parse::Parse, Token};
+
+use super::{kw, parse_qualifier, Variant};
+
+pub struct GroupByColumn {
+    /// Table name or alias qualifier
+    pub table: Option<Variant>,
+    pub name: Variant,
+}
+
+/// `GROUP BY` clause
+pub struct GroupBy {
+    pub cols: Vec<GroupByColumn>,
+}
+
+impl Parse for GroupBy {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let _: kw::GROUP = input.parse()?;
+        let _: kw::BY = input.parse()?;
+
+        let mut cols = vec![];
+
+        loop {
+            let table = parse_qualifier(input)?;
+
+            let name: Variant = input.parse()?;
+
+            cols.push(GroupByColumn { table, name });
+
+            if input.lookahead1().peek(Token![,]) {
+                let _: Token![,] = input.parse()?;
+                continue;
+            }
+
+            break;
+        }
+
+        Ok(GroupBy { cols })
+    }
+}
@@ -1,10 +1,12 @@
 use syn::parse::Parse;
 
-use super::{kw, Columns, Variant};
+use super::{kw, parse_returning, Columns, Variant};
 
 pub struct Insert {
     pub table_name: Variant,
     pub cols: Columns,
+    pub on_conflict: Option<OnConflict>,
+    pub returning: Option<Columns>,
 }
 
 impl Parse for Insert {
@@ -17,6 +19,55 @@ impl Parse for Insert {
 
         let cols = input.parse()?;
 
-        Ok(Insert { table_name, cols })
+        let mut on_conflict = None;
+
+        if input.lookahead1().peek(kw::ON) {
+            on_conflict = Some(input.parse()?);
+        }
+
+        let returning = parse_returning(input)?;
+
+        Ok(Insert {
+            table_name,
+            cols,
+            on_conflict,
+            returning,
+        })
+    }
+}
+
+/// `ON CONFLICT (target) DO NOTHING` or `ON CONFLICT (target) DO UPDATE SET (cols)`
+pub struct OnConflict {
+    pub target: Columns,
+    /// `None` for `DO NOTHING`
+    pub update: Option<Columns>,
+}
+
+impl Parse for OnConflict {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let _: kw::ON = input.parse()?;
+
+        let _: kw::CONFLICT = input.parse()?;
+
+        let target = input.parse()?;
+
+        let _: kw::DO = input.parse()?;
+
+        let lookahead = input.lookahead1();
+
+        let update = if lookahead.peek(kw::NOTHING) {
+            let _: kw::NOTHING = input.parse()?;
+
+            None
+        } else if lookahead.peek(kw::UPDATE) {
+            let _: kw::UPDATE = input.parse()?;
+            let _: kw::SET = input.parse()?;
+
+            Some(input.parse()?)
+        } else {
+            return Err(lookahead.error());
+        };
+
+        Ok(Self { target, update })
     }
 }
@@ -0,0 +1,77 @@
+use syn::parse::Parse;
+
+use super::{cond, kw, parse_aliase, Variant};
+
+pub enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    Cross,
+}
+
+/// `[INNER] JOIN`, `LEFT [OUTER] JOIN`, `RIGHT [OUTER] JOIN` or `CROSS JOIN` clause.
+pub struct Join {
+    pub kind: JoinKind,
+    pub table_name: Variant,
+    pub aliase: Option<Variant>,
+    pub on: Option<cond::CondExpr>,
+}
+
+impl Join {
+    pub fn peek(input: syn::parse::ParseStream) -> bool {
+        input.peek(kw::JOIN)
+            || input.peek(kw::INNER)
+            || input.peek(kw::LEFT)
+            || input.peek(kw::RIGHT)
+            || input.peek(kw::CROSS)
+    }
+}
+
+impl Parse for Join {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        let kind = if lookahead.peek(kw::INNER) {
+            let _: kw::INNER = input.parse()?;
+            JoinKind::Inner
+        } else if lookahead.peek(kw::LEFT) {
+            let _: kw::LEFT = input.parse()?;
+            JoinKind::Left
+        } else if lookahead.peek(kw::RIGHT) {
+            let _: kw::RIGHT = input.parse()?;
+            JoinKind::Right
+        } else if lookahead.peek(kw::CROSS) {
+            let _: kw::CROSS = input.parse()?;
+            JoinKind::Cross
+        } else if lookahead.peek(kw::JOIN) {
+            JoinKind::Inner
+        } else {
+            return Err(lookahead.error());
+        };
+
+        if matches!(kind, JoinKind::Left | JoinKind::Right) && input.peek(kw::OUTER) {
+            let _: kw::OUTER = input.parse()?;
+        }
+
+        let _: kw::JOIN = input.parse()?;
+
+        let table_name: Variant = input.parse()?;
+
+        let aliase = parse_aliase(input)?;
+
+        let on = if let JoinKind::Cross = kind {
+            None
+        } else {
+            let _: kw::ON = input.parse()?;
+
+            Some(input.parse()?)
+        };
+
+        Ok(Join {
+            kind,
+            table_name,
+            aliase,
+            on,
+        })
+    }
+}
@@ -2,6 +2,11 @@ use syn::custom_keyword;
 
 custom_keyword!(SELECT);
 custom_keyword!(LIKE);
+custom_keyword!(NOT);
+custom_keyword!(IS);
+custom_keyword!(NULL);
+custom_keyword!(BETWEEN);
+custom_keyword!(EXISTS);
 custom_keyword!(AND);
 custom_keyword!(OR);
 custom_keyword!(LIMIT);
@@ -10,6 +15,9 @@ custom_keyword!(ORDER);
 custom_keyword!(BY);
 custom_keyword!(ASC);
 custom_keyword!(DESC);
+custom_keyword!(NULLS);
+custom_keyword!(FIRST);
+custom_keyword!(LAST);
 custom_keyword!(FROM);
 custom_keyword!(INSERT);
 custom_keyword!(UPDATE);
@@ -17,3 +25,22 @@ custom_keyword!(DELETE);
 custom_keyword!(INTO);
 custom_keyword!(WHERE);
 custom_keyword!(AS);
+custom_keyword!(JOIN);
+custom_keyword!(INNER);
+custom_keyword!(LEFT);
+custom_keyword!(RIGHT);
+custom_keyword!(CROSS);
+custom_keyword!(OUTER);
+custom_keyword!(ON);
+custom_keyword!(GROUP);
+custom_keyword!(HAVING);
+custom_keyword!(COUNT);
+custom_keyword!(SUM);
+custom_keyword!(AVG);
+custom_keyword!(MIN);
+custom_keyword!(MAX);
+custom_keyword!(CONFLICT);
+custom_keyword!(DO);
+custom_keyword!(NOTHING);
+custom_keyword!(SET);
+custom_keyword!(RETURNING);
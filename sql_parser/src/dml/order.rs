@@ -1,17 +1,38 @@
 use syn::{parse::Parse, Token};
 
-use super::{kw, Variant};
+use super::{kw, parse_qualifier, Variant};
 
+/// One term of `ORDER BY` clause
 pub struct OrderBy {
+    /// Table name or alias qualifier
+    pub table: Option<Variant>,
     pub name: Variant,
     pub order: Order,
+    pub nulls: Option<Nulls>,
 }
 
-impl Parse for OrderBy {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+impl OrderBy {
+    /// Parse `ORDER BY` clause with comma separated terms.
+    pub fn parse_terms(input: syn::parse::ParseStream) -> syn::Result<Vec<Self>> {
         let _: kw::ORDER = input.parse()?;
         let _: kw::BY = input.parse()?;
 
+        let mut terms = vec![input.parse()?];
+
+        while input.lookahead1().peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            terms.push(input.parse()?);
+        }
+
+        Ok(terms)
+    }
+}
+
+impl Parse for OrderBy {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let table = parse_qualifier(input)?;
+
         let name: Variant = input.parse()?;
 
         let order = if input.lookahead1().peek(kw::ASC) {
@@ -26,12 +47,42 @@ impl Parse for OrderBy {
             Order::ASC
         };
 
-        Ok(OrderBy { name, order })
+        let nulls = if input.lookahead1().peek(kw::NULLS) {
+            let _: kw::NULLS = input.parse()?;
+
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(kw::FIRST) {
+                let _: kw::FIRST = input.parse()?;
+                Some(Nulls::First)
+            } else if lookahead.peek(kw::LAST) {
+                let _: kw::LAST = input.parse()?;
+                Some(Nulls::Last)
+            } else {
+                return Err(lookahead.error());
+            }
+        } else {
+            None
+        };
+
+        Ok(OrderBy {
+            table,
+            name,
+            order,
+            nulls,
+        })
     }
 }
 
+// `Variant` may carry a `syn::Expr`; an order is consumed right after parsing.
+#[allow(clippy::large_enum_variant)]
 pub enum Order {
     ASC,
     DESC,
     Variant(Variant),
 }
+
+pub enum Nulls {
+    First,
+    Last,
+}
@@ -1,13 +1,17 @@
 use syn::{parenthesized, parse::Parse, Expr, Token};
 
-use super::{cond, kw, From, Limit, OrderBy, Variant};
+use super::{
+    cond, kw, parse_qualifier, Aggregate, AggregateFunc, From, GroupBy, Limit, OrderBy, Variant,
+};
 
 pub struct Select {
     pub cols: SelectColumns,
     pub from: From,
     pub cond: Option<cond::CondExpr>,
+    pub group_by: Option<GroupBy>,
+    pub having: Option<cond::CondExpr>,
     pub limit: Option<Limit>,
-    pub order: Option<OrderBy>,
+    pub order: Vec<OrderBy>,
 }
 
 impl Parse for Select {
@@ -19,8 +23,10 @@ impl Parse for Select {
         let from: From = input.parse()?;
 
         let mut cond = None;
+        let mut group_by = None;
+        let mut having = None;
         let mut limit = None;
-        let mut order = None;
+        let mut order = vec![];
 
         if input.lookahead1().peek(kw::WHERE) {
             let _: kw::WHERE = input.parse()?;
@@ -28,24 +34,38 @@ impl Parse for Select {
             cond = Some(input.parse()?);
         }
 
+        if input.lookahead1().peek(kw::GROUP) {
+            group_by = Some(input.parse()?);
+        }
+
+        if input.lookahead1().peek(kw::HAVING) {
+            let _: kw::HAVING = input.parse()?;
+
+            having = Some(input.parse()?);
+        }
+
         if input.lookahead1().peek(kw::ORDER) {
-            order = Some(input.parse()?);
+            order = OrderBy::parse_terms(input)?;
         }
 
         if input.lookahead1().peek(kw::LIMIT) {
             limit = Some(input.parse()?);
         }
 
-        return Ok(Self {
+        Ok(Self {
             cols,
             from,
             cond,
+            group_by,
+            having,
             limit,
             order,
-        });
+        })
     }
 }
 
+// `Expr` holds an unboxed `syn::Expr`; the node lives only while the macro expands.
+#[allow(clippy::large_enum_variant)]
 pub enum SelectColumns {
     All,
     NamedColumns(Vec<NamedColumn>),
@@ -57,7 +77,7 @@ impl Parse for SelectColumns {
         let lookahead = input.lookahead1();
         if lookahead.peek(Token![*]) {
             let _: Token![*] = input.parse()?;
-            return Ok(SelectColumns::All);
+            Ok(SelectColumns::All)
         } else if lookahead.peek(Token![#]) {
             let _: Token![#] = input.parse()?;
             let content;
@@ -67,7 +87,7 @@ impl Parse for SelectColumns {
 
             let _: Token![*] = input.parse()?;
 
-            return Ok(SelectColumns::Expr(expr));
+            Ok(SelectColumns::Expr(expr))
         } else {
             let mut cols = vec![];
             loop {
@@ -83,19 +103,28 @@ impl Parse for SelectColumns {
                 break;
             }
 
-            return Ok(SelectColumns::NamedColumns(cols));
+            Ok(SelectColumns::NamedColumns(cols))
         }
     }
 }
 
 pub struct NamedColumn {
+    /// Table name or alias qualifier
+    pub table: Option<Variant>,
     pub name: Variant,
     pub aliase: Option<Variant>,
+    pub func: Option<AggregateFunc>,
 }
 
 impl Parse for NamedColumn {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let name: Variant = input.parse()?;
+        let (func, table, name) = if Aggregate::peek(input) {
+            let aggregate: Aggregate = input.parse()?;
+
+            (Some(aggregate.func), aggregate.table, aggregate.name)
+        } else {
+            (None, parse_qualifier(input)?, input.parse()?)
+        };
 
         let aliase = if input.lookahead1().peek(kw::AS) {
             let _: kw::AS = input.parse()?;
@@ -107,6 +136,11 @@ impl Parse for NamedColumn {
             None
         };
 
-        Ok(NamedColumn { name, aliase })
+        Ok(NamedColumn {
+            table,
+            name,
+            aliase,
+            func,
+        })
     }
 }
@@ -1,11 +1,12 @@
 use syn::parse::Parse;
 
-use super::{kw, Columns, CondExpr, Variant};
+use super::{kw, parse_returning, Columns, CondExpr, Variant};
 
 pub struct Update {
     pub table_name: Variant,
     pub cols: Columns,
     pub cond: CondExpr,
+    pub returning: Option<Columns>,
 }
 
 impl Parse for Update {
@@ -20,10 +21,13 @@ impl Parse for Update {
 
         let cond = input.parse()?;
 
+        let returning = parse_returning(input)?;
+
         Ok(Update {
             table_name,
             cols,
             cond,
+            returning,
         })
     }
 }
@@ -5,6 +5,8 @@ mod ddl;
 pub use ddl::*;
 
 mod variant;
+// Also reached through `dml::*`; kept so the root path stays explicit.
+#[allow(unused_imports)]
 pub use variant::*;
 
 mod utils;
@@ -1,6 +1,9 @@
 use proc_macro2::Ident;
 use syn::{parse::Parse, Expr, Lit, Token};
 
+// `Expr` holds the `#expr` interpolation inline so code generation can match
+// on it directly; variants are short-lived parse nodes.
+#[allow(clippy::large_enum_variant)]
 pub enum Variant {
     Ident(Ident),
     Lit(Lit),
@@ -23,3 +26,15 @@ impl Parse for Variant {
         }
     }
 }
+
+/// Parse optional table qualifier of a column reference, e.g. `u` of `u.id`.
+pub fn parse_qualifier(input: syn::parse::ParseStream) -> syn::Result<Option<Variant>> {
+    if input.peek(syn::Ident) && input.peek2(Token![.]) {
+        let table: Ident = input.parse()?;
+        let _: Token![.] = input.parse()?;
+
+        Ok(Some(Variant::Ident(table)))
+    } else {
+        Ok(None)
+    }
+}